@@ -4,6 +4,7 @@ use super::environment::jvm::{JVMF, JVMAgent};
 use super::environment::jvmti::JVMTI;
 use super::event::*;
 use super::error::*;
+use super::logging::logger;
 use super::native::JavaVMPtr;
 use super::options::Options;
 use super::version::VersionNumber;
@@ -18,31 +19,21 @@ pub struct Agent {
 impl Agent {
 
     /// Create a newly initialised but blank JVM `Agent` instance using the provided Java VM pointer.
-    pub fn new(vm: JavaVMPtr) -> Agent {
-        let jvm_agent = JVMAgent::new(vm);
-
-        match jvm_agent.get_environment() {
-            Ok(environment) => Agent {
-                jvm: Box::new(jvm_agent),
-                capabilities: Capabilities::new(),
-                callbacks: EventCallbacks::new(),
-                environment: environment
-            },
-            Err(err) => panic!("FATAL: Could not get JVMTI environment: {}", translate_error(&err))
-        }
-
+    /// An error is returned if the JVMTI environment cannot be acquired from the VM.
+    pub fn new(vm: JavaVMPtr) -> Result<Agent, NativeError> {
+        Agent::new_from(Box::new(JVMAgent::new(vm)))
     }
 
     /// Create a newly initialised but blank JVM `Agent` instance using the provided JVM agent.
-    pub fn new_from(jvm: Box<JVMF>) -> Agent {
+    pub fn new_from(jvm: Box<JVMF>) -> Result<Agent, NativeError> {
         match jvm.get_environment() {
-            Ok(environment) => Agent {
+            Ok(environment) => Ok(Agent {
                 jvm: jvm,
                 capabilities: Capabilities::new(),
                 callbacks: EventCallbacks::new(),
                 environment: environment
-            },
-            Err(err) => panic!("FATAL: Could not get JVMTI environment: {}", translate_error(&err))
+            }),
+            Err(err) => Err(err)
         }
     }
 
@@ -59,16 +50,23 @@ impl Agent {
         self.jvm.destroy()
     }
 
+    /// Consume the agent and return its JVMTI environment. Events can only be enabled or disabled
+    /// through the environment the callbacks have been registered with.
+    pub fn into_environment(self) -> Box<JVMTI> {
+        self.environment
+    }
+
     pub fn update(&mut self) {
         match self.environment.add_capabilities(&self.capabilities) {
             Ok(caps) => {
-                println!("Current capabilities: {}", caps);
+                debug!(logger(), "Current capabilities: {}", caps);
                 self.capabilities = caps;
 
                 match self.environment.set_event_callbacks(self.callbacks.clone()) {
                     None => {
                         self.environment.set_event_notification_mode(VMEvent::VMObjectAlloc, self.callbacks.vm_object_alloc.is_some());
                         self.environment.set_event_notification_mode(VMEvent::VMObjectFree, self.callbacks.vm_object_free.is_some());
+                        self.environment.set_event_notification_mode(VMEvent::SampledObjectAlloc, self.callbacks.sampled_object_alloc.is_some());
                         self.environment.set_event_notification_mode(VMEvent::VMStart, self.callbacks.vm_start.is_some());
                         self.environment.set_event_notification_mode(VMEvent::VMInit, self.callbacks.vm_init.is_some());
                         self.environment.set_event_notification_mode(VMEvent::VMDeath, self.callbacks.vm_death.is_some());
@@ -88,10 +86,10 @@ impl Agent {
                         self.environment.set_event_notification_mode(VMEvent::GarbageCollectionFinish, self.callbacks.garbage_collection_finish.is_some());
                         self.environment.set_event_notification_mode(VMEvent::ClassFileLoadHook, self.callbacks.class_file_load_hook.is_some());
                     },
-                    Some(error) => println!("Couldn't register callbacks: {}", translate_error(&error))
+                    Some(error) => error!(logger(), "Couldn't register callbacks: {}", translate_error(&error))
                 }
             },
-            Err(error) => println!("Couldn't update capabilities: {}", translate_error(&error))
+            Err(error) => error!(logger(), "Couldn't update capabilities: {}", translate_error(&error))
         }
     }
 
@@ -127,6 +125,19 @@ impl Agent {
         self.capabilities.can_generate_object_free_events = handler.is_some();
     }
 
+    /// Register a handler for sampled object allocations (requires JVMTI 11 or later). The
+    /// sampling rate can be adjusted using `set_heap_sampling_interval`.
+    pub fn on_sampled_object_alloc(&mut self, handler: Option<FnSampledObjectAlloc>) {
+        self.callbacks.sampled_object_alloc = handler;
+        self.capabilities.can_generate_sampled_object_alloc_events = handler.is_some();
+    }
+
+    /// Set the average number of bytes allocated between two sampled object allocation events.
+    /// The JVM defaults to 512KB.
+    pub fn set_heap_sampling_interval(&mut self, sampling_interval: i32) -> Option<NativeError> {
+        self.environment.set_heap_sampling_interval(sampling_interval)
+    }
+
     pub fn on_thread_start(&mut self, handler: Option<FnThreadStart>) {
         self.callbacks.thread_start = handler;
     }
@@ -137,56 +148,38 @@ impl Agent {
 
     pub fn on_exception(&mut self, handler: Option<FnException>) {
         self.callbacks.exception = handler;
-        self.capabilities.can_generate_exception_events = handler.or(self.callbacks.exception_catch).is_some();
+        self.capabilities.can_generate_exception_events = handler.is_some() || self.callbacks.exception_catch.is_some();
     }
 
     pub fn on_exception_catch(&mut self, handler: Option<FnExceptionCatch>) {
         self.callbacks.exception_catch = handler;
-        self.capabilities.can_generate_exception_events = handler.or(self.callbacks.exception).is_some();
+        self.capabilities.can_generate_exception_events = handler.is_some() || self.callbacks.exception.is_some();
     }
 
     pub fn on_monitor_wait(&mut self, handler: Option<FnMonitorWait>) {
         self.callbacks.monitor_wait = handler;
-
-        let has_some = handler
-            .or(self.callbacks.monitor_waited)
-            .or(self.callbacks.monitor_contended_enter)
-            .or(self.callbacks.monitor_contended_entered).is_some();
-
-        self.capabilities.can_generate_monitor_events = has_some;
+        self.capabilities.can_generate_monitor_events = self.has_monitor_callbacks();
     }
 
     pub fn on_monitor_waited(&mut self, handler: Option<FnMonitorWaited>) {
         self.callbacks.monitor_waited = handler;
-
-        let has_some = handler
-            .or(self.callbacks.monitor_wait)
-            .or(self.callbacks.monitor_contended_enter)
-            .or(self.callbacks.monitor_contended_entered).is_some();
-
-        self.capabilities.can_generate_monitor_events = has_some;
+        self.capabilities.can_generate_monitor_events = self.has_monitor_callbacks();
     }
 
     pub fn on_monitor_contended_enter(&mut self, handler: Option<FnMonitorContendedEnter>) {
         self.callbacks.monitor_contended_enter = handler;
-
-        let has_some = handler
-            .or(self.callbacks.monitor_wait)
-            .or(self.callbacks.monitor_waited)
-            .or(self.callbacks.monitor_contended_entered).is_some();
-
-        self.capabilities.can_generate_monitor_events = has_some;
+        self.capabilities.can_generate_monitor_events = self.has_monitor_callbacks();
     }
 
     pub fn on_monitor_contended_entered(&mut self, handler: Option<FnMonitorContendedEntered>) {
         self.callbacks.monitor_contended_entered = handler;
+        self.capabilities.can_generate_monitor_events = self.has_monitor_callbacks();
+    }
 
-        let has_some = handler
-            .or(self.callbacks.monitor_wait)
-            .or(self.callbacks.monitor_waited)
-            .or(self.callbacks.monitor_contended_enter).is_some();
-
-        self.capabilities.can_generate_monitor_events = has_some;
+    /// The monitor callbacks have different signatures, so they can't be chained with `or`
+    fn has_monitor_callbacks(&self) -> bool {
+        self.callbacks.monitor_wait.is_some() || self.callbacks.monitor_waited.is_some()
+            || self.callbacks.monitor_contended_enter.is_some() || self.callbacks.monitor_contended_entered.is_some()
     }
 
     pub fn on_field_access(&mut self, handler: Option<FnFieldAccess>) {
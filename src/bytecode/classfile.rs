@@ -1,3 +1,5 @@
+use super::super::mutf8;
+
 
 ///
 /// A `Classfile` represents a definition of a single JVM class or interface. Unlike the bytecode
@@ -90,19 +92,18 @@ impl ConstantPool {
 
     pub fn get_utf8_string(&self, idx: u16) -> Option<String> {
         match self.get_utf8(idx) {
-            Some(bytes) => match String::from_utf8(bytes.clone()) {
-                Ok(string) => Some(string),
-                _ => None
-            },
+            Some(bytes) => mutf8::decode(bytes),
             _ => None
         }
     }
 
     pub fn find_ut8_index(&self, utf8: &'static str) -> Option<usize> {
+        let encoded = mutf8::encode(utf8);
+
         for i in 0..self.constants.len() {
             match self.constants[i] {
                 Constant::Utf8(ref bytes) => {
-                    if bytes.as_slice() == utf8.as_bytes() {
+                    if *bytes == encoded {
                         return Some(i);
                     }
                 },
@@ -183,6 +184,12 @@ pub enum Constant {
 }
 
 impl Constant {
+
+    /// Create a new `Utf8` constant holding the given string in modified UTF-8 encoding
+    pub fn utf8(value: &str) -> Constant {
+        Constant::Utf8(mutf8::encode(value))
+    }
+
     pub fn cp_size(&self) -> usize {
         match self {
             &Constant::Long(_) => 2,
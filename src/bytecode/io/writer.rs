@@ -43,6 +43,8 @@ impl<'a> ClassWriter<'a> {
 
     fn write_constant(&mut self, constant: &Constant) -> Result<usize, Error> {
         match constant {
+            // Utf8 constants are stored in modified UTF-8 already, with their length limited by the u16 prefix
+            &Constant::Utf8(ref bytes) if bytes.len() > 0xFFFF => Err(Error::new(ErrorKind::InvalidData, "Utf8 constant is too long")),
             &Constant::Utf8(ref bytes) => self.write_u8(1).and(self.write_u16(bytes.len() as u16)).and(self.write_n(bytes)),
             &Constant::Integer(ref value) => self.write_u8(3).and(self.write_u32(*value)),
             &Constant::Float(ref value) => self.write_u8(4).and(self.write_u32(*value)),
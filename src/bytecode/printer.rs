@@ -1,4 +1,5 @@
 use super::classfile::*;
+use super::super::mutf8;
 
 pub struct ClassfilePrinter;
 
@@ -56,7 +57,7 @@ impl ClassfilePrinter {
 
     pub fn render_constant(constant: &Constant, pool: &ConstantPool) -> String {
         match constant {
-            &Constant::Utf8(ref content) => format!("Utf8               {}", String::from_utf8_lossy(content.as_slice())),
+            &Constant::Utf8(ref content) => format!("Utf8               {}", mutf8::decode(content).unwrap_or_else(|| String::from_utf8_lossy(content).into_owned())),
             &Constant::Integer(value) => format!("Integer            {}", value),
             &Constant::Float(value) => format!("Float               {}", value),
             &Constant::Long(value) => format!("Long               {}", value),
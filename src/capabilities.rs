@@ -91,7 +91,14 @@ pub struct Capabilities {
     /// Can generate events when the VM is unable to allocate memory from the JavaTM platform heap.
     pub can_generate_resource_exhaustion_heap_events: bool,
     /// Can generate events when the VM is unable to create a thread.
-    pub can_generate_resource_exhaustion_threads_events: bool
+    pub can_generate_resource_exhaustion_threads_events: bool,
+    /// Can generate the VMStart event early, before the VM has fully initialised the module
+    /// system (Java 9+)
+    pub can_generate_early_vmstart: bool,
+    /// Can generate the ClassLoad and ClassPrepare events in the primordial phase (Java 9+)
+    pub can_generate_early_class_hook_events: bool,
+    /// Can generate sampled object allocation events (Java 11+)
+    pub can_generate_sampled_object_alloc_events: bool
 }
 
 impl Capabilities {
@@ -146,6 +153,9 @@ impl Capabilities {
             can_retransform_any_class:                  native_caps._bindgen_bitfield_2_ & 0x00000040 > 0,
             can_generate_resource_exhaustion_heap_events: native_caps._bindgen_bitfield_2_ & 0x00000080 > 0,
             can_generate_resource_exhaustion_threads_events: native_caps._bindgen_bitfield_2_ & 0x00000100 > 0,
+            can_generate_early_vmstart:                 native_caps._bindgen_bitfield_2_ & 0x00000200 > 0,
+            can_generate_early_class_hook_events:       native_caps._bindgen_bitfield_2_ & 0x00000400 > 0,
+            can_generate_sampled_object_alloc_events:   native_caps._bindgen_bitfield_2_ & 0x00000800 > 0,
         }
     }
 
@@ -200,6 +210,9 @@ impl Capabilities {
         field_map2.insert(0x00000040, self.can_retransform_any_class);
         field_map2.insert(0x00000080, self.can_generate_resource_exhaustion_heap_events);
         field_map2.insert(0x00000100, self.can_generate_resource_exhaustion_threads_events);
+        field_map2.insert(0x00000200, self.can_generate_early_vmstart);
+        field_map2.insert(0x00000400, self.can_generate_early_class_hook_events);
+        field_map2.insert(0x00000800, self.can_generate_sampled_object_alloc_events);
 
         let fields = vec![ field_map1, field_map2, field_map3, field_map4 ];
         let result:Vec<u32> = fields.iter().map(|f| f.iter().map(|(&value, &switch)| if switch { value } else { 0 }).fold(0, |acc, item| acc | item) ).collect();
@@ -220,9 +233,9 @@ impl Capabilities {
 
         let native_merged = jvmtiCapabilities {
                 _bindgen_bitfield_1_: native1._bindgen_bitfield_1_ | native2._bindgen_bitfield_1_,
-                _bindgen_bitfield_2_: native1._bindgen_bitfield_1_ | native2._bindgen_bitfield_2_,
-                _bindgen_bitfield_3_: native1._bindgen_bitfield_1_ | native2._bindgen_bitfield_3_,
-                _bindgen_bitfield_4_: native1._bindgen_bitfield_1_ | native2._bindgen_bitfield_4_
+                _bindgen_bitfield_2_: native1._bindgen_bitfield_2_ | native2._bindgen_bitfield_2_,
+                _bindgen_bitfield_3_: native1._bindgen_bitfield_3_ | native2._bindgen_bitfield_3_,
+                _bindgen_bitfield_4_: native1._bindgen_bitfield_4_ | native2._bindgen_bitfield_4_
         };
 
         Capabilities::from_native(&native_merged)
@@ -272,7 +285,10 @@ impl Display for Capabilities {
             can_retransform_classes: {},\
             can_retransform_any_class: {},\
             can_generate_resource_exhaustion_heap_events: {},\
-            can_generate_resource_exhaustion_threads_events: {})",
+            can_generate_resource_exhaustion_threads_events: {},\
+            can_generate_early_vmstart: {},\
+            can_generate_early_class_hook_events: {},\
+            can_generate_sampled_object_alloc_events: {})",
 
             self.can_tag_objects,
             self.can_generate_field_modification_events,
@@ -314,6 +330,9 @@ impl Display for Capabilities {
             self.can_retransform_classes,
             self.can_retransform_any_class,
             self.can_generate_resource_exhaustion_heap_events,
-            self.can_generate_resource_exhaustion_threads_events)
+            self.can_generate_resource_exhaustion_threads_events,
+            self.can_generate_early_vmstart,
+            self.can_generate_early_class_hook_events,
+            self.can_generate_sampled_object_alloc_events)
     }
 }
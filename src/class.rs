@@ -54,6 +54,50 @@ impl<'a> JavaType<'a> {
         }
     }
 
+    /// Return the types of the arguments of a method descriptor, eg. `(I[JLjava/lang/String;)V`.
+    /// None is returned if the descriptor is malformed.
+    pub fn parse_arguments(descriptor: &'a str) -> Option<Vec<JavaType<'a>>> {
+        let end = match (descriptor.starts_with('('), descriptor.find(')')) {
+            (true, Some(end)) => end,
+            _ => return None
+        };
+
+        let bytes = descriptor.as_bytes();
+        let mut arguments = vec![];
+        let mut idx = 1;
+
+        while idx < end {
+            let start = idx;
+
+            while bytes[idx] == b'[' {
+                idx += 1;
+            }
+
+            idx = match bytes[idx] {
+                b'L' => match descriptor[idx..end].find(';') {
+                    Some(offset) => idx + offset + 1,
+                    None => return None
+                },
+                _ => idx + 1
+            };
+
+            match JavaType::parse(&descriptor[start..idx]) {
+                Some(JavaType::Void) | None => return None,
+                Some(java_type) => arguments.push(java_type)
+            }
+        }
+
+        Some(arguments)
+    }
+
+    /// Return the number of local variable slots a value of this type occupies
+    pub fn slot_size(&self) -> usize {
+        match *self {
+            JavaType::Long | JavaType::Double => 2,
+            _ => 1
+        }
+    }
+
     ///
     /// Converts the given Java type into a conventional human-readable representation
     ///
@@ -104,8 +148,15 @@ impl ClassSignature {
         }
     }
 
+    pub fn unknown() -> ClassSignature {
+        ClassSignature { package: "".to_string(), name: "<UNKNOWN CLASS>".to_string() }
+    }
+
     pub fn to_string(&self) -> String {
-        format!("{}.{}", self.package, self.name)
+        match self.package.is_empty() {
+            true => self.name.clone(),
+            false => format!("{}.{}", self.package, self.name)
+        }
     }
 }
 
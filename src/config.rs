@@ -1,46 +1,782 @@
 extern crate toml;
 
+use serde_json::{self, Value};
+use std::error::Error;
+use std::fmt;
 use std::fs::File;
 use std::io::{ Read };
 use std::path::Path;
 
-#[derive(Deserialize)]
+/// Current version of the configuration schema. Files without a `version` are read as version 1,
+/// which had the instrumentation rules at the top level rather than in `[instrumentation]`.
+pub const SCHEMA_VERSION: u32 = 2;
+/// File the configuration is read from unless the `config` agent option is given
+pub const DEFAULT_CONFIG_FILE: &'static str = "agent.conf";
+
+///
+/// The agent configuration, read from a TOML file with a section per subsystem. Every setting is
+/// optional and falls back to its default.
+///
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
 pub struct Config {
+    /// Version of the schema the configuration is written in
+    pub version: u32,
     pub agent_name: String,
+    /// Directory the collected profiles are written to when the VM shuts down
+    pub output_dir: String,
+    pub instrumentation: InstrumentationConfig,
+    pub sampling: SamplingConfig,
+    pub gc: GcConfig,
+    pub allocations: AllocationsConfig,
+    pub exceptions: ExceptionsConfig,
+    pub locks: LocksConfig,
+    pub timing: TimingConfig,
+    pub trace: TraceConfig,
+    pub slow_invocations: SlowInvocationsConfig,
+    pub export: ExportConfig,
+    pub prometheus: PrometheusConfig,
+    pub statsd: StatsdConfig,
+    pub timeline: TimelineConfig,
+    pub recording: RecordingConfig,
+    pub control: ControlConfig,
+    pub reload: ReloadConfig
+}
+
+///
+/// A problem found in a configuration file, along with the line it's on if it's known. Problems
+/// that don't prevent the configuration from being used, like unknown keys, are reported as
+/// warnings.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConfigError {
+    pub line: Option<usize>,
+    pub message: String
+}
+
+impl ConfigError {
+
+    pub fn new(line: Option<usize>, message: &str) -> ConfigError {
+        ConfigError { line: line, message: message.to_string() }
+    }
+}
+
+impl fmt::Display for ConfigError {
+
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "line {}: {}", line, self.message),
+            None => write!(f, "{}", self.message)
+        }
+    }
+}
+
+impl Error for ConfigError {
+
+    fn description(&self) -> &str {
+        &self.message
+    }
+}
+
+fn default_output_dir() -> String {
+    String::from(".")
+}
+
+///
+/// Instrumentation rules, configured in the `[instrumentation]` section.
+///
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct InstrumentationConfig {
+    /// Methods whose invocations are traced, eg. `java.io.OutputStream.flush`. Classes in the
+    /// default package are written with a leading dot, eg. `.Hello.main`.
     pub entry_points: Vec<String>,
+    /// Classes whose bytecode may be transformed when they're loaded
     pub active_classes: Vec<String>
 }
 
+impl Default for InstrumentationConfig {
+
+    fn default() -> Self {
+        InstrumentationConfig {
+            entry_points: vec![],
+            active_classes: vec![]
+        }
+    }
+}
+
+///
+/// Settings of the sampling profiler, configured in the `[sampling]` section.
+///
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct SamplingConfig {
+    /// Start sampling as soon as the VM has been initialised
+    pub enabled: bool,
+    /// Time between two samples in milliseconds
+    pub interval_ms: u64,
+    /// Maximum number of frames recorded per stack, starting with the innermost one
+    pub max_depth: usize,
+    /// Only sample threads whose name starts with one of these prefixes. Empty means all threads.
+    pub threads: Vec<String>,
+    /// Only sample threads that are running, as opposed to blocked or waiting ones
+    pub runnable_only: bool,
+    /// Time between two samples of the CPU time used by each thread in milliseconds. 0 disables
+    /// sampling CPU times, they're only recorded when threads end then.
+    pub cpu_interval_ms: u64
+}
+
+impl Default for SamplingConfig {
+
+    fn default() -> Self {
+        SamplingConfig {
+            enabled: false,
+            interval_ms: 10,
+            max_depth: 64,
+            threads: vec![],
+            runnable_only: true,
+            cpu_interval_ms: 0
+        }
+    }
+}
+
+///
+/// Settings of the garbage collection pause tracking, configured in the `[gc]` section.
+///
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct GcConfig {
+    pub enabled: bool,
+    /// Pauses longer than this (in milliseconds) are reported as they happen. 0 disables it.
+    pub pause_threshold_ms: u64,
+    /// Total pause time (in milliseconds) allowed within the budget window. 0 disables it.
+    pub budget_ms: u64,
+    /// Length of the sliding window the pause time budget applies to, in milliseconds. It has to
+    /// be greater than 0 while there is a budget.
+    pub budget_window_ms: u64,
+    /// Number of most recent pauses kept in the timeline
+    pub timeline_size: usize,
+    ///
+    /// Count how many of the sampled allocations each collection frees, which needs allocation
+    /// sampling to be enabled. Only the sampled objects are tagged, so the count is a sample of the
+    /// freed objects rather than their total.
+    ///
+    pub count_freed_sampled: bool
+}
+
+impl Default for GcConfig {
+
+    fn default() -> Self {
+        GcConfig {
+            enabled: false,
+            pause_threshold_ms: 100,
+            budget_ms: 100,
+            budget_window_ms: 1000,
+            timeline_size: 1024,
+            count_freed_sampled: false
+        }
+    }
+}
+
+///
+/// Settings of the allocation sampling, configured in the `[allocations]` section. Sampled object
+/// allocations (JDK 11 or later) feed the allocation profile, keyed by the allocating stack.
+///
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct AllocationsConfig {
+    pub enabled: bool,
+    /// Average number of bytes allocated between two samples. 0 samples every allocation.
+    pub sampling_interval: i32
+}
+
+impl Default for AllocationsConfig {
+
+    fn default() -> Self {
+        AllocationsConfig {
+            enabled: false,
+            sampling_interval: 512 * 1024
+        }
+    }
+}
+
+///
+/// Settings of the exception analytics, configured in the `[exceptions]` section.
+///
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct ExceptionsConfig {
+    pub enabled: bool,
+    /// Number of exceptions per second thrown at a single site and caught by a single method from
+    /// which on the site is reported as using exceptions for control flow
+    pub hot_spot_rate: u64,
+    /// Number of exception classes and throw sites listed in the report
+    pub report_limit: usize
+}
+
+impl Default for ExceptionsConfig {
+
+    fn default() -> Self {
+        ExceptionsConfig {
+            enabled: false,
+            hot_spot_rate: 100,
+            report_limit: 20
+        }
+    }
+}
+
+///
+/// Settings of the lock profiling, configured in the `[locks]` section. Monitor contention and
+/// waits are only tracked, and only shown in the timeline, while it's enabled.
+///
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct LocksConfig {
+    pub enabled: bool
+}
+
+impl Default for LocksConfig {
+
+    fn default() -> Self {
+        LocksConfig {
+            enabled: false
+        }
+    }
+}
+
+///
+/// Settings of the method timing statistics, configured in the `[timing]` section. Every method
+/// invocation is timed while it's enabled, regardless of the entry points being traced.
+///
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct TimingConfig {
+    pub enabled: bool
+}
+
+impl Default for TimingConfig {
+
+    fn default() -> Self {
+        TimingConfig {
+            enabled: false
+        }
+    }
+}
+
+///
+/// Settings of the trace mode, configured in the `[trace]` section. Invocations of the configured
+/// entry points are traced along with every call made from them.
+///
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct TraceConfig {
+    /// Maximum depth of the recorded calls, the entry point being at depth 1
+    pub max_depth: usize,
+    /// Calls made later than this (in milliseconds) after entering the entry point aren't recorded
+    pub max_duration_ms: u64,
+    /// Where completed traces are written to: `stdout` or `file`, in which case they're appended
+    /// to `traces.log` in the output directory
+    pub output: String
+}
+
+impl Default for TraceConfig {
+
+    fn default() -> Self {
+        TraceConfig {
+            max_depth: 64,
+            max_duration_ms: 10_000,
+            output: String::from("stdout")
+        }
+    }
+}
+
+///
+/// Settings of the timeline, configured in the `[timeline]` section. The timeline is written in
+/// the Chrome Trace Event Format to `file` in the output directory when the VM exits.
+///
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct TimelineConfig {
+    pub enabled: bool,
+    /// Maximum number of events kept, later ones are dropped
+    pub max_events: usize,
+    pub file: String
+}
+
+impl Default for TimelineConfig {
+
+    fn default() -> Self {
+        TimelineConfig {
+            enabled: false,
+            max_events: 1_000_000,
+            file: String::from("timeline.json")
+        }
+    }
+}
+
+///
+/// Settings of the binary event recording, configured in the `[recording]` section. Events are
+/// streamed to `file` in the output directory, which can be read with `jvmti recording`.
+///
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct RecordingConfig {
+    pub enabled: bool,
+    pub file: String,
+    /// Size in bytes from which on a chunk is written to the file
+    pub chunk_size: usize
+}
+
+impl Default for RecordingConfig {
+
+    fn default() -> Self {
+        RecordingConfig {
+            enabled: false,
+            file: String::from("recording.jrec"),
+            chunk_size: 64 * 1024
+        }
+    }
+}
+
+///
+/// Settings of the local control socket, configured in the `[control]` section. The socket is
+/// created at `path`, or at `/tmp/jvmti-<pid>.sock` if no path is set, and is only accessible to
+/// the user running the VM. Exception events are hooked while the socket is enabled so that
+/// exception profiling can be switched on at runtime.
+///
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct ControlConfig {
+    pub enabled: bool,
+    pub path: Option<String>
+}
+
+impl Default for ControlConfig {
+
+    fn default() -> Self {
+        ControlConfig {
+            enabled: false,
+            path: None
+        }
+    }
+}
+
+///
+/// Settings of the configuration reload, configured in the `[reload]` section. The modification
+/// time of the configuration file is checked every `interval_ms` and changed settings are applied
+/// without restarting the VM.
+///
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct ReloadConfig {
+    pub enabled: bool,
+    pub interval_ms: u64
+}
+
+impl Default for ReloadConfig {
+
+    fn default() -> Self {
+        ReloadConfig {
+            enabled: false,
+            interval_ms: 2_000
+        }
+    }
+}
+
+///
+/// Settings of the slow invocation detector, configured in the `[slow_invocations]` section. The
+/// detector is enabled if any methods are listed.
+///
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct SlowInvocationsConfig {
+    /// The watched methods, written the same way as entry points, eg. `com.example.Dao.query`
+    pub methods: Vec<String>,
+    /// Invocations taking at least this long (in milliseconds) are reported
+    pub threshold_ms: u64,
+    /// Captured argument values longer than this are truncated
+    pub max_argument_length: usize,
+    /// Maximum number of frames in the stack trace of a slow invocation
+    pub stack_depth: usize,
+    /// Number of the slowest invocations listed when the VM exits
+    pub report_limit: usize
+}
+
+impl SlowInvocationsConfig {
+
+    pub fn is_enabled(&self) -> bool {
+        !self.methods.is_empty()
+    }
+
+    /// Return true if the given method is watched
+    pub fn is_watched(&self, package: &str, class_name: &str, method_name: &str) -> bool {
+        self.methods.iter().any(|item| *item == format!("{}.{}.{}", package, class_name, method_name))
+    }
+}
+
+impl Default for SlowInvocationsConfig {
+
+    fn default() -> Self {
+        SlowInvocationsConfig {
+            methods: vec![],
+            threshold_ms: 100,
+            max_argument_length: 200,
+            stack_depth: 32,
+            report_limit: 20
+        }
+    }
+}
+
+///
+/// Settings of the periodic metrics export, configured in the `[export]` section. The export is
+/// enabled if any formats are listed.
+///
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct ExportConfig {
+    /// The formats exported: `jsonl`, `csv` and `table`, each of them written to its own file
+    pub formats: Vec<String>,
+    /// Time between two snapshots in milliseconds
+    pub interval_ms: u64,
+    /// Directory the files are written to, the output directory if not set
+    pub directory: Option<String>,
+    /// Size in bytes from which on a file is rotated
+    pub max_file_size: u64,
+    /// Number of rotated files kept next to the current one
+    pub max_files: usize,
+    /// Maximum number of methods, threads, monitors and exception classes in a snapshot
+    pub limit: usize
+}
+
+impl ExportConfig {
+
+    pub fn is_enabled(&self) -> bool {
+        !self.formats.is_empty()
+    }
+}
+
+impl Default for ExportConfig {
+
+    fn default() -> Self {
+        ExportConfig {
+            formats: vec![],
+            interval_ms: 10_000,
+            directory: None,
+            max_file_size: 10 * 1024 * 1024,
+            max_files: 5,
+            limit: 50
+        }
+    }
+}
+
+///
+/// Settings of the embedded HTTP server exposing metrics in the Prometheus text format, configured
+/// in the `[prometheus]` section. The server is started if a port is set.
+///
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct PrometheusConfig {
+    /// Address the server listens on, only local connections are accepted by default
+    pub address: String,
+    pub port: Option<u16>,
+    /// Path the metrics are served on, every other path is answered with 404
+    pub path: String,
+    /// Maximum number of methods, threads, monitors and exception classes exposed
+    pub limit: usize
+}
+
+impl PrometheusConfig {
+
+    pub fn is_enabled(&self) -> bool {
+        self.port.is_some()
+    }
+}
+
+impl Default for PrometheusConfig {
+
+    fn default() -> Self {
+        PrometheusConfig {
+            address: String::from("127.0.0.1"),
+            port: None,
+            path: String::from("/metrics"),
+            limit: 50
+        }
+    }
+}
+
+///
+/// Settings of the StatsD emitter, configured in the `[statsd]` section. Counters are sent as the
+/// increase since the previous flush, every other metric as a gauge.
+///
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct StatsdConfig {
+    pub enabled: bool,
+    pub host: String,
+    pub port: u16,
+    /// Prefix of the metric names, separated from them by a dot
+    pub prefix: String,
+    /// Send the labels of the metrics and the agent ID as DogStatsD tags. Otherwise the label
+    /// values are appended to the metric names.
+    pub dogstatsd: bool,
+    /// Additional tags sent with every metric, eg. `env:production`
+    pub tags: Vec<String>,
+    /// Time between two flushes in milliseconds
+    pub interval_ms: u64,
+    /// Maximum number of methods, threads, monitors and exception classes sent
+    pub limit: usize,
+    /// Maximum size of a single datagram in bytes
+    pub max_packet_size: usize
+}
+
+impl Default for StatsdConfig {
+
+    fn default() -> Self {
+        StatsdConfig {
+            enabled: false,
+            host: String::from("127.0.0.1"),
+            port: 8125,
+            prefix: String::from("jvmti"),
+            dogstatsd: true,
+            tags: vec![],
+            interval_ms: 10_000,
+            limit: 50,
+            max_packet_size: 1432
+        }
+    }
+}
+
 impl Config {
 
-    pub fn read_config() -> Option<Config> {
-        let default_config: String = String::from("agent.conf");
+    /// Return the directory exported metrics are written to
+    pub fn export_dir(&self) -> String {
+        self.export.directory.clone().unwrap_or(self.output_dir.clone())
+    }
+
+    /// Return true if the given method, eg. `java.io.OutputStream.flush`, is a configured entry
+    /// point. Classes in the default package are written with a leading dot, eg. `.Hello.main`.
+    pub fn is_entry_point(&self, package: &str, class_name: &str, method_name: &str) -> bool {
+        self.instrumentation.entry_points.iter().any(|item| *item == format!("{}.{}.{}", package, class_name, method_name))
+    }
+
+    ///
+    /// Read the configuration from the given file, returning it along with warnings about settings
+    /// that have been ignored.
+    ///
+    pub fn read_from_file<T: AsRef<Path>>(file_name: T) -> Result<(Config, Vec<ConfigError>), ConfigError> {
+        let mut contents = String::new();
+
+        File::open(file_name).and_then(|mut file| file.read_to_string(&mut contents))
+            .map_err(|err| ConfigError::new(None, &err.to_string()))?;
 
-        Config::read_from_file(default_config)
+        Config::parse(&contents)
     }
 
-    pub fn read_from_file<T: AsRef<Path>>(file_name: T) -> Option<Config> {
-        match File::open(file_name) {
-            Ok(mut file) => {
-                let mut contents = String::new();
-                let _ = file.read_to_string(&mut contents);
+    ///
+    /// Parse a configuration, returning it along with warnings about unknown keys and deprecated
+    /// settings. Syntax errors, values of the wrong type and invalid settings are reported as
+    /// errors, pointing at the line they're on.
+    ///
+    pub fn parse(text: &str) -> Result<(Config, Vec<ConfigError>), ConfigError> {
+        let mut value: toml::Value = toml::from_str(text).map_err(|err| {
+            let message = err.to_string();
 
-                let config: Config = toml::from_str(contents.as_str()).unwrap();
+            // The line is reported separately
+            ConfigError::new(err.line_col().map(|(line, _)| line + 1), message.split(" at line ").next().unwrap_or(&message))
+        })?;
 
-                Some(config)
-            },
-            _ => None
+        let version = match value.get("version") {
+            None => 1,
+            Some(&toml::Value::Integer(version)) if version >= 1 && version <= SCHEMA_VERSION as i64 => version as u32,
+            Some(other) => return Err(ConfigError::new(line_of(text, &["version"]), &format!("unsupported configuration version {}, expected 1 to {}", other, SCHEMA_VERSION)))
+        };
+
+        let mut warnings = vec![];
+
+        if version < 2 {
+            migrate_instrumentation(&mut value, text, &mut warnings);
+        }
+
+        let schema = serde_json::to_value(Config::default()).unwrap_or(Value::Null);
+
+        unknown_keys(&value, &schema, &mut vec![], text, &mut warnings);
+
+        let mut config: Config = value.try_into().map_err(|err| {
+            let message = err.to_string();
+            let line = message.rfind("for key `").and_then(|idx| {
+                let path: Vec<&str> = message[idx + 9..].trim_end_matches('`').split('.').collect();
+
+                line_of(text, &path)
+            });
+
+            ConfigError::new(line, &message)
+        })?;
+
+        config.version = version;
+        config.validate(text)?;
+
+        Ok((config, warnings))
+    }
+
+    /// Check the settings that can't be told apart by their type alone
+    fn validate(&self, text: &str) -> Result<(), ConfigError> {
+        let intervals = [
+            (&["sampling", "interval_ms"], self.sampling.interval_ms),
+            (&["export", "interval_ms"], self.export.interval_ms),
+            (&["statsd", "interval_ms"], self.statsd.interval_ms),
+            (&["reload", "interval_ms"], self.reload.interval_ms),
+            (&["recording", "chunk_size"], self.recording.chunk_size as u64)
+        ];
+
+        if let Some(&(path, _)) = intervals.iter().find(|&&(_, value)| value == 0) {
+            return Err(ConfigError::new(line_of(text, path), &format!("`{}` must be greater than 0", path.join("."))));
+        }
+
+        // Pauses would leave an empty window as soon as they've been added, never exceeding the budget
+        if self.gc.budget_ms > 0 && self.gc.budget_window_ms == 0 {
+            let path = &["gc", "budget_window_ms"];
+            return Err(ConfigError::new(line_of(text, path), "`gc.budget_window_ms` must be greater than 0 if `gc.budget_ms` is set"));
+        }
+
+        if self.allocations.sampling_interval < 0 {
+            let path = &["allocations", "sampling_interval"];
+            return Err(ConfigError::new(line_of(text, path), "`allocations.sampling_interval` must not be negative"));
+        }
+
+        // Only sampled allocations are tagged, nothing would ever be counted
+        if self.gc.enabled && self.gc.count_freed_sampled && !self.allocations.enabled {
+            let path = &["gc", "count_freed_sampled"];
+            return Err(ConfigError::new(line_of(text, path), "`gc.count_freed_sampled` needs `allocations.enabled` to be set"));
+        }
+
+        Ok(())
+    }
+}
+
+///
+/// Move the instrumentation rules of a version 1 configuration, which were at the top level, into
+/// the `[instrumentation]` section. Rules already in the section take precedence.
+///
+fn migrate_instrumentation(value: &mut toml::Value, text: &str, warnings: &mut Vec<ConfigError>) {
+    let table = match *value {
+        toml::Value::Table(ref mut table) => table,
+        _ => return
+    };
+
+    for key in ["entry_points", "active_classes"].iter() {
+        if let Some(rule) = table.remove(*key) {
+            warnings.push(ConfigError::new(line_of(text, &[key]), &format!("`{}` should be set in the [instrumentation] section since version 2", key)));
+
+            let section = table.entry("instrumentation".to_string()).or_insert_with(|| toml::Value::Table(toml::value::Table::new()));
+
+            if let toml::Value::Table(ref mut section) = *section {
+                section.entry(key.to_string()).or_insert(rule);
+            }
         }
     }
 }
 
+/// Warn about the keys of the configuration that aren't part of the schema, suggesting known keys
+/// that are spelled similarly
+fn unknown_keys(value: &toml::Value, schema: &Value, path: &mut Vec<String>, text: &str, warnings: &mut Vec<ConfigError>) {
+    let (table, known) = match (value, schema) {
+        (&toml::Value::Table(ref table), &Value::Object(ref known)) => (table, known),
+        _ => return
+    };
+
+    for (key, value) in table.iter() {
+        path.push(key.clone());
+
+        match known.get(key) {
+            Some(schema) => unknown_keys(value, schema, path, text, warnings),
+            None => {
+                let keys: Vec<&str> = path.iter().map(|key| key.as_str()).collect();
+                let suggestion = known.keys().filter(|candidate| edit_distance(key, candidate) <= 2).min_by_key(|candidate| edit_distance(key, candidate));
+
+                warnings.push(ConfigError::new(line_of(text, &keys), &match suggestion {
+                    Some(candidate) => format!("unknown key `{}`, did you mean `{}`?", keys.join("."), candidate),
+                    None => format!("unknown key `{}`", keys.join("."))
+                }));
+            }
+        }
+
+        path.pop();
+    }
+}
+
+/// Return the number of single character edits needed to turn one string into the other
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..b.len() + 1).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut previous = row[0];
+
+        row[0] = i + 1;
+
+        for (j, cb) in b.iter().enumerate() {
+            let current = row[j + 1];
+
+            row[j + 1] = (previous + if ca == *cb { 0 } else { 1 }).min(row[j] + 1).min(current + 1);
+            previous = current;
+        }
+    }
+
+    row[b.len()]
+}
+
+///
+/// Return the number of the line the given key is defined on, eg. the line of `interval_ms` in the
+/// `[sampling]` table for `["sampling", "interval_ms"]`, or the header of a table. Only keys
+/// written one per line are found, not ones in inline tables.
+///
+fn line_of(text: &str, path: &[&str]) -> Option<usize> {
+    let mut table: Vec<String> = vec![];
+
+    for (idx, line) in text.lines().enumerate() {
+        let line = line.trim();
+
+        if line.starts_with('[') {
+            table = line.trim_start_matches('[').split(']').next().unwrap_or("").split('.').map(|part| part.trim().trim_matches('"').to_string()).collect();
+
+            if table.len() == path.len() && table.iter().zip(path.iter()).all(|(a, b)| a == b) {
+                return Some(idx + 1);
+            }
+        } else if let Some(eq) = line.find('=') {
+            let key = line[..eq].trim().trim_matches('"');
+
+            if table.len() + 1 == path.len() && table.iter().zip(path.iter()).all(|(a, b)| a == b) && key == path[table.len()] {
+                return Some(idx + 1);
+            }
+        }
+    }
+
+    None
+}
+
 impl Default for Config {
 
     fn default() -> Self {
         Config {
+            version: SCHEMA_VERSION,
             agent_name: String::from("default"),
-            entry_points: vec![],
-            active_classes: vec![]
+            output_dir: default_output_dir(),
+            instrumentation: InstrumentationConfig::default(),
+            sampling: SamplingConfig::default(),
+            gc: GcConfig::default(),
+            allocations: AllocationsConfig::default(),
+            exceptions: ExceptionsConfig::default(),
+            locks: LocksConfig::default(),
+            timing: TimingConfig::default(),
+            trace: TraceConfig::default(),
+            slow_invocations: SlowInvocationsConfig::default(),
+            export: ExportConfig::default(),
+            prometheus: PrometheusConfig::default(),
+            statsd: StatsdConfig::default(),
+            timeline: TimelineConfig::default(),
+            recording: RecordingConfig::default(),
+            control: ControlConfig::default(),
+            reload: ReloadConfig::default()
         }
     }
 }
@@ -1,10 +1,26 @@
-use super::config::Config;
-use super::thread::ThreadId;
-use std::collections::HashMap;
-use std::sync::{Arc, RwLock};
-use time::Duration;
-use time::Tm;
-use time::now;
+use super::config::{Config, ExceptionsConfig, SlowInvocationsConfig, TraceConfig};
+use super::logging::logger;
+use super::options::Options;
+use super::profile::alloc::{AllocationProfile, AllocationSite, AllocationStats};
+use super::profile::calltree::{CallTreeNode, CallTreeProfile};
+use super::profile::exceptions::{ExceptionProfile, ExceptionStats};
+use super::profile::flamegraph::{FoldedStacks, ToFolded};
+use super::profile::gc::GcPause;
+use super::profile::locks::{ContentionSite, ContentionStats, LockProfile, WaitStats};
+use super::profile::sampling::SampleProfile;
+use super::profile::slow::{SlowInvocation, SlowInvocationDetector};
+use super::profile::threads::{ThreadRecord, ThreadTable};
+use super::profile::timeline::Timeline;
+use super::profile::timing::{MethodStats, MethodTimings};
+use super::profile::trace::{ConsoleTraceSink, Trace, TraceRecorder, TraceSink};
+use super::runtime::{ExceptionCatchEvent, ExceptionEvent, MethodInvocationEvent, MonitorEvent, MonitorWaitEvent, MonitorWaitedEvent, ObjectAllocationEvent, ThreadEvent};
+use super::thread::Thread;
+use std::io;
+use std::mem;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::{Arc, Mutex, RwLock};
+use time::{get_time, precise_time_ns, Duration};
 
 ///
 /// Public static mutable *cough* agent context. This seems necessary as our code is invoked from
@@ -25,18 +41,36 @@ pub fn static_context() -> &'static AgentContext {
 
 pub struct AgentContext {
     context: Arc<RwLock<Context>>,
-    pub config: Arc<RwLock<Config>>
+    pub config: Arc<RwLock<Config>>,
+    agent_id: Arc<RwLock<String>>,
+    config_file: Arc<RwLock<Option<PathBuf>>>,
+    trace_sink: Arc<Mutex<Box<TraceSink + Send>>>
 }
 
 impl AgentContext {
     pub fn new() -> AgentContext {
         AgentContext {
             context: Arc::new(RwLock::new(Context::new())),
-            config: Arc::new(RwLock::new(Config::default()))
+            config: Arc::new(RwLock::new(Config::default())),
+            agent_id: Arc::new(RwLock::new(Options::default().agent_id)),
+            config_file: Arc::new(RwLock::new(None)),
+            trace_sink: Arc::new(Mutex::new(Box::new(ConsoleTraceSink)))
         }
     }
 
     pub fn set_config(&self, config: Config) {
+        match self.context.write() {
+            Ok(mut ctx) => {
+                (*ctx).traces = TraceRecorder::new(config.trace.max_depth, config.trace.max_duration_ms * 1_000_000);
+                (*ctx).slow_invocations = SlowInvocationDetector::new(config.slow_invocations.threshold_ms * 1_000_000, config.slow_invocations.report_limit);
+                (*ctx).timeline = match config.timeline.enabled {
+                    true => Some(Timeline::new(precise_time_ns(), config.timeline.max_events, process::id())),
+                    false => None
+                };
+            },
+            Err(_) => { /* TODO */ }
+        }
+
         match self.config.write() {
             Ok(mut cfg) => {
                 *cfg = config;
@@ -45,29 +79,165 @@ impl AgentContext {
         }
     }
 
-    pub fn thread_start(&self, thread_id: &ThreadId) {
+    ///
+    /// Replace the configuration of a running agent, returning the configuration it's replaced. The
+    /// configuration is swapped under a single lock, so changes made concurrently are applied in
+    /// order rather than lost. Unlike `set_config`, recorded traces and slow invocations are only
+    /// dropped if their own settings have changed. The timeline is left alone, it's enabled and
+    /// disabled separately. Returns `None` if the configuration is unavailable.
+    ///
+    pub fn update_config(&self, config: Config) -> Option<Config> {
+        let previous = match self.config.write() {
+            Ok(mut cfg) => mem::replace(&mut *cfg, config.clone()),
+            Err(_) => {
+                error!(logger(), "Couldn't update the configuration, its lock has been poisoned");
+                return None;
+            }
+        };
+
         match self.context.write() {
             Ok(mut ctx) => {
-                (*ctx).thread_lifetime.insert((*thread_id).clone(), now());
+                if previous.trace.max_depth != config.trace.max_depth || previous.trace.max_duration_ms != config.trace.max_duration_ms {
+                    (*ctx).traces = TraceRecorder::new(config.trace.max_depth, config.trace.max_duration_ms * 1_000_000);
+                }
+
+                if previous.slow_invocations.threshold_ms != config.slow_invocations.threshold_ms || previous.slow_invocations.report_limit != config.slow_invocations.report_limit {
+                    (*ctx).slow_invocations = SlowInvocationDetector::new(config.slow_invocations.threshold_ms * 1_000_000, config.slow_invocations.report_limit);
+                }
+            },
+            Err(_) => error!(logger(), "Couldn't reset traces and slow invocations, the agent context lock has been poisoned")
+        }
+
+        Some(previous)
+    }
+
+    pub fn set_agent_id(&self, agent_id: &str) {
+        match self.agent_id.write() {
+            Ok(mut id) => {
+                *id = agent_id.to_string();
+            },
+            Err(_) => error!(logger(), "Couldn't set the agent identifier, its lock has been poisoned")
+        }
+    }
+
+    /// Return the identifier of this agent instance, given by the `agentid` option
+    pub fn agent_id(&self) -> String {
+        match self.agent_id.read() {
+            Ok(id) => (*id).clone(),
+            Err(_) => Options::default().agent_id
+        }
+    }
+
+    pub fn set_config_file<P: AsRef<Path>>(&self, path: P) {
+        match self.config_file.write() {
+            Ok(mut file) => {
+                *file = Some(path.as_ref().to_path_buf());
+            },
+            Err(_) => error!(logger(), "Couldn't set the configuration file, its lock has been poisoned")
+        }
+    }
+
+    /// Return the file the configuration has been read from, if it's been read from one
+    pub fn config_file(&self) -> Option<PathBuf> {
+        match self.config_file.read() {
+            Ok(file) => (*file).clone(),
+            Err(_) => None
+        }
+    }
+
+    pub fn thread_start(&self, event: &ThreadEvent) {
+        match self.context.write() {
+            Ok(mut ctx) => {
+                (*ctx).threads.start(event.thread.java_id, &event.thread, wall_clock_ns());
+
+                if let Some(ref mut timeline) = (*ctx).timeline {
+                    timeline.thread_start(event.thread.java_id, &event.thread.name, precise_time_ns());
+                }
             },
             Err(_) => { /* TODO: Ignore for now */ }
         }
     }
 
-    pub fn thread_end(&self, thread_id: &ThreadId) -> Option<Duration> {
+    /// Record the end of a thread and return how long it has lived
+    pub fn thread_end(&self, event: &ThreadEvent) -> Option<Duration> {
         match self.context.write() {
             Ok(mut ctx) => {
-                let now = now();
-                Some((*ctx).thread_lifetime.remove(thread_id).unwrap_or(now) - now)
+                let thread_id = event.thread.java_id;
+
+                (*ctx).methods.thread_end(thread_id);
+                (*ctx).call_trees.thread_end(thread_id);
+                (*ctx).locks.thread_end(thread_id);
+                (*ctx).exceptions.thread_end(thread_id);
+                (*ctx).traces.thread_end(thread_id);
+                (*ctx).slow_invocations.thread_end(thread_id);
+
+                if let Some(ref mut timeline) = (*ctx).timeline {
+                    timeline.thread_end(thread_id, &event.thread.name, precise_time_ns());
+                }
+
+                let now = wall_clock_ns();
+                let record = (*ctx).threads.end(thread_id, &event.thread, now, event.cpu_time);
+
+                Some(Duration::nanoseconds(record.lifetime(now) as i64))
             },
             Err(_) => { None /* TODO: Ignore for now */ }
         }
     }
 
-    pub fn monitor_enter(&self, thread_id: &ThreadId) {
+    /// Record a sample of the CPU time used by a live thread so far, in nanoseconds
+    pub fn thread_cpu_sample(&self, java_id: i64, thread: &Thread, cpu_time: u64) {
+        match self.context.write() {
+            Ok(mut ctx) => {
+                (*ctx).threads.cpu_sample(java_id, thread, wall_clock_ns(), cpu_time);
+            },
+            Err(_) => { /* TODO: Ignoring for now */ }
+        }
+    }
+
+    /// Return the record of a live thread
+    pub fn thread_record(&self, java_id: i64) -> Option<ThreadRecord> {
+        match self.context.read() {
+            Ok(ctx) => (*ctx).threads.get(java_id).cloned(),
+            Err(_) => None
+        }
+    }
+
+    /// Return the threads, live or ended, that have used the most CPU time
+    pub fn top_threads(&self, limit: usize) -> Vec<ThreadRecord> {
+        match self.context.read() {
+            Ok(ctx) => (*ctx).threads.top_by_cpu(limit),
+            Err(_) => vec![]
+        }
+    }
+
+    /// Return the live threads that have used the most CPU time since the previous sample
+    pub fn busiest_threads(&self, limit: usize) -> Vec<ThreadRecord> {
+        match self.context.read() {
+            Ok(ctx) => (*ctx).threads.top_by_recent_cpu(limit),
+            Err(_) => vec![]
+        }
+    }
+
+    /// Return the number of threads started and ended so far
+    pub fn thread_churn(&self) -> (u64, u64) {
+        match self.context.read() {
+            Ok(ctx) => ((*ctx).threads.started(), (*ctx).threads.ended()),
+            Err(_) => (0, 0)
+        }
+    }
+
+    /// Return the number of threads that have started but not ended yet
+    pub fn live_thread_count(&self) -> usize {
+        match self.context.read() {
+            Ok(ctx) => (*ctx).threads.live().len(),
+            Err(_) => 0
+        }
+    }
+
+    pub fn monitor_enter(&self, event: &MonitorEvent) {
         match self.context.write() {
             Ok(mut ctx) => {
-                (*ctx).monitor_queue.insert((*thread_id).clone(), now());
+                (*ctx).locks.contended_enter(event, precise_time_ns());
             },
             Err(_) => {
                 // TODO: Ignore this
@@ -75,91 +245,428 @@ impl AgentContext {
         }
     }
 
-    pub fn monitor_entered(&self, thread_id: &ThreadId) -> Option<Duration> {
+    pub fn monitor_entered(&self, thread_id: i64) -> Option<Duration> {
         match self.context.write() {
             Ok(mut ctx) => {
-                let now = now();
-                Some((*ctx).monitor_queue.remove(thread_id).unwrap_or(now) - now)
+                (*ctx).locks.contended_entered(thread_id, precise_time_ns())
+                    .map(|elapsed| Duration::nanoseconds(elapsed as i64))
             },
             Err(_) => { None /* TODO: Ignore for now */ }
         }
     }
 
-    pub fn wait_start(&self, thread_id: &ThreadId) {
+    pub fn wait_start(&self, event: &MonitorWaitEvent) {
         match self.context.write() {
             Ok(mut ctx) => {
-                (*ctx).thread_wait.insert((*thread_id).clone(), now());
+                let now = precise_time_ns();
+
+                (*ctx).locks.wait_start(event, now);
+
+                if let Some(ref mut timeline) = (*ctx).timeline {
+                    timeline.wait_start(event.thread.java_id, &event.class_sig.to_string(), now);
+                }
             },
             Err(_) => { /* TODO: Ignore for now */ }
         }
     }
 
-    pub fn wait_end(&self, thread_id: &ThreadId) -> Option<Duration> {
+    pub fn wait_end(&self, event: &MonitorWaitedEvent) -> Option<Duration> {
         match self.context.write() {
             Ok(mut ctx) => {
-                let now = now();
-                Some((*ctx).thread_wait.remove(thread_id).unwrap_or(now) - now)
+                let now = precise_time_ns();
+
+                if let Some(ref mut timeline) = (*ctx).timeline {
+                    timeline.wait_end(event.thread.java_id, &event.thread.name, event.timed_out, now);
+                }
+
+                (*ctx).locks.wait_end(event, now)
+                    .map(|elapsed| Duration::nanoseconds(elapsed as i64))
             },
             Err(_) => { None /* TODO: Ignoring for now */ }
         }
     }
 
-    pub fn method_enter(&self, thread_id: &ThreadId) {
+    /// Add a garbage collection pause to the timeline, if it's enabled
+    pub fn timeline_gc_pause(&self, pause: &GcPause) {
         match self.context.write() {
             Ok(mut ctx) => {
-                let now = now();
+                if let Some(ref mut timeline) = (*ctx).timeline {
+                    timeline.gc_pause(pause);
+                }
+            },
+            Err(_) => { /* TODO: Ignoring for now */ }
+        }
+    }
 
-                let new_stack = match (*ctx).method_times.remove(thread_id) {
-                    Some(mut thread_stack) => {
-                        thread_stack.push(now);
-                        thread_stack
-                    },
-                    None => {
-                        let new_vec = vec![ now ];
-                        new_vec
-                    }
-                };
+    /// Write the timeline to the given file, if it's enabled. Returns the number of events written.
+    pub fn write_timeline<P: AsRef<Path>>(&self, path: P) -> io::Result<Option<usize>> {
+        match self.context.read() {
+            Ok(ctx) => match (*ctx).timeline {
+                Some(ref timeline) => timeline.write(path, precise_time_ns()).map(|_| Some(timeline.len())),
+                None => Ok(None)
+            },
+            Err(_) => Ok(None)
+        }
+    }
 
-                (*ctx).method_times.insert((*thread_id).clone(), new_stack);
+    /// Start a new timeline if none is being recorded, returning false if one was already
+    pub fn enable_timeline(&self, max_events: usize) -> bool {
+        match self.context.write() {
+            Ok(mut ctx) => match (*ctx).timeline.is_none() {
+                true => {
+                    (*ctx).timeline = Some(Timeline::new(precise_time_ns(), max_events, process::id()));
+                    true
+                },
+                false => false
+            },
+            Err(_) => { false /* TODO: Ignoring for now */ }
+        }
+    }
+
+    /// Stop recording the timeline and return it, if it was enabled
+    pub fn disable_timeline(&self) -> Option<Timeline> {
+        match self.context.write() {
+            Ok(mut ctx) => (*ctx).timeline.take(),
+            Err(_) => { None /* TODO: Ignoring for now */ }
+        }
+    }
+
+    /// Return the number of events in the timeline, if it's enabled
+    pub fn timeline_len(&self) -> Option<usize> {
+        match self.context.read() {
+            Ok(ctx) => (*ctx).timeline.as_ref().map(|timeline| timeline.len()),
+            Err(_) => None
+        }
+    }
+
+    /// Return the monitors threads have been blocked on the longest, along with the blocked stacks
+    pub fn top_contended_monitors(&self, limit: usize) -> Vec<(ContentionSite, ContentionStats)> {
+        match self.context.read() {
+            Ok(ctx) => (*ctx).locks.top_contended(limit),
+            Err(_) => vec![]
+        }
+    }
+
+    /// Return the objects threads have been waiting on the longest in `Object.wait`
+    pub fn top_monitor_waits(&self, limit: usize) -> Vec<(ContentionSite, WaitStats)> {
+        match self.context.read() {
+            Ok(ctx) => (*ctx).locks.top_waits(limit),
+            Err(_) => vec![]
+        }
+    }
+
+    /// Return the contended monitor sites as stacks, weighted by the time spent blocked
+    pub fn lock_stacks(&self) -> FoldedStacks {
+        match self.context.read() {
+            Ok(ctx) => (*ctx).locks.to_folded(),
+            Err(_) => FoldedStacks::new()
+        }
+    }
+
+    pub fn reset_lock_stats(&self) {
+        match self.context.write() {
+            Ok(mut ctx) => {
+                (*ctx).locks.reset();
             },
             Err(_) => { /* TODO: Ignoring for now */ }
         }
     }
 
-    pub fn method_exit(&self, thread_id: &ThreadId) -> Option<Duration> {
+    pub fn exception_thrown(&self, event: &ExceptionEvent) {
         match self.context.write() {
             Ok(mut ctx) => {
-                let now = now();
+                (*ctx).exceptions.thrown(event, precise_time_ns());
+            },
+            Err(_) => { /* TODO: Ignoring for now */ }
+        }
+    }
+
+    /// Record a caught exception and return the number of frames it has travelled up the stack
+    pub fn exception_caught(&self, event: &ExceptionCatchEvent) -> Option<u64> {
+        match self.context.write() {
+            Ok(mut ctx) => (*ctx).exceptions.caught(event),
+            Err(_) => { None /* TODO: Ignoring for now */ }
+        }
+    }
+
+    /// Return the exception classes thrown the most
+    pub fn top_exception_types(&self, limit: usize) -> Vec<(String, ExceptionStats)> {
+        match self.context.read() {
+            Ok(ctx) => (*ctx).exceptions.top_types(limit),
+            Err(_) => vec![]
+        }
+    }
+
+    /// Render the exception report, using the limits from the configuration
+    pub fn exception_report(&self) -> Vec<String> {
+        let config = match self.config.read() {
+            Ok(cfg) => (*cfg).exceptions.clone(),
+            Err(_) => ExceptionsConfig::default()
+        };
+
+        match self.context.read() {
+            Ok(ctx) => (*ctx).exceptions.report_lines(config.report_limit, config.hot_spot_rate),
+            Err(_) => vec![]
+        }
+    }
 
-                match (*ctx).method_times.get_mut(thread_id) {
-                    Some(ref mut thread_stack) => match thread_stack.pop() {
-                        Some(time) => Some(time - now),
-                        None => None
-                    },
-                    None => None
+    pub fn reset_exceptions(&self) {
+        match self.context.write() {
+            Ok(mut ctx) => {
+                (*ctx).exceptions.reset();
+            },
+            Err(_) => { /* TODO: Ignoring for now */ }
+        }
+    }
+
+    pub fn method_enter(&self, event: &MethodInvocationEvent) {
+        match self.context.write() {
+            Ok(mut ctx) => {
+                let now = precise_time_ns();
+
+                (*ctx).methods.enter(event.thread.java_id, &event.method_key(), now);
+                (*ctx).call_trees.enter(event.thread.java_id, &event.method_name(), now);
+            },
+            Err(_) => { /* TODO: Ignoring for now */ }
+        }
+    }
+
+    ///
+    /// Record a method entry in the trace being recorded on the thread, if any, or start a new
+    /// trace if the method is an entry point
+    ///
+    pub fn trace_enter(&self, event: &MethodInvocationEvent, is_entry_point: bool) {
+        match self.context.write() {
+            Ok(mut ctx) => {
+                (*ctx).traces.enter(event.thread.java_id, &event.thread.name, &event.method_name(), precise_time_ns(), is_entry_point);
+            },
+            Err(_) => { /* TODO: Ignoring for now */ }
+        }
+    }
+
+    /// Record a method exit in the trace being recorded on the thread and return the trace if it
+    /// has been completed
+    pub fn trace_exit(&self, event: &MethodInvocationEvent) -> Option<Trace> {
+        match self.context.write() {
+            Ok(mut ctx) => {
+                let trace = (*ctx).traces.exit(event.thread.java_id, &event.method_name(), precise_time_ns(), event.popped_by_exception);
+
+                if let (&Some(ref trace), &mut Some(ref mut timeline)) = (&trace, &mut (*ctx).timeline) {
+                    timeline.trace(trace);
+                }
+
+                trace
+            },
+            Err(_) => { None /* TODO: Ignoring for now */ }
+        }
+    }
+
+    ///
+    /// Record that a watched method has been entered with the given arguments. Returns true if the
+    /// thread has just entered its outermost watched method.
+    ///
+    pub fn invocation_enter(&self, event: &MethodInvocationEvent, arguments: Vec<String>) -> bool {
+        match self.context.write() {
+            Ok(mut ctx) => (*ctx).slow_invocations.enter(event.thread.java_id, &event.method_name(), arguments, precise_time_ns()),
+            Err(_) => { false /* TODO: Ignoring for now */ }
+        }
+    }
+
+    /// Record that a watched method has exited and return the invocation if it's been slow
+    pub fn invocation_exit(&self, event: &MethodInvocationEvent) -> Option<SlowInvocation> {
+        match self.context.write() {
+            Ok(mut ctx) => (*ctx).slow_invocations.exit(event.thread.java_id, &event.thread.name, &event.method_name(), precise_time_ns(), event.popped_by_exception),
+            Err(_) => { None /* TODO: Ignoring for now */ }
+        }
+    }
+
+    /// Return true if the given thread is inside a watched method
+    pub fn is_watching_invocations(&self, thread_id: i64) -> bool {
+        match self.context.read() {
+            Ok(ctx) => (*ctx).slow_invocations.is_watching(thread_id),
+            Err(_) => false
+        }
+    }
+
+    pub fn record_slow_invocation(&self, invocation: SlowInvocation) {
+        match self.context.write() {
+            Ok(mut ctx) => {
+                (*ctx).slow_invocations.record(invocation);
+            },
+            Err(_) => { /* TODO: Ignoring for now */ }
+        }
+    }
+
+    /// Return the number of slow invocations and at most `limit` of the slowest ones
+    pub fn slowest_invocations(&self, limit: usize) -> (u64, Vec<SlowInvocation>) {
+        match self.context.read() {
+            Ok(ctx) => ((*ctx).slow_invocations.count(), (*ctx).slow_invocations.slowest(limit)),
+            Err(_) => (0, vec![])
+        }
+    }
+
+    /// Replace the destination of completed traces, which is the standard output by default
+    pub fn set_trace_sink(&self, sink: Box<TraceSink + Send>) {
+        match self.trace_sink.lock() {
+            Ok(mut trace_sink) => {
+                *trace_sink = sink;
+            },
+            Err(_) => { /* TODO: Ignoring for now */ }
+        }
+    }
+
+    pub fn write_trace(&self, trace: &Trace) {
+        match self.trace_sink.lock() {
+            Ok(mut sink) => {
+                if let Err(err) = sink.write_trace(trace) {
+                    warn!(logger(), "Couldn't write trace: {}", err);
                 }
             },
+            Err(_) => { /* TODO: Ignoring for now */ }
+        }
+    }
+
+    pub fn method_exit(&self, event: &MethodInvocationEvent) -> Option<Duration> {
+        match self.context.write() {
+            Ok(mut ctx) => {
+                let now = precise_time_ns();
+
+                (*ctx).call_trees.exit(event.thread.java_id, &event.method_name(), now);
+                (*ctx).methods.exit(event.thread.java_id, &event.method_key(), now, event.popped_by_exception)
+                    .map(|elapsed| Duration::nanoseconds(elapsed as i64))
+            },
             Err(_) => { None /* TODO Ignoring for now */ }
         }
     }
+
+    /// Return the timing statistics collected for the given method, eg. `java.lang.Thread.run()V`
+    pub fn method_stats(&self, method: &str) -> Option<MethodStats> {
+        match self.context.read() {
+            Ok(ctx) => (*ctx).methods.get(method).cloned(),
+            Err(_) => None
+        }
+    }
+
+    /// Return the methods with the most time spent in them, including the time spent in callees
+    pub fn top_methods(&self, limit: usize) -> Vec<(String, MethodStats)> {
+        match self.context.read() {
+            Ok(ctx) => (*ctx).methods.top_methods(limit),
+            Err(_) => vec![]
+        }
+    }
+
+    /// Return the calling context trees of all threads merged into a single tree
+    pub fn call_tree(&self) -> Option<CallTreeNode> {
+        match self.context.read() {
+            Ok(ctx) => Some((*ctx).call_trees.merged()),
+            Err(_) => None
+        }
+    }
+
+    pub fn reset_method_stats(&self) {
+        match self.context.write() {
+            Ok(mut ctx) => {
+                (*ctx).methods.reset();
+                (*ctx).call_trees.reset();
+            },
+            Err(_) => { /* TODO: Ignoring for now */ }
+        }
+    }
+
+    pub fn object_allocated(&self, event: &ObjectAllocationEvent) {
+        match self.context.write() {
+            Ok(mut ctx) => {
+                (*ctx).allocations.record(event);
+            },
+            Err(_) => { /* TODO: Ignoring for now */ }
+        }
+    }
+
+    /// Record a sampled stack trace, starting with the innermost frame
+    pub fn record_sample(&self, frames: &[String]) {
+        match self.context.write() {
+            Ok(mut ctx) => {
+                (*ctx).samples.record(frames);
+            },
+            Err(_) => { /* TODO: Ignoring for now */ }
+        }
+    }
+
+    /// Return the sampled stacks, weighted by their sample counts
+    pub fn sampled_stacks(&self) -> FoldedStacks {
+        match self.context.read() {
+            Ok(ctx) => (*ctx).samples.to_folded(),
+            Err(_) => FoldedStacks::new()
+        }
+    }
+
+    /// Return the allocation sites as stacks, weighted by the number of bytes allocated
+    pub fn allocation_stacks(&self) -> FoldedStacks {
+        match self.context.read() {
+            Ok(ctx) => (*ctx).allocations.to_folded(),
+            Err(_) => FoldedStacks::new()
+        }
+    }
+
+    pub fn reset_samples(&self) {
+        match self.context.write() {
+            Ok(mut ctx) => {
+                (*ctx).samples.reset();
+            },
+            Err(_) => { /* TODO: Ignoring for now */ }
+        }
+    }
+
+    /// Return the top allocation sites recorded so far, ordered by the number of bytes allocated
+    pub fn top_allocation_sites(&self, limit: usize) -> Vec<(AllocationSite, AllocationStats)> {
+        match self.context.read() {
+            Ok(ctx) => (*ctx).allocations.top_sites(limit),
+            Err(_) => vec![]
+        }
+    }
 }
 
+/// Number of ended threads whose records are kept
+pub const FINISHED_THREAD_RECORDS: usize = 1024;
+/// Number of stack frames used to tell allocation sites apart
+pub const ALLOCATION_SITE_DEPTH: usize = 4;
+/// Number of stack frames used to tell contended monitor and wait sites apart
+pub const LOCK_SITE_DEPTH: usize = 4;
+
 pub struct Context {
-    pub thread_lifetime: HashMap<ThreadId, Tm>,
-    pub monitor_queue: HashMap<ThreadId, Tm>,
-    pub thread_wait: HashMap<ThreadId, Tm>,
-    pub method_times: HashMap<ThreadId, Vec<Tm>>,
-    pub method_net_times: HashMap<ThreadId, Vec<Tm>>
+    pub threads: ThreadTable,
+    pub methods: MethodTimings,
+    pub call_trees: CallTreeProfile,
+    pub samples: SampleProfile,
+    pub allocations: AllocationProfile,
+    pub locks: LockProfile,
+    pub exceptions: ExceptionProfile,
+    pub traces: TraceRecorder,
+    pub slow_invocations: SlowInvocationDetector,
+    /// Set if the timeline is enabled
+    pub timeline: Option<Timeline>
 }
 
 impl Context {
     pub fn new() -> Context {
         Context {
-            thread_lifetime: HashMap::new(),
-            monitor_queue: HashMap::new(),
-            thread_wait: HashMap::new(),
-            method_times: HashMap::new(),
-            method_net_times: HashMap::new()
+            threads: ThreadTable::new(FINISHED_THREAD_RECORDS),
+            methods: MethodTimings::new(),
+            call_trees: CallTreeProfile::new(),
+            samples: SampleProfile::new(),
+            allocations: AllocationProfile::new(ALLOCATION_SITE_DEPTH),
+            locks: LockProfile::new(LOCK_SITE_DEPTH),
+            exceptions: ExceptionProfile::new(),
+            traces: TraceRecorder::new(TraceConfig::default().max_depth, TraceConfig::default().max_duration_ms * 1_000_000),
+            slow_invocations: SlowInvocationDetector::new(SlowInvocationsConfig::default().threshold_ms * 1_000_000, SlowInvocationsConfig::default().report_limit),
+            timeline: None
         }
     }
 }
+
+/// Return the wall clock time in nanoseconds since the Unix epoch
+fn wall_clock_ns() -> u64 {
+    let now = get_time();
+
+    (now.sec as u64) * 1_000_000_000 + now.nsec as u64
+}
@@ -0,0 +1,779 @@
+//! Local control socket for steering a running agent. Commands are read one per line from a Unix
+//! domain socket, either as words, eg. `enable sampling`, or as JSON objects like
+//! `{"command": "enable", "args": ["sampling"]}`. Every command is answered with a single JSON
+//! line, either `{"ok":true,"lines":[...]}` or `{"ok":false,"error":"..."}`.
+
+use super::config::ControlConfig;
+use super::context::static_context;
+use super::environment::jni::{JNIEnvironment, LocalFrame};
+use super::environment::jvm::{java_vm, JVMF, JVMAgent};
+use super::environment::jvmti::JVMTI;
+use super::error::translate_error;
+use super::export::prometheus::{self, DURATION_BUCKETS};
+use super::gc;
+use super::invocations;
+use super::logging::logger;
+use super::profile::flamegraph::{FoldedStacks, ToFolded};
+use super::profile::histogram::Histogram;
+use super::recording;
+use super::reload;
+use super::sampler;
+use super::stack::resolve_stack_trace;
+use libc;
+use serde_json::{self, Value};
+use std::fs;
+use std::io::{self, BufRead, BufReader, ErrorKind, Read, Write};
+use std::mem;
+use std::os::unix::fs::{DirBuilderExt, FileTypeExt, PermissionsExt};
+use std::os::unix::io::AsRawFd;
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+use time::precise_time_ns;
+
+/// Name of the agent thread serving the control socket, as seen by the JVM
+pub const CONTROL_THREAD_NAME: &'static str = "jvmti-control";
+/// Longest time the control thread waits for a connection or a command before checking whether
+/// it's been stopped
+const POLL_INTERVAL_MS: u64 = 100;
+/// Time after which an idle client is disconnected. Clients are served one at a time.
+const CLIENT_TIMEOUT_MS: u64 = 60_000;
+/// Longest command accepted, clients sending longer ones are disconnected
+const MAX_REQUEST_SIZE: usize = 8192;
+/// Number of entries listed by reports unless a limit is given
+const DEFAULT_LIMIT: usize = 10;
+/// Number of frames shown per thread in thread dumps unless a depth is given
+const DEFAULT_STACK_DEPTH: usize = 32;
+
+/// Settings that can be changed with `set`
+pub const SETTINGS: [&'static str; 3] = ["sampling.interval_ms", "sampling.cpu_interval_ms", "sampling.max_depth"];
+/// Statistics that can be cleared with `reset`
+pub const RESET_TARGETS: [&'static str; 6] = ["all", "methods", "samples", "locks", "exceptions", "gc"];
+/// Reports that can be fetched with `report`
+pub const REPORTS: [&'static str; 6] = ["exceptions", "threads", "methods", "slow", "gc", "metrics"];
+/// Profiles that can be fetched as folded stacks with `stacks`
+pub const PROFILES: [&'static str; 4] = ["samples", "calltree", "allocations", "locks"];
+
+const HELP: [&'static str; 12] = [
+    "status                        show which profilers are enabled and their settings",
+    "enable|disable <profiler>     switch the sampling, exceptions or timeline profiler on or off",
+    "set <setting> <value>         change sampling.interval_ms, sampling.cpu_interval_ms or sampling.max_depth",
+    "watch|unwatch <method>        add or remove a method watched for slow invocations",
+    "threads [depth]               dump the stacks of all threads",
+    "histogram gc|<method>         show the distribution of GC pause or method durations",
+    "reset <target>                clear all, methods, samples, locks, exceptions or gc statistics",
+    "report <report> [limit]       fetch the exceptions, threads, methods, slow, gc or metrics report",
+    "stacks <profile>              fetch the samples, calltree, allocations or locks profile as folded stacks",
+    "help                          show this help",
+    "",
+    "Commands may also be sent as JSON, eg. {\"command\": \"enable\", \"args\": [\"sampling\"]}"
+];
+
+/// Set while the control thread is alive
+static RUNNING: AtomicBool = AtomicBool::new(false);
+
+lazy_static! {
+    // The control thread along with the path of its socket, which is removed once it's stopped
+    static ref HANDLE: Mutex<Option<(JoinHandle<()>, PathBuf)>> = Mutex::new(None);
+}
+
+/// A profiler that can be switched on and off at runtime
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Profiler {
+    Sampling,
+    Exceptions,
+    Timeline
+}
+
+impl Profiler {
+
+    pub fn parse(name: &str) -> Result<Profiler, String> {
+        match name {
+            "sampling" => Ok(Profiler::Sampling),
+            "exceptions" => Ok(Profiler::Exceptions),
+            "timeline" => Ok(Profiler::Timeline),
+            other => Err(format!("unknown profiler: {}, expected sampling, exceptions or timeline", other))
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Profiler::Sampling => "sampling",
+            Profiler::Exceptions => "exceptions",
+            Profiler::Timeline => "timeline"
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Command {
+    Help,
+    Status,
+    Enable(Profiler),
+    Disable(Profiler),
+    Set(String, u64),
+    Watch(String),
+    Unwatch(String),
+    /// Dump the stacks of all threads, up to the given depth
+    Threads(usize),
+    /// Show the distribution of GC pause times, or of the durations of the given method
+    Histogram(String),
+    Reset(String),
+    /// Fetch a report, listing at most the given number of entries
+    Report(String, usize),
+    /// Fetch a profile as folded stacks, one stack per line
+    Stacks(String)
+}
+
+#[derive(Deserialize)]
+struct JsonCommand {
+    command: String,
+    #[serde(default)]
+    args: Vec<Value>
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+enum Response {
+    Ok { ok: bool, lines: Vec<String> },
+    Error { ok: bool, error: String }
+}
+
+#[derive(Deserialize)]
+struct ResponseBody {
+    ok: bool,
+    #[serde(default)]
+    lines: Vec<String>,
+    #[serde(default)]
+    error: String
+}
+
+impl Command {
+
+    /// Parse a command given as words separated by whitespace or as a JSON object
+    pub fn parse(request: &str) -> Result<Command, String> {
+        let request = request.trim();
+
+        let words: Vec<String> = match request.starts_with('{') {
+            true => {
+                let json: JsonCommand = serde_json::from_str(request).map_err(|err| format!("invalid JSON command: {}", err))?;
+
+                Some(json.command).into_iter().chain(json.args.into_iter().map(|arg| match arg {
+                    Value::String(arg) => arg,
+                    other => other.to_string()
+                })).collect()
+            },
+            false => request.split_whitespace().map(|word| word.to_string()).collect()
+        };
+
+        let words: Vec<&str> = words.iter().map(|word| word.as_str()).collect();
+
+        match words.as_slice() {
+            [] => Err("empty command".to_string()),
+            ["help"] => Ok(Command::Help),
+            ["status"] => Ok(Command::Status),
+            ["enable", profiler] => Profiler::parse(profiler).map(Command::Enable),
+            ["disable", profiler] => Profiler::parse(profiler).map(Command::Disable),
+            ["set", setting, value] => match SETTINGS.contains(setting) {
+                true => number(value).map(|value| Command::Set(setting.to_string(), value)),
+                false => Err(format!("unknown setting: {}, expected one of {}", setting, SETTINGS.join(", ")))
+            },
+            ["watch", method] => Ok(Command::Watch(method.to_string())),
+            ["unwatch", method] => Ok(Command::Unwatch(method.to_string())),
+            ["threads"] => Ok(Command::Threads(DEFAULT_STACK_DEPTH)),
+            ["threads", depth] => number(depth).map(|depth| Command::Threads(depth as usize)),
+            ["histogram", subject] => Ok(Command::Histogram(subject.to_string())),
+            ["reset", target] => match RESET_TARGETS.contains(target) {
+                true => Ok(Command::Reset(target.to_string())),
+                false => Err(format!("unknown reset target: {}, expected one of {}", target, RESET_TARGETS.join(", ")))
+            },
+            ["report", report] => Command::report(report, DEFAULT_LIMIT),
+            ["report", report, limit] => number(limit).and_then(|limit| Command::report(report, limit as usize)),
+            ["stacks", profile] => match PROFILES.contains(profile) {
+                true => Ok(Command::Stacks(profile.to_string())),
+                false => Err(format!("unknown profile: {}, expected one of {}", profile, PROFILES.join(", ")))
+            },
+            [command, ..] => match ["help", "status", "enable", "disable", "set", "watch", "unwatch", "threads", "histogram", "reset", "report", "stacks"].contains(command) {
+                true => Err(format!("wrong number of arguments for {}, see help", command)),
+                false => Err(format!("unknown command: {}, see help", command))
+            }
+        }
+    }
+
+    fn report(report: &str, limit: usize) -> Result<Command, String> {
+        match REPORTS.contains(&report) {
+            true => Ok(Command::Report(report.to_string(), limit)),
+            false => Err(format!("unknown report: {}, expected one of {}", report, REPORTS.join(", ")))
+        }
+    }
+}
+
+fn number(value: &str) -> Result<u64, String> {
+    value.parse().map_err(|_| format!("not a number: {}", value))
+}
+
+///
+/// Executes commands against the agent context and the profilers. A controller running on a thread
+/// attached to the JVM can also inspect the threads of the VM.
+///
+pub struct Controller {
+    jvm: Option<(Box<JVMTI>, JNIEnvironment)>
+}
+
+impl Controller {
+
+    /// Create a controller that isn't attached to the JVM
+    pub fn new() -> Controller {
+        Controller { jvm: None }
+    }
+
+    /// Create a controller, attaching the current thread to the JVM if there is one
+    fn attach() -> Controller {
+        let jvm = match java_vm() {
+            Some(vm) => JVMAgent::new(vm),
+            None => return Controller::new()
+        };
+
+        let environments = jvm.attach_current_thread(CONTROL_THREAD_NAME)
+            .and_then(|_| jvm.get_environment())
+            .and_then(|jvmti| JNIEnvironment::current().map(|jni| (jvmti, jni)));
+
+        match environments {
+            Ok(environments) => Controller { jvm: Some(environments) },
+            Err(err) => {
+                warn!(logger(), "Couldn't attach the control thread to the JVM: {}", translate_error(&err));
+                Controller::new()
+            }
+        }
+    }
+
+    /// Parse and execute a command, returning the JSON encoded response
+    pub fn handle(&mut self, request: &str) -> String {
+        let response = match Command::parse(request).and_then(|command| self.execute(&command)) {
+            Ok(lines) => Response::Ok { ok: true, lines: lines },
+            Err(error) => Response::Error { ok: false, error: error }
+        };
+
+        serde_json::to_string(&response).unwrap_or_default()
+    }
+
+    /// Execute a command, returning the lines of its output
+    pub fn execute(&mut self, command: &Command) -> Result<Vec<String>, String> {
+        match *command {
+            Command::Help => Ok(HELP.iter().map(|line| line.to_string()).collect()),
+            Command::Status => Ok(status()),
+            Command::Enable(profiler) => set_enabled(profiler, true),
+            Command::Disable(profiler) => set_enabled(profiler, false),
+            Command::Set(ref setting, value) => set(setting, value),
+            Command::Watch(ref method) => watch(method, true),
+            Command::Unwatch(ref method) => watch(method, false),
+            Command::Threads(depth) => self.thread_dump(depth),
+            Command::Histogram(ref subject) => histogram(subject),
+            Command::Reset(ref target) => Ok(reset(target)),
+            Command::Report(ref report, limit) => Ok(report_lines(report, limit)),
+            Command::Stacks(ref profile) => Ok(stacks(profile).lines())
+        }
+    }
+
+    fn thread_dump(&self, depth: usize) -> Result<Vec<String>, String> {
+        let (jvmti, jni) = match self.jvm {
+            Some((ref jvmti, ref jni)) => (jvmti, jni),
+            None => return Err("not attached to the JVM".to_string())
+        };
+
+        // The thread references returned by the JVM are released along with this frame
+        let _frame = LocalFrame::new(jni, 16).map_err(|err| translate_error(&err))?;
+        let mut lines = vec![];
+
+        for trace in jvmti.get_all_stack_traces(depth).map_err(|err| translate_error(&err))? {
+            // Threads may end while the stacks are being resolved
+            if let Ok(thread) = jvmti.get_thread_info(&trace.thread_id.native_id) {
+                lines.push(format!("\"{}\"{} {}", thread.name, if thread.is_daemon { " daemon" } else { "" }, trace.state_name()));
+                lines.extend(resolve_stack_trace(&**jvmti, &trace.frames).iter().map(|element| format!("    at {}", element.to_string())));
+                lines.push(String::new());
+            }
+        }
+
+        Ok(lines)
+    }
+}
+
+fn enabled(enabled: bool) -> &'static str {
+    if enabled { "enabled" } else { "disabled" }
+}
+
+fn status() -> Vec<String> {
+    let config = match static_context().config.read() {
+        Ok(cfg) => (*cfg).clone(),
+        Err(_) => return vec![]
+    };
+
+    let timeline = match static_context().timeline_len() {
+        Some(events) => format!("enabled ({} events)", events),
+        None => enabled(false).to_string()
+    };
+
+    vec![
+        format!("sampling: {} (interval: {} ms, CPU interval: {} ms, max depth: {})", enabled(sampler::is_running() && sampler::is_enabled()),
+            config.sampling.interval_ms, config.sampling.cpu_interval_ms, config.sampling.max_depth),
+        format!("exceptions: {}", enabled(config.exceptions.enabled)),
+        format!("timeline: {}", timeline),
+        format!("recording: {}", enabled(recording::is_recording())),
+        format!("watched methods: {}", if config.slow_invocations.methods.is_empty() { "none".to_string() } else { config.slow_invocations.methods.join(", ") })
+    ]
+}
+
+fn set_enabled(profiler: Profiler, enable: bool) -> Result<Vec<String>, String> {
+    // Exception events stay off until exception profiling is enabled
+    if profiler == Profiler::Exceptions {
+        invocations::set_exception_events(enable).map_err(|err| format!("couldn't set exception events: {}", translate_error(&err)))?;
+    }
+
+    let config = match static_context().config.write() {
+        Ok(mut cfg) => {
+            match profiler {
+                Profiler::Sampling => (*cfg).sampling.enabled = enable,
+                Profiler::Exceptions => (*cfg).exceptions.enabled = enable,
+                Profiler::Timeline => (*cfg).timeline.enabled = enable
+            }
+
+            (*cfg).clone()
+        },
+        Err(_) => return Err("the configuration is unavailable".to_string())
+    };
+
+    match (profiler, enable) {
+        (Profiler::Sampling, _) if sampler::is_running() => sampler::set_enabled(enable),
+        (Profiler::Sampling, true) => sampler::start(config.sampling).map_err(|err| format!("couldn't start the sampler: {}", err))?,
+        (Profiler::Timeline, true) => {
+            static_context().enable_timeline(config.timeline.max_events);
+        },
+        (Profiler::Timeline, false) => if let Some(timeline) = static_context().disable_timeline() {
+            let path = Path::new(&config.output_dir).join(&config.timeline.file);
+
+            timeline.write(&path, precise_time_ns()).map_err(|err| format!("couldn't write the timeline: {}", err))?;
+
+            return Ok(vec![format!("timeline disabled, wrote {} events to {}", timeline.len(), path.display())]);
+        },
+        _ => ()
+    }
+
+    Ok(vec![format!("{} {}", profiler.name(), enabled(enable))])
+}
+
+fn set(setting: &str, value: u64) -> Result<Vec<String>, String> {
+    if value == 0 && setting != "sampling.cpu_interval_ms" {
+        return Err(format!("{} must be greater than 0", setting));
+    }
+
+    let sampling = match static_context().config.write() {
+        Ok(mut cfg) => {
+            match setting {
+                "sampling.interval_ms" => (*cfg).sampling.interval_ms = value,
+                "sampling.cpu_interval_ms" => (*cfg).sampling.cpu_interval_ms = value,
+                "sampling.max_depth" => (*cfg).sampling.max_depth = value as usize,
+                other => return Err(format!("unknown setting: {}", other))
+            }
+
+            (*cfg).sampling.clone()
+        },
+        Err(_) => return Err("the configuration is unavailable".to_string())
+    };
+
+    // The sampler is given its settings when it's started, a new one replaces the current one
+    if sampler::is_running() {
+        sampler::stop();
+        sampler::start(sampling).map_err(|err| format!("couldn't restart the sampler: {}", err))?;
+    }
+
+    Ok(vec![format!("{} set to {}", setting, value)])
+}
+
+fn watch(method: &str, add: bool) -> Result<Vec<String>, String> {
+    // Method exit events are only enabled for watched threads once the detector has been set up
+    if add && !invocations::is_initialised() {
+        return Err("slow invocation detection hasn't been set up, list at least one method in [slow_invocations] to enable it".to_string());
+    }
+
+    let config = match static_context().config.write() {
+        Ok(mut cfg) => {
+            {
+                let methods = &mut (*cfg).slow_invocations.methods;
+                let watched = methods.iter().any(|item| item == method);
+
+                match (add, watched) {
+                    (true, false) => methods.push(method.to_string()),
+                    (false, true) => methods.retain(|item| item != method),
+                    (true, true) => return Err(format!("{} is watched already", method)),
+                    (false, false) => return Err(format!("{} isn't watched", method))
+                }
+            }
+
+            (*cfg).clone()
+        },
+        Err(_) => return Err("the configuration is unavailable".to_string())
+    };
+
+    // Method entry events are only enabled while there are watched methods or entry points, there
+    // are no events to update if the detector hasn't been set up
+    if invocations::is_initialised() {
+        if let Err(err) = reload::set_method_events(&config) {
+            return Err(format!("couldn't update method events: {}", translate_error(&err)));
+        }
+    }
+
+    Ok(vec![format!("{} {}", if add { "watching" } else { "no longer watching" }, method)])
+}
+
+/// Render a histogram of durations with the bucket bounds used by the Prometheus endpoint
+fn render_histogram(title: &str, histogram: &Histogram) -> Vec<String> {
+    let mut lines = vec![format!("{}: {} (p50: {:.3} ms, p99: {:.3} ms, max: {:.3} ms)", title, histogram.count(),
+        histogram.percentile(0.5) as f64 / 1e6, histogram.percentile(0.99) as f64 / 1e6, histogram.percentile(1.0) as f64 / 1e6)];
+
+    lines.extend(DURATION_BUCKETS.iter().map(|bound| format!("  <= {:>8.1} ms: {}", *bound as f64 / 1e6, histogram.count_at_most(*bound))));
+    lines.push(format!("  <= {:>8} ms: {}", "+Inf", histogram.count()));
+    lines
+}
+
+fn histogram(subject: &str) -> Result<Vec<String>, String> {
+    match subject {
+        "gc" => gc::with_pauses(|pauses| render_histogram("GC pauses", pauses.histogram()))
+            .ok_or_else(|| "GC pause tracking isn't enabled".to_string()),
+        method => static_context().method_stats(method)
+            .map(|stats| render_histogram(method, stats.histogram()))
+            .ok_or_else(|| format!("no timings recorded for {}", method))
+    }
+}
+
+fn reset(target: &str) -> Vec<String> {
+    let all = target == "all";
+
+    if all || target == "methods" {
+        static_context().reset_method_stats();
+    }
+
+    if all || target == "samples" {
+        static_context().reset_samples();
+    }
+
+    if all || target == "locks" {
+        static_context().reset_lock_stats();
+    }
+
+    if all || target == "exceptions" {
+        static_context().reset_exceptions();
+    }
+
+    if all || target == "gc" {
+        gc::with_pauses(|pauses| pauses.reset());
+    }
+
+    vec![format!("reset {}", target)]
+}
+
+fn report_lines(report: &str, limit: usize) -> Vec<String> {
+    match report {
+        "exceptions" => static_context().exception_report(),
+        "threads" => static_context().top_threads(limit).iter().map(|record| {
+            format!("{} (id: {}{}): {:.3} ms CPU, {}", record.name, record.java_id, if record.is_daemon { ", daemon" } else { "" },
+                record.cpu_time as f64 / 1e6, if record.is_alive() { "alive" } else { "ended" })
+        }).collect(),
+        "methods" => static_context().top_methods(limit).iter().map(|&(ref method, ref stats)| {
+            format!("{}: {} calls, total: {:.3} ms, self: {:.3} ms, p99: {:.3} ms, exceptions: {}", method, stats.count,
+                stats.total_time as f64 / 1e6, stats.self_time as f64 / 1e6, stats.p99() as f64 / 1e6, stats.exceptions)
+        }).collect(),
+        "slow" => {
+            let (count, slowest) = static_context().slowest_invocations(limit);
+
+            Some(format!("Slow invocations: {}", count)).into_iter().chain(slowest.iter().map(|invocation| {
+                format!("  {}({}) on {}: {:.3} ms", invocation.method, invocation.arguments.join(", "), invocation.thread_name, invocation.duration as f64 / 1e6)
+            })).collect()
+        },
+        "gc" => vec![gc::with_pauses(|pauses| pauses.render_summary()).unwrap_or_else(|| "GC pause tracking isn't enabled".to_string())],
+        "metrics" => prometheus::collect(limit).lines().map(|line| line.to_string()).collect(),
+        _ => vec![]
+    }
+}
+
+fn stacks(profile: &str) -> FoldedStacks {
+    match profile {
+        "samples" => static_context().sampled_stacks(),
+        "calltree" => static_context().call_tree().map(|tree| tree.to_folded()).unwrap_or_else(FoldedStacks::new),
+        "allocations" => static_context().allocation_stacks(),
+        "locks" => static_context().lock_stacks(),
+        _ => FoldedStacks::new()
+    }
+}
+
+/// Return the default path of the control socket of the given process
+pub fn default_socket_path(pid: u32) -> PathBuf {
+    PathBuf::from(format!("/tmp/jvmti-{}.sock", pid))
+}
+
+/// Return the path of the control socket of this process
+pub fn socket_path(config: &ControlConfig) -> PathBuf {
+    config.path.as_ref().map(PathBuf::from).unwrap_or_else(|| default_socket_path(process::id()))
+}
+
+///
+/// Start serving the control socket, returning its path. A socket left behind by a process that's
+/// gone is replaced. Returns an error if the control thread is running already or the socket is in
+/// use.
+///
+pub fn start(config: &ControlConfig) -> io::Result<PathBuf> {
+    if RUNNING.swap(true, Ordering::SeqCst) {
+        return Err(io::Error::new(ErrorKind::AlreadyExists, "the control socket is being served already"));
+    }
+
+    let path = socket_path(config);
+
+    let listener = match bind(&path) {
+        Ok(listener) => listener,
+        Err(err) => {
+            RUNNING.store(false, Ordering::SeqCst);
+            return Err(err);
+        }
+    };
+
+    match thread::Builder::new().name(CONTROL_THREAD_NAME.to_string()).spawn(move || run(listener)) {
+        Ok(handle) => {
+            if let Ok(mut current) = HANDLE.lock() {
+                *current = Some((handle, path.clone()));
+            }
+
+            info!(logger(), "Listening for commands on {}", path.display());
+            Ok(path)
+        },
+        Err(err) => {
+            RUNNING.store(false, Ordering::SeqCst);
+            let _ = fs::remove_file(&path);
+            Err(err)
+        }
+    }
+}
+
+fn bind(path: &Path) -> io::Result<UnixListener> {
+    if let Ok(metadata) = fs::symlink_metadata(path) {
+        if !metadata.file_type().is_socket() {
+            return Err(io::Error::new(ErrorKind::AlreadyExists, format!("{} exists and isn't a socket", path.display())));
+        }
+
+        if UnixStream::connect(path).is_ok() {
+            return Err(io::Error::new(ErrorKind::AddrInUse, format!("{} is in use", path.display())));
+        }
+
+        fs::remove_file(path)?;
+    }
+
+    // Anyone able to connect can steer the agent. The socket is bound inside a directory only the
+    // owner can enter and restricted before it's moved into place, so nobody else can connect to it
+    // in between.
+    let dir = private_dir(path)?;
+    let staged = dir.join("control.sock");
+
+    let result = UnixListener::bind(&staged)
+        .and_then(|listener| fs::set_permissions(&staged, fs::Permissions::from_mode(0o600)).map(|_| listener))
+        .and_then(|listener| fs::rename(&staged, path).map(|_| listener));
+
+    if result.is_err() {
+        let _ = fs::remove_file(&staged);
+    }
+
+    let _ = fs::remove_dir(&dir);
+    let listener = result?;
+    // Accepting without blocking lets the control thread notice that it's been stopped
+    listener.set_nonblocking(true)?;
+    Ok(listener)
+}
+
+/// Create a directory only the owner can access next to the given path, which has to be on the same
+/// file system for the socket to be moved into place
+fn private_dir(path: &Path) -> io::Result<PathBuf> {
+    let parent = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new(".")
+    };
+    let dir = parent.join(format!(".jvmti-control-{}", process::id()));
+
+    // A directory left behind by a process with the same id is only removed if it's empty and ours
+    if fs::symlink_metadata(&dir).is_ok() {
+        fs::remove_dir(&dir)?;
+    }
+
+    fs::DirBuilder::new().mode(0o700).create(&dir)?;
+    Ok(dir)
+}
+
+/// Return the user id of the process on the other end of a connection
+fn peer_uid(stream: &UnixStream) -> io::Result<u32> {
+    let mut credentials = libc::ucred { pid: 0, uid: 0, gid: 0 };
+    let mut len = mem::size_of::<libc::ucred>() as libc::socklen_t;
+
+    let result = unsafe {
+        libc::getsockopt(stream.as_raw_fd(), libc::SOL_SOCKET, libc::SO_PEERCRED, &mut credentials as *mut libc::ucred as *mut libc::c_void, &mut len)
+    };
+
+    match result {
+        0 => Ok(credentials.uid),
+        _ => Err(io::Error::last_os_error())
+    }
+}
+
+/// Stop serving the control socket, wait until the control thread has finished and remove the socket
+pub fn stop() {
+    RUNNING.store(false, Ordering::SeqCst);
+
+    let handle = match HANDLE.lock() {
+        Ok(mut handle) => handle.take(),
+        Err(_) => None
+    };
+
+    if let Some((handle, path)) = handle {
+        let _ = handle.join();
+        let _ = fs::remove_file(path);
+    }
+}
+
+pub fn is_running() -> bool {
+    RUNNING.load(Ordering::SeqCst)
+}
+
+fn run(listener: UnixListener) {
+    let mut controller = Controller::attach();
+
+    while RUNNING.load(Ordering::SeqCst) {
+        match listener.accept() {
+            Ok((stream, _)) => {
+                // Only the owner may access the socket, its permissions could have been widened since
+                match peer_uid(&stream) {
+                    Ok(uid) if uid == unsafe { libc::geteuid() } => (),
+                    Ok(uid) => {
+                        warn!(logger(), "Refused control connection from user {}", uid);
+                        continue;
+                    },
+                    Err(err) => {
+                        warn!(logger(), "Couldn't identify the control client: {}", err);
+                        continue;
+                    }
+                }
+
+                if let Err(err) = serve(stream, &mut controller) {
+                    debug!(logger(), "Control connection failed: {}", err);
+                }
+            },
+            Err(ref err) if err.kind() == ErrorKind::WouldBlock => thread::sleep(Duration::from_millis(POLL_INTERVAL_MS)),
+            Err(err) => {
+                warn!(logger(), "Couldn't accept control connection: {}", err);
+                thread::sleep(Duration::from_millis(POLL_INTERVAL_MS));
+            }
+        }
+    }
+
+    if controller.jvm.is_some() {
+        if let Some(vm) = java_vm() {
+            let _ = JVMAgent::new(vm).detach_current_thread();
+        }
+    }
+}
+
+/// Answer the commands of a client until it disconnects, goes idle or the control thread is stopped
+fn serve(mut stream: UnixStream, controller: &mut Controller) -> io::Result<()> {
+    stream.set_nonblocking(false)?;
+    stream.set_read_timeout(Some(Duration::from_millis(POLL_INTERVAL_MS)))?;
+    stream.set_write_timeout(Some(Duration::from_millis(CLIENT_TIMEOUT_MS)))?;
+
+    let mut pending: Vec<u8> = vec![];
+    let mut buffer = [0; 1024];
+    let mut last_read = Instant::now();
+
+    while RUNNING.load(Ordering::SeqCst) {
+        while let Some(end) = pending.iter().position(|byte| *byte == b'\n') {
+            let request: Vec<u8> = pending.drain(..end + 1).collect();
+
+            respond(&mut stream, controller, &request)?;
+        }
+
+        if pending.len() > MAX_REQUEST_SIZE {
+            return Err(io::Error::new(ErrorKind::InvalidData, "command too long"));
+        }
+
+        match stream.read(&mut buffer) {
+            // A last command may be sent without a line break before the client shuts its side down
+            Ok(0) => return match pending.is_empty() {
+                true => Ok(()),
+                false => respond(&mut stream, controller, &pending)
+            },
+            Ok(len) => {
+                pending.extend_from_slice(&buffer[..len]);
+                last_read = Instant::now();
+            },
+            Err(ref err) if err.kind() == ErrorKind::WouldBlock || err.kind() == ErrorKind::TimedOut => {
+                if last_read.elapsed() > Duration::from_millis(CLIENT_TIMEOUT_MS) {
+                    return Ok(());
+                }
+            },
+            Err(ref err) if err.kind() == ErrorKind::Interrupted => (),
+            Err(err) => return Err(err)
+        }
+    }
+
+    Ok(())
+}
+
+fn respond(stream: &mut UnixStream, controller: &mut Controller, request: &[u8]) -> io::Result<()> {
+    let request = String::from_utf8_lossy(request);
+
+    // Blank lines are ignored, so that clients can check whether the connection is alive
+    if request.trim().is_empty() {
+        return Ok(());
+    }
+
+    let mut response = controller.handle(&request);
+
+    response.push('\n');
+    stream.write_all(response.as_bytes())?;
+    stream.flush()
+}
+
+/// Client side of the control socket, as used by `jvmti ctl`
+pub struct ControlClient {
+    stream: UnixStream,
+    responses: BufReader<UnixStream>
+}
+
+impl ControlClient {
+
+    pub fn connect<P: AsRef<Path>>(path: P) -> io::Result<ControlClient> {
+        let stream = UnixStream::connect(path)?;
+
+        stream.set_read_timeout(Some(Duration::from_millis(CLIENT_TIMEOUT_MS)))?;
+
+        let responses = BufReader::new(stream.try_clone()?);
+
+        Ok(ControlClient { stream: stream, responses: responses })
+    }
+
+    /// Send a command and wait for the response, which is either the output of the command or the
+    /// error reported by the agent
+    pub fn send(&mut self, command: &str) -> io::Result<Result<Vec<String>, String>> {
+        self.stream.write_all(format!("{}\n", command.trim()).as_bytes())?;
+        self.stream.flush()?;
+
+        let mut line = String::new();
+
+        if self.responses.read_line(&mut line)? == 0 {
+            return Err(io::Error::new(ErrorKind::UnexpectedEof, "the agent closed the connection"));
+        }
+
+        let response: ResponseBody = serde_json::from_str(&line).map_err(|err| io::Error::new(ErrorKind::InvalidData, err))?;
+
+        Ok(match response.ok {
+            true => Ok(response.lines),
+            false => Err(response.error)
+        })
+    }
+}
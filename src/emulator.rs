@@ -6,17 +6,22 @@ use super::environment::jvmti::{JVMTI};
 use super::event::{EventCallbacks, VMEvent};
 use super::mem::MemoryAllocation;
 use super::method::{MethodId, MethodSignature};
-use super::native::JavaThread;
+use super::monitor::RawMonitorId;
+use super::native::{JavaObject, JavaThread, TagId};
 use super::runtime::*;
+use super::stack::{StackFrame, ThreadStackTrace};
 use super::thread::Thread;
 use super::version::VersionNumber;
 use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 
 /// Allows testing of JVM and JVMTI-related functions by emulating (mocking) a JVM agent.
 pub struct JVMEmulator {
     pub capabilities: Capabilities,
     pub callbacks: EventCallbacks,
-    pub events: HashMap<VMEvent, bool>
+    /// Notification mode of each event, shared so it can be checked once the emulator's been handed over
+    pub events: Arc<Mutex<HashMap<VMEvent, bool>>>,
+    pub heap_sampling_interval: Option<i32>
 }
 
 impl JVMEmulator {
@@ -24,7 +29,8 @@ impl JVMEmulator {
         JVMEmulator {
             capabilities: Capabilities::new(),
             callbacks: EventCallbacks::new(),
-            events: HashMap::new()
+            events: Arc::new(Mutex::new(HashMap::new())),
+            heap_sampling_interval: None
         }
     }
 
@@ -36,6 +42,15 @@ impl JVMEmulator {
             _ => ()
         }
     }
+
+    pub fn emit_sampled_object_alloc(&self, event: ObjectAllocationEvent) {
+        match self.callbacks.sampled_object_alloc {
+            Some(handler) => {
+                handler(event);
+            },
+            _ => ()
+        }
+    }
 }
 
 impl JVMF for JVMEmulator {
@@ -46,6 +61,16 @@ impl JVMF for JVMEmulator {
     fn destroy(&self) -> Result<(), NativeError> {
         Ok(())
     }
+
+    fn attach_current_thread(&self, name: &str) -> Result<(), NativeError> {
+        match name {
+            _ => Ok(())
+        }
+    }
+
+    fn detach_current_thread(&self) -> Result<(), NativeError> {
+        Ok(())
+    }
 }
 
 impl JVMTI for JVMEmulator {
@@ -70,8 +95,19 @@ impl JVMTI for JVMEmulator {
         None
     }
 
-    fn set_event_notification_mode(&mut self, event: VMEvent, mode: bool) -> Option<NativeError> {
-        self.events.insert(event, mode);
+    fn set_event_notification_mode(&self, event: VMEvent, mode: bool) -> Option<NativeError> {
+        if let Ok(mut events) = self.events.lock() {
+            events.insert(event, mode);
+        }
+        None
+    }
+
+    fn set_thread_event_notification_mode(&self, event: VMEvent, mode: bool, thread_id: &JavaThread) -> Option<NativeError> {
+        None
+    }
+
+    fn set_heap_sampling_interval(&mut self, sampling_interval: i32) -> Option<NativeError> {
+        self.heap_sampling_interval = Some(sampling_interval);
         None
     }
 
@@ -81,6 +117,24 @@ impl JVMTI for JVMEmulator {
         }
     }
 
+    fn get_stack_trace(&self, thread_id: &JavaThread, max_frame_count: usize) -> Result<Vec<StackFrame>, NativeError> {
+        match *thread_id as u64 {
+            _ => Err(NativeError::NotImplemented)
+        }
+    }
+
+    fn get_frame_count(&self, thread_id: &JavaThread) -> Result<usize, NativeError> {
+        match *thread_id as u64 {
+            _ => Err(NativeError::NotImplemented)
+        }
+    }
+
+    fn get_all_stack_traces(&self, max_frame_count: usize) -> Result<Vec<ThreadStackTrace>, NativeError> {
+        match max_frame_count {
+            _ => Err(NativeError::NotImplemented)
+        }
+    }
+
     fn get_method_declaring_class(&self, method_id: &MethodId) -> Result<ClassId, NativeError> {
         match method_id.native_id as u64 {
             _ => Err(NativeError::NotImplemented)
@@ -94,12 +148,88 @@ impl JVMTI for JVMEmulator {
         }
     }
 
+    fn get_method_descriptor(&self, method_id: &MethodId) -> Result<String, NativeError> {
+        match method_id.native_id as u64 {
+            0x01 => Ok("()V".to_string()),
+            _ => Err(NativeError::NotImplemented)
+        }
+    }
+
+    fn get_method_modifiers(&self, method_id: &MethodId) -> Result<u32, NativeError> {
+        match method_id.native_id as u64 {
+            _ => Err(NativeError::NotImplemented)
+        }
+    }
+
+    fn get_local_int(&self, thread_id: &JavaThread, depth: usize, slot: usize) -> Result<i32, NativeError> {
+        Err(NativeError::NotImplemented)
+    }
+
+    fn get_local_long(&self, thread_id: &JavaThread, depth: usize, slot: usize) -> Result<i64, NativeError> {
+        Err(NativeError::NotImplemented)
+    }
+
+    fn get_local_float(&self, thread_id: &JavaThread, depth: usize, slot: usize) -> Result<f32, NativeError> {
+        Err(NativeError::NotImplemented)
+    }
+
+    fn get_local_double(&self, thread_id: &JavaThread, depth: usize, slot: usize) -> Result<f64, NativeError> {
+        Err(NativeError::NotImplemented)
+    }
+
+    fn get_local_object(&self, thread_id: &JavaThread, depth: usize, slot: usize) -> Result<JavaObject, NativeError> {
+        Err(NativeError::NotImplemented)
+    }
+
     fn get_class_signature(&self, class_id: &ClassId) -> Result<ClassSignature, NativeError> {
         match class_id.native_id as u64 {
             _ => Err(NativeError::NotImplemented)
         }
     }
 
+    fn get_loaded_classes(&self) -> Result<Vec<ClassId>, NativeError> {
+        Err(NativeError::NotImplemented)
+    }
+
+    fn retransform_classes(&self, classes: &[ClassId]) -> Option<NativeError> {
+        match classes.len() {
+            _ => Some(NativeError::NotImplemented)
+        }
+    }
+
+    fn get_thread_cpu_time(&self, thread_id: &JavaThread) -> Result<u64, NativeError> {
+        match *thread_id as u64 {
+            _ => Err(NativeError::NotImplemented)
+        }
+    }
+
+    fn get_current_thread_cpu_time(&self) -> Result<u64, NativeError> {
+        Err(NativeError::NotImplemented)
+    }
+
+    fn create_raw_monitor(&self, name: &str) -> Result<RawMonitorId, NativeError> {
+        match name.is_empty() {
+            true => Err(NativeError::IllegalArgument),
+            false => Ok(RawMonitorId { native_id: ::std::ptr::null_mut() })
+        }
+    }
+
+    fn destroy_raw_monitor(&self, monitor: &RawMonitorId) -> Result<(), NativeError> {
+        Ok(())
+    }
+
+    fn raw_monitor_enter(&self, monitor: &RawMonitorId) -> Result<(), NativeError> {
+        Ok(())
+    }
+
+    fn raw_monitor_exit(&self, monitor: &RawMonitorId) -> Result<(), NativeError> {
+        Ok(())
+    }
+
+    fn set_tag(&self, object: &JavaObject, tag: TagId) -> Result<(), NativeError> {
+        Ok(())
+    }
+
     fn allocate(&self, len: usize) -> Result<MemoryAllocation, NativeError> {
         Ok(MemoryAllocation { ptr: ::std::ptr::null_mut(), len: len })
     }
@@ -1,13 +1,370 @@
+use super::super::class::{ClassId, JavaType};
+use super::super::error::NativeError;
+use super::super::field::FieldId;
+use super::super::method::MethodId;
 use super::super::native::{JavaObject, JNIEnvPtr};
-use super::super::class::ClassId;
+use super::super::native::jvmti_native::{jvalue, jbyte, jint, jlong, JNI_OK, JNI_EDETACHED, JNI_EVERSION, JNI_VERSION_1_6};
+use super::super::thread::ThreadId;
+use super::jvm::java_vm;
+use libc::c_void;
+use std::error::Error;
+use std::ffi::CString;
+use std::fmt::{self, Display, Formatter};
+use std::marker::PhantomData;
+use std::ptr;
+
+///
+/// A reference to a Java object that is valid for the lifetime `'a`. References returned by the
+/// `JNI` functions are local references, which are bound to the environment (or `LocalFrame`) that
+/// created them and become invalid once the native method or event callback returns.
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct JObject<'a> {
+    native_id: JavaObject,
+    lifetime: PhantomData<&'a ()>
+}
+
+impl<'a> JObject<'a> {
+
+    /// Wrap a raw JNI reference. The caller has to make sure the reference stays valid for `'a`.
+    pub unsafe fn from_raw(native_id: JavaObject) -> JObject<'a> {
+        JObject { native_id: native_id, lifetime: PhantomData }
+    }
+
+    /// Return a reference representing Java `null`
+    pub fn null() -> JObject<'a> {
+        JObject { native_id: ptr::null_mut(), lifetime: PhantomData }
+    }
+
+    pub fn as_raw(&self) -> JavaObject {
+        self.native_id
+    }
+
+    pub fn is_null(&self) -> bool {
+        self.native_id.is_null()
+    }
+}
+
+impl<'a> From<&'a ClassId> for JObject<'a> {
+    fn from(class_id: &'a ClassId) -> JObject<'a> {
+        JObject { native_id: class_id.native_id, lifetime: PhantomData }
+    }
+}
+
+impl<'a> From<&'a ThreadId> for JObject<'a> {
+    fn from(thread_id: &'a ThreadId) -> JObject<'a> {
+        JObject { native_id: thread_id.native_id, lifetime: PhantomData }
+    }
+}
+
+///
+/// A JNI global reference. Global references stay valid across native calls and threads until
+/// they're explicitly released using `JNI::delete_global_ref`.
+///
+#[derive(Debug, Eq, PartialEq)]
+pub struct GlobalRef {
+    native_id: JavaObject
+}
+
+/// Marker trait implementation for `Send`
+unsafe impl Send for GlobalRef { }
+
+/// Marker trait implementation for `Sync`
+unsafe impl Sync for GlobalRef { }
+
+impl GlobalRef {
+
+    /// Borrow the global reference as an object that can be passed to the `JNI` functions
+    pub fn as_obj(&self) -> JObject {
+        JObject { native_id: self.native_id, lifetime: PhantomData }
+    }
+}
+
+///
+/// A typed Java value, used both for passing arguments to Java methods and for returning field
+/// values and method results.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum JValue<'a> {
+    Boolean(bool),
+    Byte(i8),
+    Char(u16),
+    Short(i16),
+    Int(i32),
+    Long(i64),
+    Float(f32),
+    Double(f64),
+    Object(JObject<'a>),
+    Void
+}
+
+impl<'a> JValue<'a> {
+
+    /// Convert this value into a native `jvalue` union
+    pub fn to_native(&self) -> jvalue {
+        let mut value = jvalue::default();
+
+        match *self {
+            JValue::Boolean(v) => value.z = if v { 1 } else { 0 },
+            JValue::Byte(v) => value.b = v,
+            JValue::Char(v) => value.c = v,
+            JValue::Short(v) => value.s = v,
+            JValue::Int(v) => value.i = v,
+            JValue::Long(v) => value.j = v,
+            JValue::Float(v) => value.f = v,
+            JValue::Double(v) => value.d = v,
+            JValue::Object(v) => value.l = v.as_raw(),
+            JValue::Void => ()
+        }
+
+        value
+    }
+
+    pub fn z(&self) -> Result<bool, NativeError> {
+        match *self { JValue::Boolean(v) => Ok(v), _ => Err(NativeError::TypeMismatch) }
+    }
+
+    pub fn b(&self) -> Result<i8, NativeError> {
+        match *self { JValue::Byte(v) => Ok(v), _ => Err(NativeError::TypeMismatch) }
+    }
+
+    pub fn c(&self) -> Result<u16, NativeError> {
+        match *self { JValue::Char(v) => Ok(v), _ => Err(NativeError::TypeMismatch) }
+    }
+
+    pub fn s(&self) -> Result<i16, NativeError> {
+        match *self { JValue::Short(v) => Ok(v), _ => Err(NativeError::TypeMismatch) }
+    }
+
+    pub fn i(&self) -> Result<i32, NativeError> {
+        match *self { JValue::Int(v) => Ok(v), _ => Err(NativeError::TypeMismatch) }
+    }
+
+    pub fn j(&self) -> Result<i64, NativeError> {
+        match *self { JValue::Long(v) => Ok(v), _ => Err(NativeError::TypeMismatch) }
+    }
+
+    pub fn f(&self) -> Result<f32, NativeError> {
+        match *self { JValue::Float(v) => Ok(v), _ => Err(NativeError::TypeMismatch) }
+    }
+
+    pub fn d(&self) -> Result<f64, NativeError> {
+        match *self { JValue::Double(v) => Ok(v), _ => Err(NativeError::TypeMismatch) }
+    }
+
+    pub fn l(&self) -> Result<JObject<'a>, NativeError> {
+        match *self { JValue::Object(v) => Ok(v), _ => Err(NativeError::TypeMismatch) }
+    }
+}
+
+///
+/// Return the type returned by the method with the given JNI signature, eg. `int` for `(J)I`.
+///
+pub fn return_type(signature: &str) -> Result<JavaType, NativeError> {
+    match signature.rfind(')') {
+        Some(idx) if signature.starts_with('(') => JavaType::parse(&signature[idx + 1..]).ok_or(NativeError::IllegalArgument),
+        _ => Err(NativeError::IllegalArgument)
+    }
+}
+
+/// Errors thrown by lookup functions to report a missing class or member
+const NOT_FOUND_ERRORS: [&'static str; 4] = ["java/lang/ClassNotFoundException", "java/lang/NoClassDefFoundError", "java/lang/NoSuchFieldError", "java/lang/NoSuchMethodError"];
+
+///
+/// The ways JNI functions can fail: either the function itself failed, or the Java code it ran
+/// threw an exception, which is left pending.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub enum JNIError {
+    Native(NativeError),
+    JavaException
+}
+
+impl From<NativeError> for JNIError {
+
+    fn from(err: NativeError) -> Self {
+        JNIError::Native(err)
+    }
+}
+
+impl Display for JNIError {
+
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            JNIError::Native(ref err) => err.fmt(f),
+            JNIError::JavaException => write!(f, "A Java exception has been thrown and is pending in the current thread.")
+        }
+    }
+}
+
+impl Error for JNIError {}
 
 ///
 /// `JNI` defines a set of operatations the JVM offers through it's JNI interface.
 ///
+/// Functions that may run Java code (eg. method calls) return `JNIError::JavaException` if the
+/// code threw an exception. The exception is left pending so that it can be inspected; it has to
+/// be cleared using `exception_clear` before any other JNI function is called.
+///
 pub trait JNI {
 
     /// Return an `ClassId` belonging to the given Java object instance.
     fn get_object_class(&self, object_id: &JavaObject) -> ClassId;
+    /// Look up a class by its fully qualified internal name, eg. `java/lang/Thread`
+    fn find_class(&self, name: &str) -> Result<JObject, JNIError>;
+    fn get_method_id(&self, class: &JObject, name: &str, signature: &str) -> Result<MethodId, JNIError>;
+    fn get_static_method_id(&self, class: &JObject, name: &str, signature: &str) -> Result<MethodId, JNIError>;
+    fn get_field_id(&self, class: &JObject, name: &str, signature: &str) -> Result<FieldId, JNIError>;
+    fn get_static_field_id(&self, class: &JObject, name: &str, signature: &str) -> Result<FieldId, JNIError>;
+    /// Call an instance method. The method signature is used to determine the type of the result.
+    fn call_method(&self, object: &JObject, method_id: &MethodId, signature: &str, args: &[JValue]) -> Result<JValue, JNIError>;
+    /// Call a static method. The method signature is used to determine the type of the result.
+    fn call_static_method(&self, class: &JObject, method_id: &MethodId, signature: &str, args: &[JValue]) -> Result<JValue, JNIError>;
+    /// Create a new instance of the given class using the given constructor
+    fn new_object(&self, class: &JObject, constructor_id: &MethodId, args: &[JValue]) -> Result<JObject, JNIError>;
+    fn get_field(&self, object: &JObject, field_id: &FieldId, signature: &str) -> Result<JValue, JNIError>;
+    fn set_field(&self, object: &JObject, field_id: &FieldId, value: &JValue) -> Result<(), JNIError>;
+    fn get_static_field(&self, class: &JObject, field_id: &FieldId, signature: &str) -> Result<JValue, JNIError>;
+    fn set_static_field(&self, class: &JObject, field_id: &FieldId, value: &JValue) -> Result<(), JNIError>;
+    /// Create a new `java.lang.String` instance holding the given text
+    fn new_string(&self, value: &str) -> Result<JObject, JNIError>;
+    /// Return the contents of the given `java.lang.String` instance
+    fn get_string(&self, string: &JObject) -> Result<String, JNIError>;
+    fn get_array_length(&self, array: &JObject) -> Result<usize, JNIError>;
+    fn new_object_array(&self, length: usize, element_class: &JObject, initial_element: &JObject) -> Result<JObject, JNIError>;
+    fn get_object_array_element(&self, array: &JObject, index: usize) -> Result<JObject, JNIError>;
+    fn set_object_array_element(&self, array: &JObject, index: usize, value: &JObject) -> Result<(), JNIError>;
+    fn new_byte_array(&self, values: &[i8]) -> Result<JObject, JNIError>;
+    fn get_byte_array(&self, array: &JObject) -> Result<Vec<i8>, JNIError>;
+    fn new_int_array(&self, values: &[i32]) -> Result<JObject, JNIError>;
+    fn get_int_array(&self, array: &JObject) -> Result<Vec<i32>, JNIError>;
+    fn new_long_array(&self, values: &[i64]) -> Result<JObject, JNIError>;
+    fn get_long_array(&self, array: &JObject) -> Result<Vec<i64>, JNIError>;
+    fn is_same_object(&self, first: &JObject, second: &JObject) -> bool;
+    fn new_local_ref(&self, object: &JObject) -> Result<JObject, JNIError>;
+    fn delete_local_ref(&self, object: JObject);
+    fn new_global_ref(&self, object: &JObject) -> Result<GlobalRef, JNIError>;
+    fn delete_global_ref(&self, global: GlobalRef);
+    /// Create a new local reference frame. Use `LocalFrame` rather than calling this directly.
+    fn push_local_frame(&self, capacity: i32) -> Result<(), NativeError>;
+    /// Pop the current local reference frame, freeing all of its local references except for
+    /// `result`, for which a new reference is returned in the previous frame.
+    fn pop_local_frame(&self, result: &JObject) -> JavaObject;
+    fn exception_check(&self) -> bool;
+    fn exception_occurred(&self) -> Option<JObject>;
+    /// Print the pending exception and its stack trace to the standard error output
+    fn exception_describe(&self);
+    fn exception_clear(&self);
+
+    /// Look up and call an instance method by its name and signature
+    fn call_method_by_name(&self, object: &JObject, name: &str, signature: &str, args: &[JValue]) -> Result<JValue, JNIError> {
+        let class_id = self.get_object_class(&object.as_raw());
+        let method_id = self.get_method_id(&JObject::from(&class_id), name, signature);
+
+        self.delete_local_ref(JObject::from(&class_id));
+
+        match method_id {
+            Ok(method_id) => self.call_method(object, &method_id, signature, args),
+            Err(err) => Err(err)
+        }
+    }
+
+    /// Look up and read an instance field by its name and signature
+    fn get_field_by_name(&self, object: &JObject, name: &str, signature: &str) -> Result<JValue, JNIError> {
+        let class_id = self.get_object_class(&object.as_raw());
+        let field_id = self.get_field_id(&JObject::from(&class_id), name, signature);
+
+        self.delete_local_ref(JObject::from(&class_id));
+
+        match field_id {
+            Ok(field_id) => self.get_field(object, &field_id, signature),
+            Err(err) => Err(err)
+        }
+    }
+
+    /// Return the result of calling `toString()` on the given object, or "null" for null references
+    fn to_string(&self, object: &JObject) -> Result<String, JNIError> {
+        if object.is_null() {
+            return Ok("null".to_string());
+        }
+
+        match self.call_method_by_name(object, "toString", "()Ljava/lang/String;", &[]).and_then(|value| value.l().map_err(JNIError::from)) {
+            Ok(ref string) if string.is_null() => Ok("null".to_string()),
+            Ok(string) => {
+                let result = self.get_string(&string);
+                self.delete_local_ref(string);
+                result
+            },
+            Err(err) => Err(err)
+        }
+    }
+}
+
+///
+/// A local reference that is deleted once it goes out of scope. Useful when creating many local
+/// references in a loop, as the JVM only guarantees a limited number of them per frame.
+///
+pub struct AutoLocal<'a> {
+    env: &'a JNI,
+    object: JObject<'a>
+}
+
+impl<'a> AutoLocal<'a> {
+
+    pub fn new(env: &'a JNI, object: JObject<'a>) -> AutoLocal<'a> {
+        AutoLocal { env: env, object: object }
+    }
+
+    pub fn as_obj<'b>(&'b self) -> JObject<'b> where 'a: 'b {
+        self.object
+    }
+}
+
+impl<'a> Drop for AutoLocal<'a> {
+    fn drop(&mut self) {
+        if !self.object.is_null() {
+            self.env.delete_local_ref(self.object);
+        }
+    }
+}
+
+///
+/// A local reference frame that is popped (freeing all the local references created within) once
+/// it goes out of scope. References created through `env()` borrow the frame, so the compiler
+/// makes sure they aren't used after the frame has been popped.
+///
+pub struct LocalFrame<'a> {
+    env: &'a JNI,
+    popped: bool
+}
+
+impl<'a> LocalFrame<'a> {
+
+    /// Push a new local frame that can hold at least `capacity` local references
+    pub fn new(env: &'a JNI, capacity: i32) -> Result<LocalFrame<'a>, NativeError> {
+        match env.push_local_frame(capacity) {
+            Ok(()) => Ok(LocalFrame { env: env, popped: false }),
+            Err(err) => Err(err)
+        }
+    }
+
+    pub fn env(&self) -> &JNI {
+        self.env
+    }
+
+    /// Pop this frame while keeping the given reference alive in the enclosing frame
+    pub fn pop_with(mut self, result: JObject) -> JObject<'a> {
+        self.popped = true;
+        unsafe { JObject::from_raw(self.env.pop_local_frame(&result)) }
+    }
+}
+
+impl<'a> Drop for LocalFrame<'a> {
+    fn drop(&mut self) {
+        if !self.popped {
+            self.env.pop_local_frame(&JObject::null());
+        }
+    }
 }
 
 ///
@@ -23,6 +380,107 @@ impl JNIEnvironment {
     pub fn new(jni: JNIEnvPtr) -> JNIEnvironment {
         JNIEnvironment { jni: jni }
     }
+
+    /// Return the JNI environment of the current thread. This allows event handlers, which are
+    /// only given the event data, to call back into Java code.
+    pub fn current() -> Result<JNIEnvironment, NativeError> {
+        let vm = match java_vm() {
+            Some(vm) => vm,
+            None => return Err(NativeError::Disconnected)
+        };
+
+        unsafe {
+            let mut void_ptr: *mut c_void = ptr::null_mut();
+
+            match (**vm).GetEnv.unwrap()(vm, &mut void_ptr, JNI_VERSION_1_6) {
+                JNI_OK => Ok(JNIEnvironment::new(void_ptr as JNIEnvPtr)),
+                JNI_EDETACHED => Err(NativeError::ThreadNotAttached),
+                JNI_EVERSION => Err(NativeError::UnsupportedVersion),
+                _ => Err(NativeError::UnexpectedInternalError)
+            }
+        }
+    }
+
+    /// Wrap the result of a JNI call, turning pending exceptions into errors
+    fn checked<T>(&self, value: T) -> Result<T, JNIError> {
+        match self.exception_check() {
+            true => Err(JNIError::JavaException),
+            false => Ok(value)
+        }
+    }
+
+    /// Wrap a newly returned reference, treating null references as the result of a failure
+    fn checked_ref(&self, object: JavaObject) -> Result<JObject, JNIError> {
+        match self.checked(object) {
+            Ok(object) if object.is_null() => Err(JNIError::Native(NativeError::OutOfMemory)),
+            Ok(object) => Ok(unsafe { JObject::from_raw(object) }),
+            Err(err) => Err(err)
+        }
+    }
+
+    ///
+    /// Lookup functions report missing members by throwing one of `NOT_FOUND_ERRORS`, which is
+    /// cleared here as the result already conveys the failure. Any other exception, eg. an
+    /// `OutOfMemoryError`, is left pending.
+    ///
+    fn found<T>(&self, id: *mut T) -> Result<*mut T, JNIError> {
+        if !id.is_null() {
+            return Ok(id);
+        }
+
+        let exception = match self.exception_occurred() {
+            Some(exception) => exception,
+            None => return Err(JNIError::Native(NativeError::NotFound))
+        };
+
+        // Classes can't be looked up while an exception is pending
+        self.exception_clear();
+
+        let not_found = NOT_FOUND_ERRORS.iter().any(|class_name| self.is_instance_of(&exception, class_name));
+
+        if !not_found {
+            unsafe {
+                (**self.jni).Throw.unwrap()(self.jni, exception.as_raw());
+            }
+        }
+
+        self.delete_local_ref(exception);
+
+        match not_found {
+            true => Err(JNIError::Native(NativeError::NotFound)),
+            false => Err(JNIError::JavaException)
+        }
+    }
+
+    /// Return true if the object is an instance of the named class. It's false if the class can't
+    /// be found, the lookup failure is cleared.
+    fn is_instance_of(&self, object: &JObject, class_name: &str) -> bool {
+        let class_name = match CString::new(class_name) {
+            Ok(class_name) => class_name,
+            Err(_) => return false
+        };
+
+        unsafe {
+            let class = (**self.jni).FindClass.unwrap()(self.jni, class_name.as_ptr());
+
+            if class.is_null() {
+                self.exception_clear();
+                return false;
+            }
+
+            let result = (**self.jni).IsInstanceOf.unwrap()(self.jni, object.as_raw(), class) > 0;
+            (**self.jni).DeleteLocalRef.unwrap()(self.jni, class);
+            result
+        }
+    }
+}
+
+fn native_args(args: &[JValue]) -> Vec<jvalue> {
+    args.iter().map(|arg| arg.to_native()).collect()
+}
+
+fn native_string(value: &str) -> Result<CString, JNIError> {
+    CString::new(value).map_err(|_| JNIError::Native(NativeError::IllegalArgument))
 }
 
 impl JNI for JNIEnvironment {
@@ -34,4 +492,382 @@ impl JNI for JNIEnvironment {
             ClassId { native_id: class_id }
         }
     }
+
+    fn find_class(&self, name: &str) -> Result<JObject, JNIError> {
+        let name = native_string(name)?;
+
+        unsafe {
+            let class = (**self.jni).FindClass.unwrap()(self.jni, name.as_ptr());
+            self.found(class).map(|class| JObject::from_raw(class))
+        }
+    }
+
+    fn get_method_id(&self, class: &JObject, name: &str, signature: &str) -> Result<MethodId, JNIError> {
+        let (name, signature) = (native_string(name)?, native_string(signature)?);
+
+        unsafe {
+            let method = (**self.jni).GetMethodID.unwrap()(self.jni, class.as_raw(), name.as_ptr(), signature.as_ptr());
+            self.found(method).map(|method| MethodId { native_id: method })
+        }
+    }
+
+    fn get_static_method_id(&self, class: &JObject, name: &str, signature: &str) -> Result<MethodId, JNIError> {
+        let (name, signature) = (native_string(name)?, native_string(signature)?);
+
+        unsafe {
+            let method = (**self.jni).GetStaticMethodID.unwrap()(self.jni, class.as_raw(), name.as_ptr(), signature.as_ptr());
+            self.found(method).map(|method| MethodId { native_id: method })
+        }
+    }
+
+    fn get_field_id(&self, class: &JObject, name: &str, signature: &str) -> Result<FieldId, JNIError> {
+        let (name, signature) = (native_string(name)?, native_string(signature)?);
+
+        unsafe {
+            let field = (**self.jni).GetFieldID.unwrap()(self.jni, class.as_raw(), name.as_ptr(), signature.as_ptr());
+            self.found(field).map(|field| FieldId { native_id: field })
+        }
+    }
+
+    fn get_static_field_id(&self, class: &JObject, name: &str, signature: &str) -> Result<FieldId, JNIError> {
+        let (name, signature) = (native_string(name)?, native_string(signature)?);
+
+        unsafe {
+            let field = (**self.jni).GetStaticFieldID.unwrap()(self.jni, class.as_raw(), name.as_ptr(), signature.as_ptr());
+            self.found(field).map(|field| FieldId { native_id: field })
+        }
+    }
+
+    fn call_method(&self, object: &JObject, method_id: &MethodId, signature: &str, args: &[JValue]) -> Result<JValue, JNIError> {
+        let java_type = return_type(signature)?;
+        let args = native_args(args);
+        let (env, obj, method, args) = (self.jni, object.as_raw(), method_id.native_id, args.as_ptr());
+
+        unsafe {
+            let jni = &**self.jni;
+            let value = match java_type {
+                JavaType::Boolean => JValue::Boolean(jni.CallBooleanMethodA.unwrap()(env, obj, method, args) > 0),
+                JavaType::Byte => JValue::Byte(jni.CallByteMethodA.unwrap()(env, obj, method, args)),
+                JavaType::Char => JValue::Char(jni.CallCharMethodA.unwrap()(env, obj, method, args)),
+                JavaType::Short => JValue::Short(jni.CallShortMethodA.unwrap()(env, obj, method, args)),
+                JavaType::Int => JValue::Int(jni.CallIntMethodA.unwrap()(env, obj, method, args)),
+                JavaType::Long => JValue::Long(jni.CallLongMethodA.unwrap()(env, obj, method, args)),
+                JavaType::Float => JValue::Float(jni.CallFloatMethodA.unwrap()(env, obj, method, args)),
+                JavaType::Double => JValue::Double(jni.CallDoubleMethodA.unwrap()(env, obj, method, args)),
+                JavaType::Void => { jni.CallVoidMethodA.unwrap()(env, obj, method, args); JValue::Void },
+                JavaType::Class(_) | JavaType::Array(_) => JValue::Object(JObject::from_raw(jni.CallObjectMethodA.unwrap()(env, obj, method, args)))
+            };
+
+            self.checked(value)
+        }
+    }
+
+    fn call_static_method(&self, class: &JObject, method_id: &MethodId, signature: &str, args: &[JValue]) -> Result<JValue, JNIError> {
+        let java_type = return_type(signature)?;
+        let args = native_args(args);
+        let (env, cls, method, args) = (self.jni, class.as_raw(), method_id.native_id, args.as_ptr());
+
+        unsafe {
+            let jni = &**self.jni;
+            let value = match java_type {
+                JavaType::Boolean => JValue::Boolean(jni.CallStaticBooleanMethodA.unwrap()(env, cls, method, args) > 0),
+                JavaType::Byte => JValue::Byte(jni.CallStaticByteMethodA.unwrap()(env, cls, method, args)),
+                JavaType::Char => JValue::Char(jni.CallStaticCharMethodA.unwrap()(env, cls, method, args)),
+                JavaType::Short => JValue::Short(jni.CallStaticShortMethodA.unwrap()(env, cls, method, args)),
+                JavaType::Int => JValue::Int(jni.CallStaticIntMethodA.unwrap()(env, cls, method, args)),
+                JavaType::Long => JValue::Long(jni.CallStaticLongMethodA.unwrap()(env, cls, method, args)),
+                JavaType::Float => JValue::Float(jni.CallStaticFloatMethodA.unwrap()(env, cls, method, args)),
+                JavaType::Double => JValue::Double(jni.CallStaticDoubleMethodA.unwrap()(env, cls, method, args)),
+                JavaType::Void => { jni.CallStaticVoidMethodA.unwrap()(env, cls, method, args); JValue::Void },
+                JavaType::Class(_) | JavaType::Array(_) => JValue::Object(JObject::from_raw(jni.CallStaticObjectMethodA.unwrap()(env, cls, method, args)))
+            };
+
+            self.checked(value)
+        }
+    }
+
+    fn new_object(&self, class: &JObject, constructor_id: &MethodId, args: &[JValue]) -> Result<JObject, JNIError> {
+        let args = native_args(args);
+
+        unsafe {
+            self.checked_ref((**self.jni).NewObjectA.unwrap()(self.jni, class.as_raw(), constructor_id.native_id, args.as_ptr()))
+        }
+    }
+
+    fn get_field(&self, object: &JObject, field_id: &FieldId, signature: &str) -> Result<JValue, JNIError> {
+        let java_type = JavaType::parse(signature).ok_or(NativeError::IllegalArgument)?;
+        let (env, obj, field) = (self.jni, object.as_raw(), field_id.native_id);
+
+        unsafe {
+            let jni = &**self.jni;
+            let value = match java_type {
+                JavaType::Boolean => JValue::Boolean(jni.GetBooleanField.unwrap()(env, obj, field) > 0),
+                JavaType::Byte => JValue::Byte(jni.GetByteField.unwrap()(env, obj, field)),
+                JavaType::Char => JValue::Char(jni.GetCharField.unwrap()(env, obj, field)),
+                JavaType::Short => JValue::Short(jni.GetShortField.unwrap()(env, obj, field)),
+                JavaType::Int => JValue::Int(jni.GetIntField.unwrap()(env, obj, field)),
+                JavaType::Long => JValue::Long(jni.GetLongField.unwrap()(env, obj, field)),
+                JavaType::Float => JValue::Float(jni.GetFloatField.unwrap()(env, obj, field)),
+                JavaType::Double => JValue::Double(jni.GetDoubleField.unwrap()(env, obj, field)),
+                JavaType::Void => return Err(NativeError::IllegalArgument.into()),
+                JavaType::Class(_) | JavaType::Array(_) => JValue::Object(JObject::from_raw(jni.GetObjectField.unwrap()(env, obj, field)))
+            };
+
+            self.checked(value)
+        }
+    }
+
+    fn set_field(&self, object: &JObject, field_id: &FieldId, value: &JValue) -> Result<(), JNIError> {
+        let (env, obj, field) = (self.jni, object.as_raw(), field_id.native_id);
+
+        unsafe {
+            let jni = &**self.jni;
+            match *value {
+                JValue::Boolean(v) => jni.SetBooleanField.unwrap()(env, obj, field, if v { 1 } else { 0 }),
+                JValue::Byte(v) => jni.SetByteField.unwrap()(env, obj, field, v),
+                JValue::Char(v) => jni.SetCharField.unwrap()(env, obj, field, v),
+                JValue::Short(v) => jni.SetShortField.unwrap()(env, obj, field, v),
+                JValue::Int(v) => jni.SetIntField.unwrap()(env, obj, field, v),
+                JValue::Long(v) => jni.SetLongField.unwrap()(env, obj, field, v),
+                JValue::Float(v) => jni.SetFloatField.unwrap()(env, obj, field, v),
+                JValue::Double(v) => jni.SetDoubleField.unwrap()(env, obj, field, v),
+                JValue::Object(v) => jni.SetObjectField.unwrap()(env, obj, field, v.as_raw()),
+                JValue::Void => return Err(NativeError::IllegalArgument.into())
+            }
+        }
+
+        self.checked(())
+    }
+
+    fn get_static_field(&self, class: &JObject, field_id: &FieldId, signature: &str) -> Result<JValue, JNIError> {
+        let java_type = JavaType::parse(signature).ok_or(NativeError::IllegalArgument)?;
+        let (env, cls, field) = (self.jni, class.as_raw(), field_id.native_id);
+
+        unsafe {
+            let jni = &**self.jni;
+            let value = match java_type {
+                JavaType::Boolean => JValue::Boolean(jni.GetStaticBooleanField.unwrap()(env, cls, field) > 0),
+                JavaType::Byte => JValue::Byte(jni.GetStaticByteField.unwrap()(env, cls, field)),
+                JavaType::Char => JValue::Char(jni.GetStaticCharField.unwrap()(env, cls, field)),
+                JavaType::Short => JValue::Short(jni.GetStaticShortField.unwrap()(env, cls, field)),
+                JavaType::Int => JValue::Int(jni.GetStaticIntField.unwrap()(env, cls, field)),
+                JavaType::Long => JValue::Long(jni.GetStaticLongField.unwrap()(env, cls, field)),
+                JavaType::Float => JValue::Float(jni.GetStaticFloatField.unwrap()(env, cls, field)),
+                JavaType::Double => JValue::Double(jni.GetStaticDoubleField.unwrap()(env, cls, field)),
+                JavaType::Void => return Err(NativeError::IllegalArgument.into()),
+                JavaType::Class(_) | JavaType::Array(_) => JValue::Object(JObject::from_raw(jni.GetStaticObjectField.unwrap()(env, cls, field)))
+            };
+
+            self.checked(value)
+        }
+    }
+
+    fn set_static_field(&self, class: &JObject, field_id: &FieldId, value: &JValue) -> Result<(), JNIError> {
+        let (env, cls, field) = (self.jni, class.as_raw(), field_id.native_id);
+
+        unsafe {
+            let jni = &**self.jni;
+            match *value {
+                JValue::Boolean(v) => jni.SetStaticBooleanField.unwrap()(env, cls, field, if v { 1 } else { 0 }),
+                JValue::Byte(v) => jni.SetStaticByteField.unwrap()(env, cls, field, v),
+                JValue::Char(v) => jni.SetStaticCharField.unwrap()(env, cls, field, v),
+                JValue::Short(v) => jni.SetStaticShortField.unwrap()(env, cls, field, v),
+                JValue::Int(v) => jni.SetStaticIntField.unwrap()(env, cls, field, v),
+                JValue::Long(v) => jni.SetStaticLongField.unwrap()(env, cls, field, v),
+                JValue::Float(v) => jni.SetStaticFloatField.unwrap()(env, cls, field, v),
+                JValue::Double(v) => jni.SetStaticDoubleField.unwrap()(env, cls, field, v),
+                JValue::Object(v) => jni.SetStaticObjectField.unwrap()(env, cls, field, v.as_raw()),
+                JValue::Void => return Err(NativeError::IllegalArgument.into())
+            }
+        }
+
+        self.checked(())
+    }
+
+    /// Strings are transferred as UTF-16, which is how Java stores them internally, so there's no
+    /// need to deal with the modified UTF-8 encoding used by the `*StringUTF*` functions.
+    fn new_string(&self, value: &str) -> Result<JObject, JNIError> {
+        let chars: Vec<u16> = value.encode_utf16().collect();
+
+        unsafe {
+            self.checked_ref((**self.jni).NewString.unwrap()(self.jni, chars.as_ptr(), chars.len() as jint))
+        }
+    }
+
+    fn get_string(&self, string: &JObject) -> Result<String, JNIError> {
+        if string.is_null() {
+            return Err(NativeError::NullPointer.into());
+        }
+
+        unsafe {
+            let length = (**self.jni).GetStringLength.unwrap()(self.jni, string.as_raw());
+            let mut chars: Vec<u16> = vec![0; length as usize];
+
+            (**self.jni).GetStringRegion.unwrap()(self.jni, string.as_raw(), 0, length, chars.as_mut_ptr());
+
+            self.checked(String::from_utf16_lossy(&chars))
+        }
+    }
+
+    fn get_array_length(&self, array: &JObject) -> Result<usize, JNIError> {
+        unsafe {
+            let length = (**self.jni).GetArrayLength.unwrap()(self.jni, array.as_raw());
+            self.checked(length as usize)
+        }
+    }
+
+    fn new_object_array(&self, length: usize, element_class: &JObject, initial_element: &JObject) -> Result<JObject, JNIError> {
+        unsafe {
+            self.checked_ref((**self.jni).NewObjectArray.unwrap()(self.jni, length as jint, element_class.as_raw(), initial_element.as_raw()))
+        }
+    }
+
+    fn get_object_array_element(&self, array: &JObject, index: usize) -> Result<JObject, JNIError> {
+        unsafe {
+            let element = (**self.jni).GetObjectArrayElement.unwrap()(self.jni, array.as_raw(), index as jint);
+            self.checked(JObject::from_raw(element))
+        }
+    }
+
+    fn set_object_array_element(&self, array: &JObject, index: usize, value: &JObject) -> Result<(), JNIError> {
+        unsafe {
+            (**self.jni).SetObjectArrayElement.unwrap()(self.jni, array.as_raw(), index as jint, value.as_raw());
+        }
+
+        self.checked(())
+    }
+
+    fn new_byte_array(&self, values: &[i8]) -> Result<JObject, JNIError> {
+        unsafe {
+            let array = self.checked_ref((**self.jni).NewByteArray.unwrap()(self.jni, values.len() as jint))?;
+            (**self.jni).SetByteArrayRegion.unwrap()(self.jni, array.as_raw(), 0, values.len() as jint, values.as_ptr() as *const jbyte);
+            self.checked(array)
+        }
+    }
+
+    fn get_byte_array(&self, array: &JObject) -> Result<Vec<i8>, JNIError> {
+        let length = self.get_array_length(array)?;
+        let mut values: Vec<i8> = vec![0; length];
+
+        unsafe {
+            (**self.jni).GetByteArrayRegion.unwrap()(self.jni, array.as_raw(), 0, length as jint, values.as_mut_ptr() as *mut jbyte);
+        }
+
+        self.checked(values)
+    }
+
+    fn new_int_array(&self, values: &[i32]) -> Result<JObject, JNIError> {
+        unsafe {
+            let array = self.checked_ref((**self.jni).NewIntArray.unwrap()(self.jni, values.len() as jint))?;
+            (**self.jni).SetIntArrayRegion.unwrap()(self.jni, array.as_raw(), 0, values.len() as jint, values.as_ptr() as *const jint);
+            self.checked(array)
+        }
+    }
+
+    fn get_int_array(&self, array: &JObject) -> Result<Vec<i32>, JNIError> {
+        let length = self.get_array_length(array)?;
+        let mut values: Vec<i32> = vec![0; length];
+
+        unsafe {
+            (**self.jni).GetIntArrayRegion.unwrap()(self.jni, array.as_raw(), 0, length as jint, values.as_mut_ptr() as *mut jint);
+        }
+
+        self.checked(values)
+    }
+
+    fn new_long_array(&self, values: &[i64]) -> Result<JObject, JNIError> {
+        unsafe {
+            let array = self.checked_ref((**self.jni).NewLongArray.unwrap()(self.jni, values.len() as jint))?;
+            (**self.jni).SetLongArrayRegion.unwrap()(self.jni, array.as_raw(), 0, values.len() as jint, values.as_ptr() as *const jlong);
+            self.checked(array)
+        }
+    }
+
+    fn get_long_array(&self, array: &JObject) -> Result<Vec<i64>, JNIError> {
+        let length = self.get_array_length(array)?;
+        let mut values: Vec<i64> = vec![0; length];
+
+        unsafe {
+            (**self.jni).GetLongArrayRegion.unwrap()(self.jni, array.as_raw(), 0, length as jint, values.as_mut_ptr() as *mut jlong);
+        }
+
+        self.checked(values)
+    }
+
+    fn is_same_object(&self, first: &JObject, second: &JObject) -> bool {
+        unsafe {
+            (**self.jni).IsSameObject.unwrap()(self.jni, first.as_raw(), second.as_raw()) > 0
+        }
+    }
+
+    fn new_local_ref(&self, object: &JObject) -> Result<JObject, JNIError> {
+        unsafe {
+            self.checked_ref((**self.jni).NewLocalRef.unwrap()(self.jni, object.as_raw()))
+        }
+    }
+
+    fn delete_local_ref(&self, object: JObject) {
+        unsafe {
+            (**self.jni).DeleteLocalRef.unwrap()(self.jni, object.as_raw());
+        }
+    }
+
+    fn new_global_ref(&self, object: &JObject) -> Result<GlobalRef, JNIError> {
+        unsafe {
+            self.checked_ref((**self.jni).NewGlobalRef.unwrap()(self.jni, object.as_raw())).map(|global| GlobalRef { native_id: global.as_raw() })
+        }
+    }
+
+    fn delete_global_ref(&self, global: GlobalRef) {
+        unsafe {
+            (**self.jni).DeleteGlobalRef.unwrap()(self.jni, global.native_id);
+        }
+    }
+
+    fn push_local_frame(&self, capacity: i32) -> Result<(), NativeError> {
+        unsafe {
+            match (**self.jni).PushLocalFrame.unwrap()(self.jni, capacity) {
+                JNI_OK => Ok(()),
+                _ => {
+                    self.exception_clear();
+                    Err(NativeError::OutOfMemory)
+                }
+            }
+        }
+    }
+
+    fn pop_local_frame(&self, result: &JObject) -> JavaObject {
+        unsafe {
+            (**self.jni).PopLocalFrame.unwrap()(self.jni, result.as_raw())
+        }
+    }
+
+    fn exception_check(&self) -> bool {
+        unsafe {
+            (**self.jni).ExceptionCheck.unwrap()(self.jni) > 0
+        }
+    }
+
+    fn exception_occurred(&self) -> Option<JObject> {
+        unsafe {
+            let throwable = (**self.jni).ExceptionOccurred.unwrap()(self.jni);
+
+            match throwable.is_null() {
+                true => None,
+                false => Some(JObject::from_raw(throwable))
+            }
+        }
+    }
+
+    fn exception_describe(&self) {
+        unsafe {
+            (**self.jni).ExceptionDescribe.unwrap()(self.jni);
+        }
+    }
+
+    fn exception_clear(&self) {
+        unsafe {
+            (**self.jni).ExceptionClear.unwrap()(self.jni);
+        }
+    }
 }
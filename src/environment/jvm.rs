@@ -1,13 +1,34 @@
 use super::super::native::{JavaVMPtr, JVMTIEnvPtr};
-use super::super::native::jvmti_native::JVMTI_VERSION;
+use super::super::native::jvmti_native::{JavaVM, JavaVMAttachArgs, JVMTI_VERSION, JVMTI_VERSION_LEGACY, JNI_OK, JNI_EDETACHED, JNI_EVERSION, JNI_ENOMEM, JNI_VERSION_1_6};
 use super::super::environment::jvmti::{JVMTI, JVMTIEnvironment};
 use super::super::error::{wrap_error, NativeError};
 use libc::c_void;
+use std::ffi::CString;
 use std::ptr;
+use std::sync::atomic::{AtomicPtr, Ordering};
+
+/// The JVM the agent has been loaded into. It's process-wide, so the JNI environment of any
+/// attached thread can be retrieved through it.
+static JAVA_VM: AtomicPtr<JavaVM> = AtomicPtr::new(0 as JavaVMPtr);
+
+/// Return the JVM the agent has been loaded into, if any
+pub fn java_vm() -> Option<JavaVMPtr> {
+    let vm = JAVA_VM.load(Ordering::SeqCst);
+
+    match vm.is_null() {
+        true => None,
+        false => Some(vm)
+    }
+}
 
 pub trait JVMF {
     fn get_environment(&self) -> Result<Box<JVMTI>, NativeError>;
     fn destroy(&self) -> Result<(), NativeError>;
+    /// Attach the current native thread to the JVM as a daemon thread with the given name. This
+    /// is required before the JNI or JVMTI environments can be used from threads started by the
+    /// agent itself.
+    fn attach_current_thread(&self, name: &str) -> Result<(), NativeError>;
+    fn detach_current_thread(&self) -> Result<(), NativeError>;
 }
 ///
 /// `JVMAgent` represents a binding to the JVM.
@@ -20,6 +41,7 @@ impl JVMAgent {
 
     /// Create a new `JVMAgent` instance
     pub fn new(vm: JavaVMPtr) -> JVMAgent {
+        JAVA_VM.store(vm, Ordering::SeqCst);
         JVMAgent { vm: vm }
     }
 }
@@ -32,7 +54,19 @@ impl JVMF for JVMAgent {
         unsafe {
             let mut void_ptr: *mut c_void = ptr::null_mut() as *mut c_void;
             let penv_ptr: *mut *mut c_void = &mut void_ptr as *mut *mut c_void;
-            let result = wrap_error((**self.vm).GetEnv.unwrap()(self.vm, penv_ptr, JVMTI_VERSION) as u32);
+            let mut code = (**self.vm).GetEnv.unwrap()(self.vm, penv_ptr, JVMTI_VERSION);
+
+            // Virtual machines older than Java 11 only hand out environments of earlier versions
+            if code == JNI_EVERSION {
+                code = (**self.vm).GetEnv.unwrap()(self.vm, penv_ptr, JVMTI_VERSION_LEGACY);
+            }
+
+            // GetEnv reports JNI error codes rather than JVMTI ones so they need to be translated first
+            let result = match code {
+                JNI_EDETACHED => NativeError::ThreadNotAttached,
+                JNI_EVERSION => NativeError::UnsupportedVersion,
+                code @ _ => wrap_error(code as u32)
+            };
 
             match result {
                 NativeError::NoError => {
@@ -40,7 +74,7 @@ impl JVMF for JVMAgent {
                     let env = JVMTIEnvironment::new(env_ptr);
                     return Result::Ok(Box::new(env));
                 },
-                err @ _ => Result::Err(wrap_error(err as u32))
+                err @ _ => Result::Err(err)
             }
         }
     }
@@ -56,4 +90,29 @@ impl JVMF for JVMAgent {
             }
         }
     }
+
+    fn attach_current_thread(&self, name: &str) -> Result<(), NativeError> {
+        let thread_name = CString::new(name).map_err(|_| NativeError::IllegalArgument)?;
+        let mut args = JavaVMAttachArgs { version: JNI_VERSION_1_6, name: thread_name.as_ptr() as *mut _, group: ptr::null_mut() };
+        let mut void_ptr: *mut c_void = ptr::null_mut();
+
+        unsafe {
+            match (**self.vm).AttachCurrentThreadAsDaemon.unwrap()(self.vm, &mut void_ptr, &mut args as *mut JavaVMAttachArgs as *mut c_void) {
+                JNI_OK => Ok(()),
+                JNI_EVERSION => Err(NativeError::UnsupportedVersion),
+                JNI_ENOMEM => Err(NativeError::OutOfMemory),
+                _ => Err(NativeError::UnexpectedInternalError)
+            }
+        }
+    }
+
+    fn detach_current_thread(&self) -> Result<(), NativeError> {
+        unsafe {
+            match (**self.vm).DetachCurrentThread.unwrap()(self.vm) {
+                JNI_OK => Ok(()),
+                JNI_EDETACHED => Err(NativeError::ThreadNotAttached),
+                _ => Err(NativeError::UnexpectedInternalError)
+            }
+        }
+    }
 }
@@ -5,11 +5,14 @@ use super::super::event::{EventCallbacks, VMEvent};
 use super::super::event_handler::*;
 use super::super::mem::MemoryAllocation;
 use super::super::method::{MethodId, MethodSignature};
+use super::super::monitor::RawMonitorId;
 use super::super::thread::{ThreadId, Thread};
 use super::super::util::stringify;
 use super::super::version::VersionNumber;
-use super::super::native::{MutString, MutByteArray, JavaClass, JavaObject, JavaInstance, JavaLong, JavaThread, JVMTIEnvPtr};
-use super::super::native::jvmti_native::{Struct__jvmtiThreadInfo, jvmtiCapabilities};
+use super::super::native::{MutString, MutByteArray, JavaClass, JavaObject, JavaInstance, JavaLong, JavaThread, JVMTIEnvPtr, TagId};
+use super::super::native::jvmti_native::{Struct__jvmtiThreadInfo, jrawMonitorID, jvmtiCapabilities, jvmtiFrameInfo, jvmtiStackInfo};
+use super::super::stack::{StackFrame, ThreadStackTrace};
+use std::ffi::CString;
 use std::ptr;
 
 pub trait JVMTI {
@@ -30,11 +33,60 @@ pub trait JVMTI {
     /// An event must be enabled and have a callback in order to be sent--the order in which this
     /// function and set_event_notification_mode are called does not affect the result.
     fn set_event_callbacks(&mut self, callbacks: EventCallbacks) -> Option<NativeError>;
-    fn set_event_notification_mode(&mut self, event: VMEvent, mode: bool) -> Option<NativeError>;
+    fn set_event_notification_mode(&self, event: VMEvent, mode: bool) -> Option<NativeError>;
+    /// Enable or disable an event for a single thread. An event is sent to a thread if it's been
+    /// enabled either globally or for that thread.
+    fn set_thread_event_notification_mode(&self, event: VMEvent, mode: bool, thread_id: &JavaThread) -> Option<NativeError>;
+    /// Generate a sampled object allocation event whenever approximately `sampling_interval` bytes
+    /// have been allocated by a thread. An interval of 0 samples every allocation. This function
+    /// is only available from JVMTI version 11 onwards.
+    fn set_heap_sampling_interval(&mut self, sampling_interval: i32) -> Option<NativeError>;
     fn get_thread_info(&self, thread_id: &JavaThread) -> Result<Thread, NativeError>;
+    /// Return at most `max_frame_count` frames of the stack of the given thread, starting with the
+    /// currently executing frame.
+    fn get_stack_trace(&self, thread_id: &JavaThread, max_frame_count: usize) -> Result<Vec<StackFrame>, NativeError>;
+    /// Return the number of frames on the stack of the given thread
+    fn get_frame_count(&self, thread_id: &JavaThread) -> Result<usize, NativeError>;
+    /// Return at most `max_frame_count` frames of the stacks of all live threads. The stacks are
+    /// collected simultaneously, ie. no thread state changes in the meantime. The returned thread
+    /// references are JNI local references of the calling thread.
+    fn get_all_stack_traces(&self, max_frame_count: usize) -> Result<Vec<ThreadStackTrace>, NativeError>;
     fn get_method_declaring_class(&self, method_id: &MethodId) -> Result<ClassId, NativeError>;
     fn get_method_name(&self, method_id: &MethodId) -> Result<MethodSignature, NativeError>;
+    /// Return the type descriptor of a method, eg. `(ILjava/lang/String;)V`
+    fn get_method_descriptor(&self, method_id: &MethodId) -> Result<String, NativeError>;
+    /// Return the access flags of a method, as defined by the class file format
+    fn get_method_modifiers(&self, method_id: &MethodId) -> Result<u32, NativeError>;
+    /// Return the value of a local variable of the given type in the frame at `depth`, the current
+    /// frame being at depth 0. Method arguments occupy the first slots, starting with `this` for
+    /// instance methods. Values of type `long` and `double` occupy two slots.
+    fn get_local_int(&self, thread_id: &JavaThread, depth: usize, slot: usize) -> Result<i32, NativeError>;
+    fn get_local_long(&self, thread_id: &JavaThread, depth: usize, slot: usize) -> Result<i64, NativeError>;
+    fn get_local_float(&self, thread_id: &JavaThread, depth: usize, slot: usize) -> Result<f32, NativeError>;
+    fn get_local_double(&self, thread_id: &JavaThread, depth: usize, slot: usize) -> Result<f64, NativeError>;
+    /// Return a JNI local reference to the object stored in a local variable
+    fn get_local_object(&self, thread_id: &JavaThread, depth: usize, slot: usize) -> Result<JavaObject, NativeError>;
     fn get_class_signature(&self, class_id: &ClassId) -> Result<ClassSignature, NativeError>;
+    /// Return all classes loaded by the VM. The returned class references are JNI local references
+    /// of the calling thread.
+    fn get_loaded_classes(&self) -> Result<Vec<ClassId>, NativeError>;
+    /// Run the class file load hooks of retransformation capable environments on the given, already
+    /// loaded classes. Requires the `can_retransform_classes` capability.
+    fn retransform_classes(&self, classes: &[ClassId]) -> Option<NativeError>;
+    /// Return the CPU time used by the given thread in nanoseconds
+    fn get_thread_cpu_time(&self, thread_id: &JavaThread) -> Result<u64, NativeError>;
+    /// Return the CPU time used by the current thread in nanoseconds. Unlike
+    /// `get_thread_cpu_time`, this can be used from the thread's own start and end events.
+    fn get_current_thread_cpu_time(&self) -> Result<u64, NativeError>;
+    /// Create a raw monitor, ie. a monitor that's not associated with a Java object. Raw monitors
+    /// are the only kind of lock that may be used in garbage collection and object free callbacks.
+    fn create_raw_monitor(&self, name: &str) -> Result<RawMonitorId, NativeError>;
+    fn destroy_raw_monitor(&self, monitor: &RawMonitorId) -> Result<(), NativeError>;
+    fn raw_monitor_enter(&self, monitor: &RawMonitorId) -> Result<(), NativeError>;
+    fn raw_monitor_exit(&self, monitor: &RawMonitorId) -> Result<(), NativeError>;
+    /// Set the tag of an object. Tagged objects are reported by object free events once they've
+    /// been garbage collected. A tag of 0 removes the tag.
+    fn set_tag(&self, object: &JavaObject, tag: TagId) -> Result<(), NativeError>;
     fn allocate(&self, len: usize) -> Result<MemoryAllocation, NativeError>;
     fn deallocate(&self);
 }
@@ -91,6 +143,8 @@ impl JVMTI for JVMTIEnvironment {
         register_vm_start_callback(callbacks.vm_start);
         register_vm_death_callback(callbacks.vm_death);
         register_vm_object_alloc_callback(callbacks.vm_object_alloc);
+        register_vm_object_free_callback(callbacks.vm_object_free);
+        register_sampled_object_alloc_callback(callbacks.sampled_object_alloc);
         register_method_entry_callback(callbacks.method_entry);
         register_method_exit_callback(callbacks.method_exit);
         register_thread_start_callback(callbacks.thread_start);
@@ -117,7 +171,7 @@ impl JVMTI for JVMTIEnvironment {
         }
     }
 
-    fn set_event_notification_mode(&mut self, event: VMEvent, mode: bool) -> Option<NativeError> {
+    fn set_event_notification_mode(&self, event: VMEvent, mode: bool) -> Option<NativeError> {
         unsafe {
             let mode_i = match mode { true => 1, false => 0 };
             let sptr: JavaObject = ptr::null_mut();
@@ -129,6 +183,33 @@ impl JVMTI for JVMTIEnvironment {
         }
     }
 
+    fn set_thread_event_notification_mode(&self, event: VMEvent, mode: bool, thread_id: &JavaThread) -> Option<NativeError> {
+        unsafe {
+            let mode_i = match mode { true => 1, false => 0 };
+
+            match wrap_error((**self.jvmti).SetEventNotificationMode.unwrap()(self.jvmti, mode_i, event as u32, *thread_id)) {
+                NativeError::NoError => None,
+                err @ _ => Some(err)
+            }
+        }
+    }
+
+    fn set_heap_sampling_interval(&mut self, sampling_interval: i32) -> Option<NativeError> {
+        if self.get_version_number().major_version < 11 {
+            return Some(NativeError::NotAvailable);
+        }
+
+        unsafe {
+            match (**self.jvmti).SetHeapSamplingInterval {
+                Some(func) => match wrap_error(func(self.jvmti, sampling_interval)) {
+                    NativeError::NoError => None,
+                    err @ _ => Some(err)
+                },
+                None => Some(NativeError::NotAvailable)
+            }
+        }
+    }
+
     fn get_thread_info(&self, thread_id: &JavaThread) -> Result<Thread, NativeError> {
         let mut info = Struct__jvmtiThreadInfo { name: ptr::null_mut(), priority: 0, is_daemon: 0, thread_group: ptr::null_mut(), context_class_loader: ptr::null_mut()};
         let mut info_ptr = &mut info;
@@ -139,6 +220,7 @@ impl JVMTI for JVMTIEnvironment {
                     match wrap_error(func(self.jvmti, *thread_id, info_ptr)) {
                         NativeError::NoError => Ok(Thread {
                             id: ThreadId { native_id: *thread_id },
+                            java_id: 0,
                             name: stringify((*info_ptr).name),
                             priority: (*info_ptr).priority as u32,
                             is_daemon: if (*info_ptr).is_daemon > 0 { true } else { false }
@@ -151,6 +233,62 @@ impl JVMTI for JVMTIEnvironment {
         }
     }
 
+    fn get_stack_trace(&self, thread_id: &JavaThread, max_frame_count: usize) -> Result<Vec<StackFrame>, NativeError> {
+        let mut frame_buffer: Vec<jvmtiFrameInfo> = vec![jvmtiFrameInfo::default(); max_frame_count];
+        let mut frame_count: i32 = 0;
+
+        unsafe {
+            match wrap_error((**self.jvmti).GetStackTrace.unwrap()(self.jvmti, *thread_id, 0, max_frame_count as i32, frame_buffer.as_mut_ptr(), &mut frame_count)) {
+                NativeError::NoError => Ok(frame_buffer.iter().take(frame_count as usize).map(|frame| StackFrame {
+                    method_id: MethodId { native_id: frame.method },
+                    location: frame.location
+                }).collect()),
+                err @ _ => Err(err)
+            }
+        }
+    }
+
+    fn get_frame_count(&self, thread_id: &JavaThread) -> Result<usize, NativeError> {
+        let mut frame_count: i32 = 0;
+
+        unsafe {
+            match wrap_error((**self.jvmti).GetFrameCount.unwrap()(self.jvmti, *thread_id, &mut frame_count)) {
+                NativeError::NoError => Ok(frame_count as usize),
+                err @ _ => Err(err)
+            }
+        }
+    }
+
+    fn get_all_stack_traces(&self, max_frame_count: usize) -> Result<Vec<ThreadStackTrace>, NativeError> {
+        let mut stack_info: *mut jvmtiStackInfo = ptr::null_mut();
+        let mut thread_count: i32 = 0;
+
+        unsafe {
+            match wrap_error((**self.jvmti).GetAllStackTraces.unwrap()(self.jvmti, max_frame_count as i32, &mut stack_info, &mut thread_count)) {
+                NativeError::NoError => {
+                    let traces = (0..thread_count as isize).map(|idx| {
+                        let info = &*stack_info.offset(idx);
+
+                        ThreadStackTrace {
+                            thread_id: ThreadId { native_id: info.thread },
+                            state: info.state as u32,
+                            frames: (0..info.frame_count as isize).map(|frame_idx| {
+                                let frame = &*info.frame_buffer.offset(frame_idx);
+                                StackFrame { method_id: MethodId { native_id: frame.method }, location: frame.location }
+                            }).collect()
+                        }
+                    }).collect();
+
+                    // The frame buffers are part of the same allocation as the stack info array
+                    (**self.jvmti).Deallocate.unwrap()(self.jvmti, stack_info as *mut u8);
+
+                    Ok(traces)
+                },
+                err @ _ => Err(err)
+            }
+        }
+    }
+
     fn get_method_declaring_class(&self, method_id: &MethodId) -> Result<ClassId, NativeError> {
         let mut jstruct: JavaInstance = JavaInstance { _hacky_hack_workaround: 0 };
         let mut jclass_instance: JavaClass = &mut jstruct;
@@ -176,7 +314,86 @@ impl JVMTI for JVMTIEnvironment {
 
         unsafe {
             match wrap_error((**self.jvmti).GetMethodName.unwrap()(self.jvmti, method_id.native_id, method_ptr, signature_ptr, generic_sig_ptr)) {
-                NativeError::NoError => Ok(MethodSignature::new(stringify(*method_ptr))),
+                NativeError::NoError => Ok(MethodSignature::with_descriptor(stringify(*method_ptr), stringify(*signature_ptr))),
+                err @ _ => Err(err)
+            }
+        }
+    }
+
+    fn get_method_descriptor(&self, method_id: &MethodId) -> Result<String, NativeError> {
+        let mut method_name: MutString = ptr::null_mut();
+        let mut signature: MutString = ptr::null_mut();
+        let mut generic_sig: MutString = ptr::null_mut();
+
+        unsafe {
+            match wrap_error((**self.jvmti).GetMethodName.unwrap()(self.jvmti, method_id.native_id, &mut method_name, &mut signature, &mut generic_sig)) {
+                NativeError::NoError => Ok(stringify(signature)),
+                err @ _ => Err(err)
+            }
+        }
+    }
+
+    fn get_method_modifiers(&self, method_id: &MethodId) -> Result<u32, NativeError> {
+        let mut modifiers: i32 = 0;
+
+        unsafe {
+            match wrap_error((**self.jvmti).GetMethodModifiers.unwrap()(self.jvmti, method_id.native_id, &mut modifiers)) {
+                NativeError::NoError => Ok(modifiers as u32),
+                err @ _ => Err(err)
+            }
+        }
+    }
+
+    fn get_local_int(&self, thread_id: &JavaThread, depth: usize, slot: usize) -> Result<i32, NativeError> {
+        let mut value: i32 = 0;
+
+        unsafe {
+            match wrap_error((**self.jvmti).GetLocalInt.unwrap()(self.jvmti, *thread_id, depth as i32, slot as i32, &mut value)) {
+                NativeError::NoError => Ok(value),
+                err @ _ => Err(err)
+            }
+        }
+    }
+
+    fn get_local_long(&self, thread_id: &JavaThread, depth: usize, slot: usize) -> Result<i64, NativeError> {
+        let mut value: i64 = 0;
+
+        unsafe {
+            match wrap_error((**self.jvmti).GetLocalLong.unwrap()(self.jvmti, *thread_id, depth as i32, slot as i32, &mut value)) {
+                NativeError::NoError => Ok(value),
+                err @ _ => Err(err)
+            }
+        }
+    }
+
+    fn get_local_float(&self, thread_id: &JavaThread, depth: usize, slot: usize) -> Result<f32, NativeError> {
+        let mut value: f32 = 0.0;
+
+        unsafe {
+            match wrap_error((**self.jvmti).GetLocalFloat.unwrap()(self.jvmti, *thread_id, depth as i32, slot as i32, &mut value)) {
+                NativeError::NoError => Ok(value),
+                err @ _ => Err(err)
+            }
+        }
+    }
+
+    fn get_local_double(&self, thread_id: &JavaThread, depth: usize, slot: usize) -> Result<f64, NativeError> {
+        let mut value: f64 = 0.0;
+
+        unsafe {
+            match wrap_error((**self.jvmti).GetLocalDouble.unwrap()(self.jvmti, *thread_id, depth as i32, slot as i32, &mut value)) {
+                NativeError::NoError => Ok(value),
+                err @ _ => Err(err)
+            }
+        }
+    }
+
+    fn get_local_object(&self, thread_id: &JavaThread, depth: usize, slot: usize) -> Result<JavaObject, NativeError> {
+        let mut value: JavaObject = ptr::null_mut();
+
+        unsafe {
+            match wrap_error((**self.jvmti).GetLocalObject.unwrap()(self.jvmti, *thread_id, depth as i32, slot as i32, &mut value)) {
+                NativeError::NoError => Ok(value),
                 err @ _ => Err(err)
             }
         }
@@ -196,6 +413,108 @@ impl JVMTI for JVMTIEnvironment {
         }
     }
 
+    fn get_loaded_classes(&self) -> Result<Vec<ClassId>, NativeError> {
+        let mut classes: *mut JavaClass = ptr::null_mut();
+        let mut class_count: i32 = 0;
+
+        unsafe {
+            match wrap_error((**self.jvmti).GetLoadedClasses.unwrap()(self.jvmti, &mut class_count, &mut classes)) {
+                NativeError::NoError => {
+                    let class_ids = (0..class_count as isize).map(|idx| ClassId { native_id: *classes.offset(idx) }).collect();
+
+                    (**self.jvmti).Deallocate.unwrap()(self.jvmti, classes as *mut u8);
+
+                    Ok(class_ids)
+                },
+                err @ _ => Err(err)
+            }
+        }
+    }
+
+    fn retransform_classes(&self, classes: &[ClassId]) -> Option<NativeError> {
+        let native_ids: Vec<JavaClass> = classes.iter().map(|class| class.native_id).collect();
+
+        unsafe {
+            match wrap_error((**self.jvmti).RetransformClasses.unwrap()(self.jvmti, native_ids.len() as i32, native_ids.as_ptr())) {
+                NativeError::NoError => None,
+                err @ _ => Some(err)
+            }
+        }
+    }
+
+    fn get_thread_cpu_time(&self, thread_id: &JavaThread) -> Result<u64, NativeError> {
+        let mut nanos: JavaLong = 0;
+
+        unsafe {
+            match wrap_error((**self.jvmti).GetThreadCpuTime.unwrap()(self.jvmti, *thread_id, &mut nanos)) {
+                NativeError::NoError => Ok(nanos as u64),
+                err @ _ => Err(err)
+            }
+        }
+    }
+
+    fn get_current_thread_cpu_time(&self) -> Result<u64, NativeError> {
+        let mut nanos: JavaLong = 0;
+
+        unsafe {
+            match wrap_error((**self.jvmti).GetCurrentThreadCpuTime.unwrap()(self.jvmti, &mut nanos)) {
+                NativeError::NoError => Ok(nanos as u64),
+                err @ _ => Err(err)
+            }
+        }
+    }
+
+    fn create_raw_monitor(&self, name: &str) -> Result<RawMonitorId, NativeError> {
+        let native_name = match CString::new(name) {
+            Ok(native_name) => native_name,
+            Err(_) => return Err(NativeError::IllegalArgument)
+        };
+        let mut monitor: jrawMonitorID = ptr::null_mut();
+
+        unsafe {
+            match wrap_error((**self.jvmti).CreateRawMonitor.unwrap()(self.jvmti, native_name.as_ptr(), &mut monitor)) {
+                NativeError::NoError => Ok(RawMonitorId { native_id: monitor }),
+                err @ _ => Err(err)
+            }
+        }
+    }
+
+    fn destroy_raw_monitor(&self, monitor: &RawMonitorId) -> Result<(), NativeError> {
+        unsafe {
+            match wrap_error((**self.jvmti).DestroyRawMonitor.unwrap()(self.jvmti, monitor.native_id)) {
+                NativeError::NoError => Ok(()),
+                err @ _ => Err(err)
+            }
+        }
+    }
+
+    fn raw_monitor_enter(&self, monitor: &RawMonitorId) -> Result<(), NativeError> {
+        unsafe {
+            match wrap_error((**self.jvmti).RawMonitorEnter.unwrap()(self.jvmti, monitor.native_id)) {
+                NativeError::NoError => Ok(()),
+                err @ _ => Err(err)
+            }
+        }
+    }
+
+    fn raw_monitor_exit(&self, monitor: &RawMonitorId) -> Result<(), NativeError> {
+        unsafe {
+            match wrap_error((**self.jvmti).RawMonitorExit.unwrap()(self.jvmti, monitor.native_id)) {
+                NativeError::NoError => Ok(()),
+                err @ _ => Err(err)
+            }
+        }
+    }
+
+    fn set_tag(&self, object: &JavaObject, tag: TagId) -> Result<(), NativeError> {
+        unsafe {
+            match wrap_error((**self.jvmti).SetTag.unwrap()(self.jvmti, *object, tag)) {
+                NativeError::NoError => Ok(()),
+                err @ _ => Err(err)
+            }
+        }
+    }
+
     fn allocate(&self, len: usize) -> Result<MemoryAllocation, NativeError> {
         let size: JavaLong = len as JavaLong;
         let mut ptr: MutByteArray = ptr::null_mut();
@@ -1,12 +1,15 @@
 use self::jvmti::{JVMTI, JVMTIEnvironment};
-use self::jni::{JNI, JNIEnvironment};
+use self::jni::{GlobalRef, JNI, JNIEnvironment, JNIError, JObject, JValue};
 use super::capabilities::Capabilities;
 use super::class::{ClassId, ClassSignature};
 use super::error::NativeError;
+use super::field::FieldId;
 use super::event::{EventCallbacks, VMEvent};
 use super::mem::MemoryAllocation;
 use super::method::{MethodId, MethodSignature};
-use super::native::{JavaObject, JavaThread};
+use super::monitor::RawMonitorId;
+use super::native::{JavaObject, JavaThread, TagId};
+use super::stack::{StackFrame, ThreadStackTrace};
 use super::thread::Thread;
 use super::version::VersionNumber;
 
@@ -46,14 +49,34 @@ impl JVMTI for Environment {
         self.jvmti.set_event_callbacks(callbacks)
     }
 
-    fn set_event_notification_mode(&mut self, event: VMEvent, mode: bool) -> Option<NativeError> {
+    fn set_event_notification_mode(&self, event: VMEvent, mode: bool) -> Option<NativeError> {
         self.jvmti.set_event_notification_mode(event, mode)
     }
 
+    fn set_thread_event_notification_mode(&self, event: VMEvent, mode: bool, thread_id: &JavaThread) -> Option<NativeError> {
+        self.jvmti.set_thread_event_notification_mode(event, mode, thread_id)
+    }
+
+    fn set_heap_sampling_interval(&mut self, sampling_interval: i32) -> Option<NativeError> {
+        self.jvmti.set_heap_sampling_interval(sampling_interval)
+    }
+
     fn get_thread_info(&self, thread_id: &JavaThread) -> Result<Thread, NativeError> {
         self.jvmti.get_thread_info(thread_id)
     }
 
+    fn get_stack_trace(&self, thread_id: &JavaThread, max_frame_count: usize) -> Result<Vec<StackFrame>, NativeError> {
+        self.jvmti.get_stack_trace(thread_id, max_frame_count)
+    }
+
+    fn get_frame_count(&self, thread_id: &JavaThread) -> Result<usize, NativeError> {
+        self.jvmti.get_frame_count(thread_id)
+    }
+
+    fn get_all_stack_traces(&self, max_frame_count: usize) -> Result<Vec<ThreadStackTrace>, NativeError> {
+        self.jvmti.get_all_stack_traces(max_frame_count)
+    }
+
     fn get_method_declaring_class(&self, method_id: &MethodId) -> Result<ClassId, NativeError> {
         self.jvmti.get_method_declaring_class(method_id)
     }
@@ -62,10 +85,74 @@ impl JVMTI for Environment {
         self.jvmti.get_method_name(method_id)
     }
 
+    fn get_method_descriptor(&self, method_id: &MethodId) -> Result<String, NativeError> {
+        self.jvmti.get_method_descriptor(method_id)
+    }
+
+    fn get_method_modifiers(&self, method_id: &MethodId) -> Result<u32, NativeError> {
+        self.jvmti.get_method_modifiers(method_id)
+    }
+
+    fn get_local_int(&self, thread_id: &JavaThread, depth: usize, slot: usize) -> Result<i32, NativeError> {
+        self.jvmti.get_local_int(thread_id, depth, slot)
+    }
+
+    fn get_local_long(&self, thread_id: &JavaThread, depth: usize, slot: usize) -> Result<i64, NativeError> {
+        self.jvmti.get_local_long(thread_id, depth, slot)
+    }
+
+    fn get_local_float(&self, thread_id: &JavaThread, depth: usize, slot: usize) -> Result<f32, NativeError> {
+        self.jvmti.get_local_float(thread_id, depth, slot)
+    }
+
+    fn get_local_double(&self, thread_id: &JavaThread, depth: usize, slot: usize) -> Result<f64, NativeError> {
+        self.jvmti.get_local_double(thread_id, depth, slot)
+    }
+
+    fn get_local_object(&self, thread_id: &JavaThread, depth: usize, slot: usize) -> Result<JavaObject, NativeError> {
+        self.jvmti.get_local_object(thread_id, depth, slot)
+    }
+
     fn get_class_signature(&self, class_id: &ClassId) -> Result<ClassSignature, NativeError> {
         self.jvmti.get_class_signature(class_id)
     }
 
+    fn get_loaded_classes(&self) -> Result<Vec<ClassId>, NativeError> {
+        self.jvmti.get_loaded_classes()
+    }
+
+    fn retransform_classes(&self, classes: &[ClassId]) -> Option<NativeError> {
+        self.jvmti.retransform_classes(classes)
+    }
+
+    fn get_thread_cpu_time(&self, thread_id: &JavaThread) -> Result<u64, NativeError> {
+        self.jvmti.get_thread_cpu_time(thread_id)
+    }
+
+    fn get_current_thread_cpu_time(&self) -> Result<u64, NativeError> {
+        self.jvmti.get_current_thread_cpu_time()
+    }
+
+    fn create_raw_monitor(&self, name: &str) -> Result<RawMonitorId, NativeError> {
+        self.jvmti.create_raw_monitor(name)
+    }
+
+    fn destroy_raw_monitor(&self, monitor: &RawMonitorId) -> Result<(), NativeError> {
+        self.jvmti.destroy_raw_monitor(monitor)
+    }
+
+    fn raw_monitor_enter(&self, monitor: &RawMonitorId) -> Result<(), NativeError> {
+        self.jvmti.raw_monitor_enter(monitor)
+    }
+
+    fn raw_monitor_exit(&self, monitor: &RawMonitorId) -> Result<(), NativeError> {
+        self.jvmti.raw_monitor_exit(monitor)
+    }
+
+    fn set_tag(&self, object: &JavaObject, tag: TagId) -> Result<(), NativeError> {
+        self.jvmti.set_tag(object, tag)
+    }
+
     fn allocate(&self, len: usize) -> Result<MemoryAllocation, NativeError> {
         self.jvmti.allocate(len)
     }
@@ -81,4 +168,143 @@ impl JNI for Environment {
         self.jni.get_object_class(object_id)
     }
 
+    fn find_class(&self, name: &str) -> Result<JObject, JNIError> {
+        self.jni.find_class(name)
+    }
+
+    fn get_method_id(&self, class: &JObject, name: &str, signature: &str) -> Result<MethodId, JNIError> {
+        self.jni.get_method_id(class, name, signature)
+    }
+
+    fn get_static_method_id(&self, class: &JObject, name: &str, signature: &str) -> Result<MethodId, JNIError> {
+        self.jni.get_static_method_id(class, name, signature)
+    }
+
+    fn get_field_id(&self, class: &JObject, name: &str, signature: &str) -> Result<FieldId, JNIError> {
+        self.jni.get_field_id(class, name, signature)
+    }
+
+    fn get_static_field_id(&self, class: &JObject, name: &str, signature: &str) -> Result<FieldId, JNIError> {
+        self.jni.get_static_field_id(class, name, signature)
+    }
+
+    fn call_method(&self, object: &JObject, method_id: &MethodId, signature: &str, args: &[JValue]) -> Result<JValue, JNIError> {
+        self.jni.call_method(object, method_id, signature, args)
+    }
+
+    fn call_static_method(&self, class: &JObject, method_id: &MethodId, signature: &str, args: &[JValue]) -> Result<JValue, JNIError> {
+        self.jni.call_static_method(class, method_id, signature, args)
+    }
+
+    fn new_object(&self, class: &JObject, constructor_id: &MethodId, args: &[JValue]) -> Result<JObject, JNIError> {
+        self.jni.new_object(class, constructor_id, args)
+    }
+
+    fn get_field(&self, object: &JObject, field_id: &FieldId, signature: &str) -> Result<JValue, JNIError> {
+        self.jni.get_field(object, field_id, signature)
+    }
+
+    fn set_field(&self, object: &JObject, field_id: &FieldId, value: &JValue) -> Result<(), JNIError> {
+        self.jni.set_field(object, field_id, value)
+    }
+
+    fn get_static_field(&self, class: &JObject, field_id: &FieldId, signature: &str) -> Result<JValue, JNIError> {
+        self.jni.get_static_field(class, field_id, signature)
+    }
+
+    fn set_static_field(&self, class: &JObject, field_id: &FieldId, value: &JValue) -> Result<(), JNIError> {
+        self.jni.set_static_field(class, field_id, value)
+    }
+
+    fn new_string(&self, value: &str) -> Result<JObject, JNIError> {
+        self.jni.new_string(value)
+    }
+
+    fn get_string(&self, string: &JObject) -> Result<String, JNIError> {
+        self.jni.get_string(string)
+    }
+
+    fn get_array_length(&self, array: &JObject) -> Result<usize, JNIError> {
+        self.jni.get_array_length(array)
+    }
+
+    fn new_object_array(&self, length: usize, element_class: &JObject, initial_element: &JObject) -> Result<JObject, JNIError> {
+        self.jni.new_object_array(length, element_class, initial_element)
+    }
+
+    fn get_object_array_element(&self, array: &JObject, index: usize) -> Result<JObject, JNIError> {
+        self.jni.get_object_array_element(array, index)
+    }
+
+    fn set_object_array_element(&self, array: &JObject, index: usize, value: &JObject) -> Result<(), JNIError> {
+        self.jni.set_object_array_element(array, index, value)
+    }
+
+    fn new_byte_array(&self, values: &[i8]) -> Result<JObject, JNIError> {
+        self.jni.new_byte_array(values)
+    }
+
+    fn get_byte_array(&self, array: &JObject) -> Result<Vec<i8>, JNIError> {
+        self.jni.get_byte_array(array)
+    }
+
+    fn new_int_array(&self, values: &[i32]) -> Result<JObject, JNIError> {
+        self.jni.new_int_array(values)
+    }
+
+    fn get_int_array(&self, array: &JObject) -> Result<Vec<i32>, JNIError> {
+        self.jni.get_int_array(array)
+    }
+
+    fn new_long_array(&self, values: &[i64]) -> Result<JObject, JNIError> {
+        self.jni.new_long_array(values)
+    }
+
+    fn get_long_array(&self, array: &JObject) -> Result<Vec<i64>, JNIError> {
+        self.jni.get_long_array(array)
+    }
+
+    fn is_same_object(&self, first: &JObject, second: &JObject) -> bool {
+        self.jni.is_same_object(first, second)
+    }
+
+    fn new_local_ref(&self, object: &JObject) -> Result<JObject, JNIError> {
+        self.jni.new_local_ref(object)
+    }
+
+    fn delete_local_ref(&self, object: JObject) {
+        self.jni.delete_local_ref(object)
+    }
+
+    fn new_global_ref(&self, object: &JObject) -> Result<GlobalRef, JNIError> {
+        self.jni.new_global_ref(object)
+    }
+
+    fn delete_global_ref(&self, global: GlobalRef) {
+        self.jni.delete_global_ref(global)
+    }
+
+    fn push_local_frame(&self, capacity: i32) -> Result<(), NativeError> {
+        self.jni.push_local_frame(capacity)
+    }
+
+    fn pop_local_frame(&self, result: &JObject) -> JavaObject {
+        self.jni.pop_local_frame(result)
+    }
+
+    fn exception_check(&self) -> bool {
+        self.jni.exception_check()
+    }
+
+    fn exception_occurred(&self) -> Option<JObject> {
+        self.jni.exception_occurred()
+    }
+
+    fn exception_describe(&self) {
+        self.jni.exception_describe()
+    }
+
+    fn exception_clear(&self) {
+        self.jni.exception_clear()
+    }
 }
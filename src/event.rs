@@ -1,3 +1,4 @@
+use super::native::TagId;
 use super::native::jvmti_native::*;
 use super::runtime::*;
 use super::thread::Thread;
@@ -8,14 +9,15 @@ pub type FnVMInit = fn() -> ();
 pub type FnVMDeath = fn() -> ();
 pub type FnVMStart = fn() -> ();
 pub type FnVMObjectAlloc = fn(event: ObjectAllocationEvent) -> ();
-pub type FnVMObjectFree = fn() -> ();
-pub type FnThreadStart = fn(thread: Thread) -> ();
-pub type FnThreadEnd = fn(thread: Thread) -> ();
-pub type FnException = fn() -> ();
-pub type FnExceptionCatch = fn() -> ();
-pub type FnMonitorWait = fn(thread: Thread) -> ();
-pub type FnMonitorWaited = fn(thread: Thread) -> ();
-pub type FnMonitorContendedEnter = fn(thread: Thread) -> ();
+pub type FnSampledObjectAlloc = fn(event: ObjectAllocationEvent) -> ();
+pub type FnVMObjectFree = fn(tag: TagId) -> ();
+pub type FnThreadStart = fn(event: ThreadEvent) -> ();
+pub type FnThreadEnd = fn(event: ThreadEvent) -> ();
+pub type FnException = fn(event: ExceptionEvent) -> ();
+pub type FnExceptionCatch = fn(event: ExceptionCatchEvent) -> ();
+pub type FnMonitorWait = fn(event: MonitorWaitEvent) -> ();
+pub type FnMonitorWaited = fn(event: MonitorWaitedEvent) -> ();
+pub type FnMonitorContendedEnter = fn(event: MonitorEvent) -> ();
 pub type FnMonitorContendedEntered = fn(thread: Thread) -> ();
 pub type FnFieldAccess = fn() -> ();
 pub type FnFieldModification = fn() -> ();
@@ -45,6 +47,7 @@ pub enum VMEvent {
     VMDeath = JVMTI_EVENT_VM_DEATH as isize,
     VMObjectAlloc = JVMTI_EVENT_VM_OBJECT_ALLOC as isize,
     VMObjectFree = JVMTI_EVENT_OBJECT_FREE as isize,
+    SampledObjectAlloc = JVMTI_EVENT_SAMPLED_OBJECT_ALLOC as isize,
     VMStart = JVMTI_EVENT_VM_START as isize,
     MethodEntry = JVMTI_EVENT_METHOD_ENTRY as isize,
     MethodExit = JVMTI_EVENT_METHOD_EXIT as isize,
@@ -84,6 +87,7 @@ pub struct EventCallbacks {
     pub vm_death: Option<FnVMDeath>,
     pub vm_object_alloc: Option<FnVMObjectAlloc>,
     pub vm_object_free: Option<FnVMObjectFree>,
+    pub sampled_object_alloc: Option<FnSampledObjectAlloc>,
     pub vm_start: Option<FnVMStart>,
     pub method_entry: Option<FnMethodEntry>,
     pub method_exit: Option<FnMethodExit>,
@@ -1,12 +1,17 @@
+use super::class::{ClassId, ClassSignature};
 use super::environment::Environment;
-use super::environment::jni::{JNI, JNIEnvironment};
+use super::environment::jni::{JNI, JNIEnvironment, JObject};
 use super::environment::jvmti::{JVMTI, JVMTIEnvironment};
 use super::error::{translate_error, NativeError};
 use super::event::*;
-use super::method::MethodId;
+use super::gc;
+use super::logging::logger;
+use super::method::{MethodId, MethodSignature};
 use super::native::*;
 use super::native::jvmti_native::*;
 use super::runtime::*;
+use super::stack::{resolve_stack_trace, StackFrame, StackTraceElement};
+use super::thread::{current_java_thread_id, forget_current_java_thread_id, Thread};
 use libc::{c_char, c_uchar, c_void};
 use std::mem::size_of;
 use std::ptr;
@@ -19,6 +24,7 @@ pub static mut CALLBACK_TABLE: EventCallbacks = EventCallbacks {
     vm_death: None,
     vm_object_alloc: None,
     vm_object_free: None,
+    sampled_object_alloc: None,
     vm_start: None,
     method_entry: None,
     method_exit: None,
@@ -64,6 +70,10 @@ pub fn register_vm_object_free_callback(callback: Option<FnVMObjectFree>) {
     unsafe { CALLBACK_TABLE.vm_object_free = callback; }
 }
 
+pub fn register_sampled_object_alloc_callback(callback: Option<FnSampledObjectAlloc>) {
+    unsafe { CALLBACK_TABLE.sampled_object_alloc = callback; }
+}
+
 pub fn register_vm_start_callback(callback: Option<FnVMStart>) {
     unsafe { CALLBACK_TABLE.vm_start = callback; }
 }
@@ -171,31 +181,95 @@ pub fn local_event_callbacks() -> jvmtiEventCallbacks {
         GarbageCollectionStart: Some(local_cb_garbage_collection_start), //jvmtiEventGarbageCollectionStart,
         GarbageCollectionFinish: Some(local_cb_garbage_collection_finish), //jvmtiEventGarbageCollectionFinish,
         ObjectFree: Some(local_cb_object_free), //jvmtiEventObjectFree,
-        VMObjectAlloc: Some(local_cb_vm_object_alloc) //jvmtiEventVMObjectAlloc,
+        VMObjectAlloc: Some(local_cb_vm_object_alloc), //jvmtiEventVMObjectAlloc,
+        reserved85: None, //jvmtiEventReserved,
+        SampledObjectAlloc: Some(local_cb_sampled_object_alloc) //jvmtiEventSampledObjectAlloc,
+    }
+}
+
+
+///
+/// Return the thread an event is reported on along with its Java id. The events raised while the
+/// id is being resolved are rejected with `NotAvailable`.
+///
+fn current_thread_info(env: &Environment, thread: &JavaThread) -> Result<Thread, NativeError> {
+    let mut current_thread = env.get_thread_info(thread)?;
+
+    current_thread.java_id = current_java_thread_id(env, &current_thread.id).ok_or(NativeError::NotAvailable)?;
+    Ok(current_thread)
+}
+
+/// Maximum number of stack frames captured for object allocation events
+const ALLOCATION_STACK_DEPTH: usize = 32;
+
+///
+/// Assembles an `ObjectAllocationEvent` for an object of the given class that has just been
+/// allocated by the given thread, including the stack trace of the allocating thread.
+///
+fn allocation_event(env: &Environment, thread: &JavaThread, class_id: ClassId, size: jlong) -> Result<ObjectAllocationEvent, NativeError> {
+    match current_thread_info(env, thread) {
+        Ok(current_thread) => {
+            let class_sig = env.get_class_signature(&class_id).unwrap_or(ClassSignature::unknown());
+            let stack_trace = match env.get_stack_trace(thread, ALLOCATION_STACK_DEPTH) {
+                Ok(frames) => resolve_stack_trace(env, &frames),
+                Err(_) => vec![]
+            };
+
+            Ok(ObjectAllocationEvent { class_id: class_id, class_sig: class_sig, size: size as i64, thread: current_thread, stack_trace: stack_trace })
+        },
+        Err(err) => Err(err)
     }
 }
 
+/// Tag the sampled object if the number of freed sampled objects is being counted
+fn tag_allocated_object(env: &Environment, object: &JavaObject) {
+    if gc::is_tagging_objects() {
+        let _ = env.set_tag(object, gc::FREED_OBJECT_TAG);
+    }
+}
 
 #[allow(unused_variables)]
 unsafe extern "C" fn local_cb_vm_object_alloc(jvmti_env: *mut jvmtiEnv, jni_env: *mut JNIEnv, thread: JavaThread, object: JavaObject, object_klass: JavaClass, size: jlong) -> () {
     match CALLBACK_TABLE.vm_object_alloc {
         Some(function) => {
             let env = Environment::new(JVMTIEnvironment::new(jvmti_env), JNIEnvironment::new(jni_env));
-            match env.get_thread_info(&thread) {
-                Ok(current_thread) => {
-                    let class_id = env.get_object_class(&object);
+            let class_id = env.get_object_class(&object);
 
-                    function(ObjectAllocationEvent { class_id: class_id, size: size as i64, thread: current_thread })
-                },
+            tag_allocated_object(&env, &object);
+
+            match allocation_event(&env, &thread, class_id, size) {
+                Ok(event) => function(event),
+                Err(err) => {
+                    match err {
+                        NativeError::WrongPhase | NativeError::NotAvailable => { /* we're in the wrong phase or resolving the thread's id, just ignore this */ },
+                        _ => warn!(logger(), "Couldn't get thread info: {}", translate_error(&err))
+                    }
+                }
+            }
+        },
+        None => debug!(logger(), "No dynamic callback method was found for VM object allocation")
+    }
+}
+
+#[allow(unused_variables)]
+unsafe extern "C" fn local_cb_sampled_object_alloc(jvmti_env: *mut jvmtiEnv, jni_env: *mut JNIEnv, thread: JavaThread, object: JavaObject, object_klass: JavaClass, size: jlong) -> () {
+    match CALLBACK_TABLE.sampled_object_alloc {
+        Some(function) => {
+            let env = Environment::new(JVMTIEnvironment::new(jvmti_env), JNIEnvironment::new(jni_env));
+
+            tag_allocated_object(&env, &object);
+
+            match allocation_event(&env, &thread, ClassId { native_id: object_klass }, size) {
+                Ok(event) => function(event),
                 Err(err) => {
                     match err {
-                        NativeError::NotAvailable => { /* we're in the wrong phase, just ignore this */ },
-                        _ => println!("Couldn't get thread info: {}", translate_error(&err))
+                        NativeError::WrongPhase | NativeError::NotAvailable => { /* we're in the wrong phase or resolving the thread's id, just ignore this */ },
+                        _ => warn!(logger(), "Couldn't get thread info: {}", translate_error(&err))
                     }
                 }
             }
         },
-        None => println!("No dynamic callback method was found for VM object allocation")
+        None => debug!(logger(), "No dynamic callback method was found for sampled object allocation")
     }
 }
 
@@ -204,25 +278,25 @@ unsafe extern "C" fn local_cb_method_entry(jvmti_env: *mut jvmtiEnv, jni_env: *m
     match CALLBACK_TABLE.method_entry {
         Some(function) => {
             let env = Environment::new(JVMTIEnvironment::new(jvmti_env), JNIEnvironment::new(jni_env));
-            match env.get_thread_info(&thread) {
+            match current_thread_info(&env, &thread) {
                 Ok(current_thread) => {
                     let method_id = MethodId { native_id : method };
                     let class_id = env.get_method_declaring_class(&method_id).ok().unwrap();
                     let class_sig = env.get_class_signature(&class_id).ok().unwrap();
                     let method_sig = env.get_method_name(&method_id).ok().unwrap();
 
-                    function(MethodInvocationEvent { method_id: method_id, method_sig: method_sig, class_sig: class_sig, thread: current_thread })
+                    function(MethodInvocationEvent { method_id: method_id, method_sig: method_sig, class_sig: class_sig, thread: current_thread, popped_by_exception: false })
 
                 },
                 Err(err) => {
                     match err {
-                        NativeError::NotAvailable => { /* we're in the wrong phase, just ignore this */ },
-                        _ => println!("Couldn't get thread info: {}", translate_error(&err))
+                        NativeError::WrongPhase | NativeError::NotAvailable => { /* we're in the wrong phase or resolving the thread's id, just ignore this */ },
+                        _ => warn!(logger(), "Couldn't get thread info: {}", translate_error(&err))
                     }
                 }
             }
         },
-        None => println!("No dynamic callback method was found for method entry")
+        None => debug!(logger(), "No dynamic callback method was found for method entry")
     }
 }
 
@@ -231,25 +305,44 @@ unsafe extern "C" fn local_cb_method_exit(jvmti_env: *mut jvmtiEnv, jni_env: *mu
     match CALLBACK_TABLE.method_exit {
         Some(function) => {
             let env = Environment::new(JVMTIEnvironment::new(jvmti_env), JNIEnvironment::new(jni_env));
-            match env.get_thread_info(&thread) {
+            match current_thread_info(&env, &thread) {
                 Ok(current_thread) => {
                     let method_id = MethodId { native_id : method };
                     let class_id = env.get_method_declaring_class(&method_id).ok().unwrap();
                     let class_sig = env.get_class_signature(&class_id).ok().unwrap();
                     let method_sig = env.get_method_name(&method_id).ok().unwrap();
 
-                    function(MethodInvocationEvent { method_id: method_id, method_sig: method_sig, class_sig: class_sig, thread: current_thread })
+                    function(MethodInvocationEvent { method_id: method_id, method_sig: method_sig, class_sig: class_sig, thread: current_thread, popped_by_exception: was_popped_by_exception > 0 })
 
                 },
                 Err(err) => {
                     match err {
-                        NativeError::NotAvailable => { /* we're in the wrong phase, just ignore this */ },
-                        _ => println!("Couldn't get thread info: {}", translate_error(&err))
+                        NativeError::WrongPhase | NativeError::NotAvailable => { /* we're in the wrong phase or resolving the thread's id, just ignore this */ },
+                        _ => warn!(logger(), "Couldn't get thread info: {}", translate_error(&err))
                     }
                 }
             }
         }
-        None => println!("No dynamic callback method was found for method exit")
+        None => debug!(logger(), "No dynamic callback method was found for method exit")
+    }
+}
+
+/// Return the signature of the class of the given object
+fn object_class(env: &Environment, object: &JavaObject) -> ClassSignature {
+    let class_id = env.get_object_class(object);
+    let class_sig = env.get_class_signature(&class_id).unwrap_or(ClassSignature::unknown());
+
+    env.delete_local_ref(JObject::from(&class_id));
+    class_sig
+}
+
+/// Resolve a method and a location within it into a stack trace element
+fn code_location(env: &Environment, method: jmethodID, location: jlocation) -> StackTraceElement {
+    let frame = StackFrame { method_id: MethodId { native_id: method }, location: location };
+
+    match resolve_stack_trace(env, &[frame]).pop() {
+        Some(element) => element,
+        None => StackTraceElement::new(ClassSignature::unknown(), MethodSignature::unknown(), location as i64)
     }
 }
 
@@ -257,13 +350,24 @@ unsafe extern "C" fn local_cb_method_exit(jvmti_env: *mut jvmtiEnv, jni_env: *mu
 unsafe extern "C" fn local_cb_exception(jvmti_env: *mut jvmtiEnv, jni_env: *mut JNIEnv, thread: jthread, method: jmethodID, location: jlocation, exception: JavaObject, catch_method: jmethodID, catch_location: jlocation) -> () {
     match CALLBACK_TABLE.exception {
         Some(function) => {
-            function();
             let env = Environment::new(JVMTIEnvironment::new(jvmti_env), JNIEnvironment::new(jni_env));
-            let exception_class = env.get_object_class(&exception);
-
-            function()
+            match current_thread_info(&env, &thread) {
+                Ok(current_thread) => function(ExceptionEvent {
+                    thread: current_thread,
+                    exception_class: object_class(&env, &exception),
+                    throw_site: code_location(&env, method, location),
+                    catch_site: if catch_method.is_null() { None } else { Some(code_location(&env, catch_method, catch_location)) },
+                    stack_depth: env.get_frame_count(&thread).unwrap_or(0)
+                }),
+                Err(err) => {
+                    match err {
+                        NativeError::WrongPhase | NativeError::NotAvailable => { /* we're in the wrong phase or resolving the thread's id, just ignore this */ },
+                        _ => warn!(logger(), "Couldn't get thread info: {}", translate_error(&err))
+                    }
+                }
+            }
         },
-        None => println!("No dynamic callback method was found for exception")
+        None => debug!(logger(), "No dynamic callback method was found for exception")
     }
 }
 
@@ -271,15 +375,33 @@ unsafe extern "C" fn local_cb_exception(jvmti_env: *mut jvmtiEnv, jni_env: *mut
 unsafe extern "C" fn local_cb_exception_catch(jvmti_env: *mut jvmtiEnv, jni_env: *mut JNIEnv, thread: jthread, method: jmethodID, location: jlocation, exception: jobject) -> () {
     match CALLBACK_TABLE.exception_catch {
         Some(function) => {
-            function();
-            /*
             let env = Environment::new(JVMTIEnvironment::new(jvmti_env), JNIEnvironment::new(jni_env));
-            let current_thread = env.get_thread_info(&thread).ok().unwrap();
-
-            function()
-            */
+            match current_thread_info(&env, &thread) {
+                Ok(current_thread) => function(ExceptionCatchEvent {
+                    thread: current_thread,
+                    exception_class: object_class(&env, &exception),
+                    catch_site: code_location(&env, method, location),
+                    stack_depth: env.get_frame_count(&thread).unwrap_or(0)
+                }),
+                Err(err) => {
+                    match err {
+                        NativeError::WrongPhase | NativeError::NotAvailable => { /* we're in the wrong phase or resolving the thread's id, just ignore this */ },
+                        _ => warn!(logger(), "Couldn't get thread info: {}", translate_error(&err))
+                    }
+                }
+            }
         },
-        None => println!("No dynamic callback method was found for exception catch")
+        None => debug!(logger(), "No dynamic callback method was found for exception catch")
+    }
+}
+
+/// Number of stack frames captured when a thread starts blocking or waiting on a monitor
+const MONITOR_STACK_DEPTH: usize = 32;
+
+fn monitor_stack_trace(env: &Environment, thread: &JavaThread) -> Vec<StackTraceElement> {
+    match env.get_stack_trace(thread, MONITOR_STACK_DEPTH) {
+        Ok(frames) => resolve_stack_trace(env, &frames),
+        Err(_) => vec![]
     }
 }
 
@@ -288,17 +410,22 @@ unsafe extern "C" fn local_cb_monitor_wait(jvmti_env: *mut jvmtiEnv, jni_env: *m
     match CALLBACK_TABLE.monitor_wait {
         Some(function) => {
             let env = Environment::new(JVMTIEnvironment::new(jvmti_env), JNIEnvironment::new(jni_env));
-            match env.get_thread_info(&thread) {
-                Ok(current_thread) => function(current_thread),
+            match current_thread_info(&env, &thread) {
+                Ok(current_thread) => function(MonitorWaitEvent {
+                    thread: current_thread,
+                    class_sig: object_class(&env, &object),
+                    stack_trace: monitor_stack_trace(&env, &thread),
+                    timeout: timeout as i64
+                }),
                 Err(err) => {
                     match err {
-                        NativeError::NotAvailable => { /* we're in the wrong phase, just ignore this */ },
-                        _ => println!("Couldn't get thread info: {}", translate_error(&err))
+                        NativeError::WrongPhase | NativeError::NotAvailable => { /* we're in the wrong phase or resolving the thread's id, just ignore this */ },
+                        _ => warn!(logger(), "Couldn't get thread info: {}", translate_error(&err))
                     }
                 }
             }
         },
-        None => println!("No dynamic callback method was found for monitor wait")
+        None => debug!(logger(), "No dynamic callback method was found for monitor wait")
     }
 }
 
@@ -307,17 +434,21 @@ unsafe extern "C" fn local_cb_monitor_waited(jvmti_env: *mut jvmtiEnv, jni_env:
     match CALLBACK_TABLE.monitor_waited {
         Some(function) => {
             let env = Environment::new(JVMTIEnvironment::new(jvmti_env), JNIEnvironment::new(jni_env));
-            match env.get_thread_info(&thread) {
-                Ok(current_thread) => function(current_thread),
+            match current_thread_info(&env, &thread) {
+                Ok(current_thread) => function(MonitorWaitedEvent {
+                    thread: current_thread,
+                    class_sig: object_class(&env, &object),
+                    timed_out: timed_out > 0
+                }),
                 Err(err) => {
                     match err {
-                        NativeError::NotAvailable => { /* we're in the wrong phase, just ignore this */ },
-                        _ => println!("Couldn't get thread info: {}", translate_error(&err))
+                        NativeError::WrongPhase | NativeError::NotAvailable => { /* we're in the wrong phase or resolving the thread's id, just ignore this */ },
+                        _ => warn!(logger(), "Couldn't get thread info: {}", translate_error(&err))
                     }
                 }
             }
         },
-        None => println!("No dynamic callback method was found for monitor entered")
+        None => debug!(logger(), "No dynamic callback method was found for monitor entered")
     }
 }
 
@@ -326,17 +457,21 @@ unsafe extern "C" fn local_cb_monitor_contended_enter(jvmti_env: *mut jvmtiEnv,
     match CALLBACK_TABLE.monitor_contended_enter {
         Some(function) => {
             let env = Environment::new(JVMTIEnvironment::new(jvmti_env), JNIEnvironment::new(jni_env));
-            match env.get_thread_info(&thread) {
-                Ok(current_thread) => function(current_thread),
+            match current_thread_info(&env, &thread) {
+                Ok(current_thread) => function(MonitorEvent {
+                    thread: current_thread,
+                    class_sig: object_class(&env, &object),
+                    stack_trace: monitor_stack_trace(&env, &thread)
+                }),
                 Err(err) => {
                     match err {
-                        NativeError::NotAvailable => { /* we're in the wrong phase, just ignore this */ },
-                        _ => println!("Couldn't get thread info: {}", translate_error(&err))
+                        NativeError::WrongPhase | NativeError::NotAvailable => { /* we're in the wrong phase or resolving the thread's id, just ignore this */ },
+                        _ => warn!(logger(), "Couldn't get thread info: {}", translate_error(&err))
                     }
                 }
             }
         },
-        None => println!("No dynamic callback method was found for monitor contended enter")
+        None => debug!(logger(), "No dynamic callback method was found for monitor contended enter")
     }
 }
 
@@ -345,17 +480,24 @@ unsafe extern "C" fn local_cb_monitor_contended_entered(jvmti_env: *mut jvmtiEnv
     match CALLBACK_TABLE.monitor_contended_entered {
         Some(function) => {
             let env = Environment::new(JVMTIEnvironment::new(jvmti_env), JNIEnvironment::new(jni_env));
-            match env.get_thread_info(&thread) {
+            match current_thread_info(&env, &thread) {
                 Ok(current_thread) => function(current_thread),
                 Err(err) => {
                     match err {
-                        NativeError::NotAvailable => { /* we're in the wrong phase, just ignore this */ },
-                        _ => println!("Couldn't get thread info: {}", translate_error(&err))
+                        NativeError::WrongPhase | NativeError::NotAvailable => { /* we're in the wrong phase or resolving the thread's id, just ignore this */ },
+                        _ => warn!(logger(), "Couldn't get thread info: {}", translate_error(&err))
                     }
                 }
             }
         },
-        None => println!("No dynamic callback method was found for monitor contended entered")
+        None => debug!(logger(), "No dynamic callback method was found for monitor contended entered")
+    }
+}
+
+fn thread_event(env: &Environment, thread: Thread) -> ThreadEvent {
+    ThreadEvent {
+        cpu_time: env.get_current_thread_cpu_time().ok(),
+        thread: thread
     }
 }
 
@@ -364,17 +506,17 @@ unsafe extern "C" fn local_cb_thread_start(jvmti_env: *mut jvmtiEnv, jni_env: *m
     match CALLBACK_TABLE.thread_start {
         Some(function) => {
             let env = Environment::new(JVMTIEnvironment::new(jvmti_env), JNIEnvironment::new(jni_env));
-            match env.get_thread_info(&thread) {
-                Ok(current_thread) => function(current_thread),
+            match current_thread_info(&env, &thread) {
+                Ok(current_thread) => function(thread_event(&env, current_thread)),
                 Err(err) => {
                     match err {
-                        NativeError::NotAvailable => { /* we're in the wrong phase, just ignore this */ },
-                        _ => println!("Couldn't get thread info: {}", translate_error(&err))
+                        NativeError::WrongPhase | NativeError::NotAvailable => { /* we're in the wrong phase or resolving the thread's id, just ignore this */ },
+                        _ => warn!(logger(), "Couldn't get thread info: {}", translate_error(&err))
                     }
                 }
             }
         },
-        None => println!("No dynamic callback method was found for thread start events")
+        None => debug!(logger(), "No dynamic callback method was found for thread start events")
     }
 
 }
@@ -384,17 +526,20 @@ unsafe extern "C" fn local_cb_thread_end(jvmti_env: *mut jvmtiEnv, jni_env: *mut
     match CALLBACK_TABLE.thread_end {
         Some(function) => {
             let env = Environment::new(JVMTIEnvironment::new(jvmti_env), JNIEnvironment::new(jni_env));
-            match env.get_thread_info(&thread) {
-                Ok(current_thread) => function(current_thread),
+            match current_thread_info(&env, &thread) {
+                Ok(current_thread) => {
+                    function(thread_event(&env, current_thread));
+                    forget_current_java_thread_id();
+                },
                 Err(err) => {
                     match err {
-                        NativeError::NotAvailable => { /* wrong phase, just ignore this */ },
-                        _ => println!("Couldn't get thread info: {}", translate_error(&err))
+                        NativeError::WrongPhase | NativeError::NotAvailable => { /* wrong phase or resolving the thread's id, just ignore this */ },
+                        _ => warn!(logger(), "Couldn't get thread info: {}", translate_error(&err))
                     }
                 }
             }
         },
-        None => println!("No dynamic callback method was found for thread end events")
+        None => debug!(logger(), "No dynamic callback method was found for thread end events")
     }
 }
 
@@ -405,7 +550,7 @@ unsafe extern "C" fn local_cb_garbage_collection_start(jvmti_env: *mut jvmtiEnv)
             function();
 
         },
-        None => println!("No dynamic callback method was found for garbage collection start events")
+        None => debug!(logger(), "No dynamic callback method was found for garbage collection start events")
     }
 
 }
@@ -417,7 +562,7 @@ unsafe extern "C" fn local_cb_garbage_collection_finish(jvmti_env: *mut jvmtiEnv
             function();
 
         },
-        None => println!("No dynamic callback method was found for garbage collection finish events")
+        None => debug!(logger(), "No dynamic callback method was found for garbage collection finish events")
     }
 
 }
@@ -444,7 +589,7 @@ unsafe extern "C" fn local_cb_class_file_load_hook(jvmti_env: JVMTIEnvPtr, jni_e
             if let Ok(classfile) = parse_class(&raw_data) {
                 match function(ClassFileLoadEvent { class_name: stringify(name), class: classfile }) {
                     Some(transformed) => {
-                        println!("Transformed class {}", stringify(name));
+                        debug!(logger(), "Transformed class {}", stringify(name));
 
                         match env.allocate(transformed.len()) {
                             Ok(allocation) => {
@@ -453,7 +598,7 @@ unsafe extern "C" fn local_cb_class_file_load_hook(jvmti_env: JVMTIEnvPtr, jni_e
                                 *new_class_data = allocation.ptr;
                             },
                             Err(err) => {
-                                println!("Failed to allocate memory")
+                                error!(logger(), "Failed to allocate memory")
                             }
                         }
                     },
@@ -461,13 +606,13 @@ unsafe extern "C" fn local_cb_class_file_load_hook(jvmti_env: JVMTIEnvPtr, jni_e
                 }
 
             } else {
-                println!("Coult not parse class file");
+                warn!(logger(), "Could not parse class file");
             }
 
 
-            println!("Loading class {} with length {}", stringify(name), class_data_len);
+            trace!(logger(), "Loading class {} with length {}", stringify(name), class_data_len);
         },
-        None => println!("No dynamic callback method was found for class file load events")
+        None => debug!(logger(), "No dynamic callback method was found for class file load events")
     }
 }
 
@@ -531,7 +676,7 @@ unsafe extern "C" fn local_cb_field_access(jvmti_env: *mut jvmtiEnv, jni_env: *m
         Some(function) => {
             function();
         },
-        None => println!("No dynamic callback method was found for field access events")
+        None => debug!(logger(), "No dynamic callback method was found for field access events")
     }
 }
 
@@ -542,7 +687,7 @@ unsafe extern "C" fn local_cb_field_modification(jvmti_env: *mut jvmtiEnv, jni_e
         Some(function) => {
             function();
         },
-        None => println!("No dynamic callback method was found for field modification events")
+        None => debug!(logger(), "No dynamic callback method was found for field modification events")
     }
 }
 
@@ -559,7 +704,10 @@ unsafe extern "C" fn local_cb_native_method_bind(jvmti_env: *mut jvmtiEnv, jni_e
 
 #[allow(unused_variables)]
 unsafe extern "C" fn local_cb_object_free(jvmti_env: *mut jvmtiEnv, tag: jlong) -> () {
-
+    match CALLBACK_TABLE.vm_object_free {
+        Some(function) => function(tag),
+        None => debug!(logger(), "No dynamic callback method was found for object free events")
+    }
 }
 
 #[allow(unused_variables)]
@@ -579,7 +727,7 @@ unsafe extern "C" fn local_cb_vm_death(jvmti_env: *mut jvmtiEnv, jni_env: *mut J
         Some(function) => {
             function();
         },
-        None => println!("No dynamic callback method was found for VM death events")
+        None => debug!(logger(), "No dynamic callback method was found for VM death events")
     }
 }
 
@@ -590,7 +738,7 @@ unsafe extern "C" fn local_cb_vm_init(jvmti_env: *mut jvmtiEnv, jni_env: *mut JN
         Some(function) => {
             function();
         },
-        None => println!("No dynamic callback method was found for VM init events")
+        None => debug!(logger(), "No dynamic callback method was found for VM init events")
     }
 }
 
@@ -600,6 +748,6 @@ unsafe extern "C" fn local_cb_vm_start(jvmti_env: *mut jvmtiEnv, jni_env: *mut J
         Some(function) => {
             function();
         },
-        None => println!("No dynamic callback method was found for VM start events")
+        None => debug!(logger(), "No dynamic callback method was found for VM start events")
     }
 }
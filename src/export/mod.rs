@@ -0,0 +1,281 @@
+//! Periodic export of the agent's aggregates. A background thread takes a snapshot of the method,
+//! garbage collection, thread, monitor and exception statistics at a fixed interval and passes it
+//! to every configured exporter.
+
+pub mod prometheus;
+pub mod rotate;
+pub mod sinks;
+pub mod statsd;
+
+use self::sinks::{CsvExporter, JsonLinesExporter, TableExporter};
+use super::config::ExportConfig;
+use super::context::static_context;
+use super::error::NativeError;
+use super::gc;
+use super::logging::logger;
+use std::collections::BTreeMap;
+use std::io;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+use time::{get_time, precise_time_ns};
+
+/// Name of the agent thread exporting the snapshots
+pub const EXPORTER_THREAD_NAME: &'static str = "jvmti-exporter";
+/// Longest time the exporter thread sleeps before checking whether it's been stopped
+const POLL_INTERVAL_MS: u64 = 100;
+
+lazy_static! {
+    static ref EXPORTER: Mutex<Option<ExportThread>> = Mutex::new(None);
+}
+
+/// A single value of a snapshot, eg. the number of calls of a method
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct Metric {
+    pub name: String,
+    /// Tells apart the values of the same metric, eg. the method the calls are counted for
+    pub labels: BTreeMap<String, String>,
+    pub value: f64
+}
+
+/// Kind of a metric, telling how its values are aggregated
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MetricType {
+    Counter,
+    Gauge,
+    Histogram
+}
+
+impl MetricType {
+
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            MetricType::Counter => "counter",
+            MetricType::Gauge => "gauge",
+            MetricType::Histogram => "histogram"
+        }
+    }
+
+    /// Return the type of a metric: the current values and statistics of a distribution
+    /// are gauges, the rest only ever grow
+    pub fn of(name: &str) -> MetricType {
+        let gauge = name == "threads_live" || ["_max_ns", "_max_time_ns", "_p50_ns", "_p99_ns", "_p99_time_ns"].iter().any(|suffix| name.ends_with(suffix));
+
+        match gauge {
+            true => MetricType::Gauge,
+            false => MetricType::Counter
+        }
+    }
+}
+
+/// The values of all metrics at a point in time, in milliseconds since the Unix epoch
+#[derive(Clone, Debug, PartialEq)]
+pub struct Snapshot {
+    pub timestamp: u64,
+    pub metrics: Vec<Metric>
+}
+
+impl Snapshot {
+
+    pub fn new(timestamp: u64) -> Snapshot {
+        Snapshot { timestamp: timestamp, metrics: vec![] }
+    }
+
+    pub fn add(&mut self, name: &str, labels: &[(&str, &str)], value: f64) {
+        self.metrics.push(Metric {
+            name: name.to_string(),
+            labels: labels.iter().map(|&(key, value)| (key.to_string(), value.to_string())).collect(),
+            value: value
+        });
+    }
+
+    ///
+    /// Take a snapshot of the aggregates of the agent. At most `limit` methods, threads, monitors
+    /// and exception classes are included, the ones with the most calls, CPU time, contentions and
+    /// exceptions respectively.
+    ///
+    pub fn collect(limit: usize) -> Snapshot {
+        let now = get_time();
+        let mut snapshot = Snapshot::new((now.sec as u64) * 1000 + (now.nsec as u64) / 1_000_000);
+
+        for (method, stats) in static_context().top_methods(limit) {
+            let labels = [("method", method.as_str())];
+
+            snapshot.add("method_calls", &labels, stats.count as f64);
+            snapshot.add("method_exceptions", &labels, stats.exceptions as f64);
+            snapshot.add("method_total_time_ns", &labels, stats.total_time as f64);
+            snapshot.add("method_self_time_ns", &labels, stats.self_time as f64);
+            snapshot.add("method_max_time_ns", &labels, stats.max_time as f64);
+            snapshot.add("method_p99_time_ns", &labels, stats.p99() as f64);
+        }
+
+        gc::with_pauses(|pauses| {
+            snapshot.add("gc_pauses", &[], pauses.count() as f64);
+            snapshot.add("gc_pause_time_ns", &[], pauses.total_time() as f64);
+            snapshot.add("gc_pause_p50_ns", &[], pauses.p50() as f64);
+            snapshot.add("gc_pause_p99_ns", &[], pauses.p99() as f64);
+            snapshot.add("gc_pause_max_ns", &[], pauses.max_time() as f64);
+            snapshot.add("gc_pauses_over_threshold", &[], pauses.over_threshold() as f64);
+            snapshot.add("gc_pauses_over_budget", &[], pauses.over_budget() as f64);
+            snapshot.add("gc_sampled_objects_freed", &[], pauses.freed() as f64);
+        });
+
+        let (started, ended) = static_context().thread_churn();
+
+        snapshot.add("threads_live", &[], static_context().live_thread_count() as f64);
+        snapshot.add("threads_started", &[], started as f64);
+        snapshot.add("threads_ended", &[], ended as f64);
+
+        for record in static_context().top_threads(limit) {
+            let java_id = record.java_id.to_string();
+
+            snapshot.add("thread_cpu_time_ns", &[("thread", record.name.as_str()), ("id", java_id.as_str())], record.cpu_time as f64);
+        }
+
+        for (site, stats) in static_context().top_contended_monitors(limit) {
+            let site = site.to_string();
+            let labels = [("site", site.as_str())];
+
+            snapshot.add("monitor_contentions", &labels, stats.count as f64);
+            snapshot.add("monitor_contention_time_ns", &labels, stats.total_time as f64);
+        }
+
+        for (site, stats) in static_context().top_monitor_waits(limit) {
+            let site = site.to_string();
+            let labels = [("site", site.as_str())];
+
+            snapshot.add("monitor_waits", &labels, stats.count as f64);
+            snapshot.add("monitor_wait_time_ns", &labels, stats.total_time as f64);
+        }
+
+        for (class, stats) in static_context().top_exception_types(limit) {
+            let labels = [("class", class.as_str())];
+
+            snapshot.add("exceptions_thrown", &labels, stats.thrown as f64);
+            snapshot.add("exceptions_caught", &labels, stats.caught as f64);
+            snapshot.add("exceptions_uncaught", &labels, stats.uncaught as f64);
+        }
+
+        snapshot
+    }
+}
+
+/// Destination of periodic snapshots
+pub trait Exporter {
+    fn export(&mut self, snapshot: &Snapshot) -> io::Result<()>;
+}
+
+///
+/// Create the exporters of the configured formats, writing to files in the given directory.
+/// Unknown formats are skipped with a warning.
+///
+pub fn exporters(config: &ExportConfig, directory: &str) -> Vec<Box<Exporter + Send>> {
+    config.formats.iter().filter_map(|format| {
+        let exporter: Box<Exporter + Send> = match format.as_str() {
+            "jsonl" => Box::new(JsonLinesExporter::new(directory, config.max_file_size, config.max_files)),
+            "csv" => Box::new(CsvExporter::new(directory, config.max_file_size, config.max_files)),
+            "table" => Box::new(TableExporter::new(directory, config.max_file_size, config.max_files)),
+            _ => {
+                warn!(logger(), "Unknown export format: {}", format);
+                return None;
+            }
+        };
+
+        Some(exporter)
+    }).collect()
+}
+
+///
+/// A background thread passing a snapshot to each of its exporters at a fixed interval and a final
+/// one once it's stopped.
+///
+pub struct ExportThread {
+    running: Arc<AtomicBool>,
+    handle: JoinHandle<()>
+}
+
+impl ExportThread {
+
+    pub fn spawn(name: &str, interval_ms: u64, limit: usize, exporters: Vec<Box<Exporter + Send>>) -> io::Result<ExportThread> {
+        let running = Arc::new(AtomicBool::new(true));
+        let thread_running = running.clone();
+        let handle = thread::Builder::new().name(name.to_string()).spawn(move || run(&thread_running, interval_ms, limit, exporters))?;
+
+        Ok(ExportThread { running: running, handle: handle })
+    }
+
+    /// Stop the thread and wait until it has exported the final snapshot
+    pub fn stop(self) {
+        self.running.store(false, Ordering::SeqCst);
+        let _ = self.handle.join();
+    }
+}
+
+///
+/// Start the exporter thread, which passes a snapshot to each exporter every `interval_ms`
+/// milliseconds and a final one once it's stopped. Returns an error if it's running already.
+///
+pub fn start(config: ExportConfig, exporters: Vec<Box<Exporter + Send>>) -> Result<(), NativeError> {
+    let mut current = match EXPORTER.lock() {
+        Ok(current) => current,
+        Err(_) => return Err(NativeError::UnexpectedInternalError)
+    };
+
+    if current.is_some() {
+        return Err(NativeError::IllegalArgument);
+    }
+
+    match ExportThread::spawn(EXPORTER_THREAD_NAME, config.interval_ms, config.limit, exporters) {
+        Ok(thread) => {
+            *current = Some(thread);
+            Ok(())
+        },
+        Err(_) => Err(NativeError::UnexpectedInternalError)
+    }
+}
+
+/// Stop the exporter thread and wait until it has exported the final snapshot
+pub fn stop() {
+    let thread = match EXPORTER.lock() {
+        Ok(mut current) => current.take(),
+        Err(_) => None
+    };
+
+    if let Some(thread) = thread {
+        thread.stop();
+    }
+}
+
+pub fn is_running() -> bool {
+    match EXPORTER.lock() {
+        Ok(current) => current.is_some(),
+        Err(_) => false
+    }
+}
+
+fn run(running: &AtomicBool, interval_ms: u64, limit: usize, mut exporters: Vec<Box<Exporter + Send>>) {
+    let interval = interval_ms * 1_000_000;
+    let mut last_export = precise_time_ns();
+
+    while running.load(Ordering::SeqCst) {
+        thread::sleep(Duration::from_millis(POLL_INTERVAL_MS.min(interval_ms.max(1))));
+
+        if precise_time_ns().saturating_sub(last_export) >= interval {
+            export(&mut exporters, limit);
+            last_export = precise_time_ns();
+        }
+    }
+
+    export(&mut exporters, limit);
+}
+
+fn export(exporters: &mut Vec<Box<Exporter + Send>>, limit: usize) {
+    let snapshot = Snapshot::collect(limit);
+
+    for exporter in exporters.iter_mut() {
+        if let Err(err) = exporter.export(&snapshot) {
+            warn!(logger(), "Couldn't export metrics: {}", err);
+        }
+    }
+}
@@ -0,0 +1,255 @@
+//! Embedded HTTP server exposing the agent's metrics in the Prometheus text format. The server
+//! runs on an agent thread and answers one request at a time, which is plenty for a few scrapers.
+
+use super::{Metric, MetricType, Snapshot};
+use super::sinks::format_value;
+use super::super::config::PrometheusConfig;
+use super::super::context::static_context;
+use super::super::gc;
+use super::super::logging::logger;
+use super::super::profile::histogram::Histogram;
+use std::collections::BTreeMap;
+use std::io::{self, ErrorKind, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// Name of the agent thread serving the metrics
+pub const PROMETHEUS_THREAD_NAME: &'static str = "jvmti-prometheus";
+/// Content type of the text exposition format
+pub const CONTENT_TYPE: &'static str = "text/plain; version=0.0.4; charset=utf-8";
+/// Prefix of the name of every exposed metric
+pub const PREFIX: &'static str = "jvmti_";
+/// Upper bounds of the buckets of the duration histograms, in nanoseconds
+pub const DURATION_BUCKETS: [u64; 10] = [
+    100_000, 1_000_000, 5_000_000, 10_000_000, 50_000_000, 100_000_000, 500_000_000, 1_000_000_000, 5_000_000_000, 10_000_000_000
+];
+/// Longest time the server thread waits for a connection before checking whether it's been stopped
+const POLL_INTERVAL_MS: u64 = 100;
+/// Time a client has to send its request or read the response
+const CLIENT_TIMEOUT_MS: u64 = 5000;
+/// Longest request head read, longer requests are rejected
+const MAX_REQUEST_SIZE: usize = 8192;
+
+/// Set while the server thread is alive
+static RUNNING: AtomicBool = AtomicBool::new(false);
+
+lazy_static! {
+    static ref HANDLE: Mutex<Option<JoinHandle<()>>> = Mutex::new(None);
+}
+
+/// A distribution of durations, exposed as a histogram with the standard duration buckets
+#[derive(Clone, Debug)]
+pub struct HistogramSample {
+    pub name: String,
+    pub labels: BTreeMap<String, String>,
+    pub histogram: Histogram,
+    /// The sum of the recorded values
+    pub sum: u64
+}
+
+/// Escape a label value: backslashes, double quotes and line feeds are escaped with a backslash
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+fn format_labels(labels: &BTreeMap<String, String>, extra: Option<(&str, &str)>) -> String {
+    let pairs: Vec<String> = labels.iter().map(|(key, value)| (key.as_str(), value.as_str())).chain(extra)
+        .map(|(key, value)| format!("{}=\"{}\"", key, escape(value)))
+        .collect();
+
+    match pairs.is_empty() {
+        true => String::new(),
+        false => format!("{{{}}}", pairs.join(","))
+    }
+}
+
+///
+/// Render a snapshot and a set of histograms in the text exposition format. The samples of a metric
+/// are listed together, after its type, in the order the metric first appears.
+///
+pub fn render(snapshot: &Snapshot, histograms: &[HistogramSample]) -> String {
+    let mut families: Vec<(&str, Vec<&Metric>)> = vec![];
+
+    for metric in snapshot.metrics.iter() {
+        match families.iter().position(|&(name, _)| name == metric.name) {
+            Some(idx) => families[idx].1.push(metric),
+            None => families.push((&metric.name, vec![metric]))
+        }
+    }
+
+    let mut text = String::new();
+
+    for (name, metrics) in families {
+        text.push_str(&format!("# TYPE {}{} {}\n", PREFIX, name, MetricType::of(name).as_str()));
+
+        for metric in metrics {
+            text.push_str(&format!("{}{}{} {}\n", PREFIX, name, format_labels(&metric.labels, None), format_value(metric.value)));
+        }
+    }
+
+    let mut typed: Vec<&str> = vec![];
+
+    for sample in histograms {
+        if !typed.contains(&sample.name.as_str()) {
+            text.push_str(&format!("# TYPE {}{} {}\n", PREFIX, sample.name, MetricType::Histogram.as_str()));
+            typed.push(&sample.name);
+        }
+
+        for bound in DURATION_BUCKETS.iter() {
+            let le = bound.to_string();
+
+            text.push_str(&format!("{}{}_bucket{} {}\n", PREFIX, sample.name, format_labels(&sample.labels, Some(("le", &le))), sample.histogram.count_at_most(*bound)));
+        }
+
+        let labels = format_labels(&sample.labels, None);
+
+        text.push_str(&format!("{}{}_bucket{} {}\n", PREFIX, sample.name, format_labels(&sample.labels, Some(("le", "+Inf"))), sample.histogram.count()));
+        text.push_str(&format!("{}{}_sum{} {}\n", PREFIX, sample.name, labels, sample.sum));
+        text.push_str(&format!("{}{}_count{} {}\n", PREFIX, sample.name, labels, sample.histogram.count()));
+    }
+
+    text
+}
+
+/// Render the current metrics of the agent, limiting the number of labelled series like snapshots do
+pub fn collect(limit: usize) -> String {
+    let mut histograms = vec![];
+
+    if let Some(sample) = gc::with_pauses(|pauses| HistogramSample { name: "gc_pause_duration_ns".to_string(), labels: BTreeMap::new(), histogram: pauses.histogram().clone(), sum: pauses.total_time() }) {
+        histograms.push(sample);
+    }
+
+    for (method, stats) in static_context().top_methods(limit) {
+        let mut labels = BTreeMap::new();
+
+        labels.insert("method".to_string(), method);
+        histograms.push(HistogramSample { name: "method_duration_ns".to_string(), labels: labels, histogram: stats.histogram().clone(), sum: stats.total_time });
+    }
+
+    render(&Snapshot::collect(limit), &histograms)
+}
+
+///
+/// Return the HTTP response to a request, given by its head. The metrics are served on the
+/// configured path to `GET` and `HEAD` requests.
+///
+pub fn response(request: &str, config: &PrometheusConfig) -> String {
+    let mut parts = request.lines().next().unwrap_or("").split_whitespace();
+    let method = parts.next().unwrap_or("");
+    let target = parts.next().unwrap_or("");
+    let path = target.split('?').next().unwrap_or("");
+
+    let (status, headers, body) = match (method, path == config.path) {
+        ("GET", true) | ("HEAD", true) => ("200 OK", format!("Content-Type: {}\r\n", CONTENT_TYPE), collect(config.limit)),
+        ("GET", false) | ("HEAD", false) => ("404 Not Found", "Content-Type: text/plain\r\n".to_string(), "Not found\n".to_string()),
+        _ => ("405 Method Not Allowed", "Allow: GET, HEAD\r\nContent-Type: text/plain\r\n".to_string(), "Method not allowed\n".to_string())
+    };
+
+    format!("HTTP/1.1 {}\r\n{}Content-Length: {}\r\nConnection: close\r\n\r\n{}", status, headers, body.len(), if method == "HEAD" { "" } else { &body })
+}
+
+///
+/// Start serving the metrics on the configured address and port, returning the address listened on.
+/// Returns an error if the server is running already or the port couldn't be bound.
+///
+pub fn start(config: PrometheusConfig) -> io::Result<SocketAddr> {
+    if RUNNING.swap(true, Ordering::SeqCst) {
+        return Err(io::Error::new(ErrorKind::AlreadyExists, "the metrics server is running already"));
+    }
+
+    let listener = match bind(&config) {
+        Ok(listener) => listener,
+        Err(err) => {
+            RUNNING.store(false, Ordering::SeqCst);
+            return Err(err);
+        }
+    };
+
+    let address = listener.local_addr()?;
+
+    match thread::Builder::new().name(PROMETHEUS_THREAD_NAME.to_string()).spawn(move || run(listener, config)) {
+        Ok(handle) => {
+            if let Ok(mut current) = HANDLE.lock() {
+                *current = Some(handle);
+            }
+
+            info!(logger(), "Serving metrics on http://{}", address);
+            Ok(address)
+        },
+        Err(err) => {
+            RUNNING.store(false, Ordering::SeqCst);
+            Err(err)
+        }
+    }
+}
+
+fn bind(config: &PrometheusConfig) -> io::Result<TcpListener> {
+    let listener = TcpListener::bind((config.address.as_str(), config.port.unwrap_or(0)))?;
+
+    // Accepting without blocking lets the server thread notice that it's been stopped
+    listener.set_nonblocking(true)?;
+    Ok(listener)
+}
+
+/// Stop the server and wait until its thread has finished
+pub fn stop() {
+    RUNNING.store(false, Ordering::SeqCst);
+
+    let handle = match HANDLE.lock() {
+        Ok(mut handle) => handle.take(),
+        Err(_) => None
+    };
+
+    if let Some(handle) = handle {
+        let _ = handle.join();
+    }
+}
+
+pub fn is_running() -> bool {
+    RUNNING.load(Ordering::SeqCst)
+}
+
+fn run(listener: TcpListener, config: PrometheusConfig) {
+    while RUNNING.load(Ordering::SeqCst) {
+        match listener.accept() {
+            Ok((stream, peer)) => {
+                if let Err(err) = serve(stream, &config) {
+                    debug!(logger(), "Couldn't serve metrics to {}: {}", peer, err);
+                }
+            },
+            Err(ref err) if err.kind() == ErrorKind::WouldBlock => thread::sleep(Duration::from_millis(POLL_INTERVAL_MS)),
+            Err(err) => {
+                warn!(logger(), "Couldn't accept metrics connection: {}", err);
+                thread::sleep(Duration::from_millis(POLL_INTERVAL_MS));
+            }
+        }
+    }
+}
+
+fn serve(mut stream: TcpStream, config: &PrometheusConfig) -> io::Result<()> {
+    let timeout = Some(Duration::from_millis(CLIENT_TIMEOUT_MS));
+
+    stream.set_nonblocking(false)?;
+    stream.set_read_timeout(timeout)?;
+    stream.set_write_timeout(timeout)?;
+
+    let mut request = vec![];
+    let mut buffer = [0; 1024];
+
+    while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+        match stream.read(&mut buffer)? {
+            0 => break,
+            len => request.extend_from_slice(&buffer[..len])
+        }
+
+        if request.len() > MAX_REQUEST_SIZE {
+            return stream.write_all(b"HTTP/1.1 431 Request Header Fields Too Large\r\nContent-Length: 0\r\nConnection: close\r\n\r\n");
+        }
+    }
+
+    stream.write_all(response(&String::from_utf8_lossy(&request), config).as_bytes())?;
+    stream.flush()
+}
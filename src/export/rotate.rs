@@ -0,0 +1,103 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+///
+/// A file that's rotated once it would grow beyond a maximum size: the current file is renamed to
+/// `<name>.1`, the previous `<name>.1` to `<name>.2` and so on, dropping the oldest one so that at
+/// most `max_files` rotated files are kept. The file and its directory are created lazily, on the
+/// first write.
+///
+pub struct RotatingFile {
+    path: PathBuf,
+    max_size: u64,
+    max_files: usize,
+    file: Option<File>,
+    size: u64
+}
+
+impl RotatingFile {
+
+    pub fn new<P: AsRef<Path>>(path: P, max_size: u64, max_files: usize) -> RotatingFile {
+        RotatingFile { path: path.as_ref().to_path_buf(), max_size: max_size, max_files: max_files, file: None, size: 0 }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    ///
+    /// Append data to the file, rotating it first if the data doesn't fit. The header, if given,
+    /// is written at the start of every new file. A single write is never split across files.
+    ///
+    pub fn write(&mut self, data: &[u8], header: Option<&[u8]>) -> io::Result<()> {
+        if self.file.is_none() {
+            self.open()?;
+        }
+
+        if self.size > 0 && self.size + data.len() as u64 > self.max_size {
+            self.rotate()?;
+        }
+
+        let mut contents = vec![];
+
+        if self.size == 0 {
+            if let Some(header) = header {
+                contents.extend_from_slice(header);
+            }
+        }
+
+        contents.extend_from_slice(data);
+
+        if let Some(ref mut file) = self.file {
+            file.write_all(&contents)?;
+            file.flush()?;
+        }
+
+        self.size += contents.len() as u64;
+        Ok(())
+    }
+
+    fn open(&mut self) -> io::Result<()> {
+        if let Some(dir) = self.path.parent() {
+            if !dir.as_os_str().is_empty() {
+                fs::create_dir_all(dir)?;
+            }
+        }
+
+        let file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+
+        self.size = file.metadata()?.len();
+        self.file = Some(file);
+        Ok(())
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file = None;
+
+        match self.max_files {
+            0 => fs::remove_file(&self.path)?,
+            max_files => {
+                for idx in (1..max_files).rev() {
+                    let from = self.rotated_path(idx);
+
+                    if from.exists() {
+                        fs::rename(from, self.rotated_path(idx + 1))?;
+                    }
+                }
+
+                fs::rename(&self.path, self.rotated_path(1))?;
+            }
+        }
+
+        self.open()
+    }
+
+    /// Return the path of the `idx`th rotated file, 1 being the most recent one
+    pub fn rotated_path(&self, idx: usize) -> PathBuf {
+        let mut name = self.path.file_name().map(|name| name.to_os_string()).unwrap_or_default();
+
+        name.push(format!(".{}", idx));
+        self.path.with_file_name(name)
+    }
+}
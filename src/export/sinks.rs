@@ -0,0 +1,132 @@
+use super::{Exporter, Metric, Snapshot};
+use super::rotate::RotatingFile;
+use serde_json;
+use std::collections::BTreeMap;
+use std::io;
+use std::path::Path;
+
+/// Name of the exported files, without the extension
+pub const FILE_NAME: &'static str = "metrics";
+
+/// Format a value without a fractional part if it's a whole number
+pub fn format_value(value: f64) -> String {
+    match value.fract() == 0.0 && value.abs() < 1e15 {
+        true => format!("{}", value as i64),
+        false => format!("{:.3}", value)
+    }
+}
+
+/// Format labels as `key=value` pairs separated by semicolons
+pub fn format_labels(labels: &BTreeMap<String, String>) -> String {
+    labels.iter().map(|(key, value)| format!("{}={}", key, value)).collect::<Vec<String>>().join(";")
+}
+
+#[derive(Serialize)]
+struct JsonMetric<'a> {
+    timestamp: u64,
+    name: &'a str,
+    labels: &'a BTreeMap<String, String>,
+    value: f64
+}
+
+///
+/// Writes every metric of a snapshot as a JSON object on its own line, eg.
+/// `{"timestamp":1500000000000,"name":"method_calls","labels":{"method":"A.run"},"value":3.0}`
+///
+pub struct JsonLinesExporter {
+    file: RotatingFile
+}
+
+impl JsonLinesExporter {
+
+    pub fn new(directory: &str, max_size: u64, max_files: usize) -> JsonLinesExporter {
+        JsonLinesExporter { file: RotatingFile::new(Path::new(directory).join(format!("{}.jsonl", FILE_NAME)), max_size, max_files) }
+    }
+}
+
+impl Exporter for JsonLinesExporter {
+    fn export(&mut self, snapshot: &Snapshot) -> io::Result<()> {
+        let mut contents = String::new();
+
+        for metric in snapshot.metrics.iter() {
+            let line = serde_json::to_string(&JsonMetric { timestamp: snapshot.timestamp, name: &metric.name, labels: &metric.labels, value: metric.value })?;
+
+            contents.push_str(&line);
+            contents.push('\n');
+        }
+
+        self.file.write(contents.as_bytes(), None)
+    }
+}
+
+/// Quote a CSV field if necessary
+fn csv_field(value: &str) -> String {
+    match value.contains(',') || value.contains('"') || value.contains('\n') {
+        true => format!("\"{}\"", value.replace("\"", "\"\"")),
+        false => value.to_string()
+    }
+}
+
+/// Writes every metric of a snapshot as a CSV row. Each file starts with a header row.
+pub struct CsvExporter {
+    file: RotatingFile
+}
+
+impl CsvExporter {
+
+    pub const HEADER: &'static str = "timestamp,name,labels,value\n";
+
+    pub fn new(directory: &str, max_size: u64, max_files: usize) -> CsvExporter {
+        CsvExporter { file: RotatingFile::new(Path::new(directory).join(format!("{}.csv", FILE_NAME)), max_size, max_files) }
+    }
+}
+
+impl Exporter for CsvExporter {
+    fn export(&mut self, snapshot: &Snapshot) -> io::Result<()> {
+        let rows: String = snapshot.metrics.iter().map(|metric| {
+            format!("{},{},{},{}\n", snapshot.timestamp, csv_field(&metric.name), csv_field(&format_labels(&metric.labels)), format_value(metric.value))
+        }).collect();
+
+        self.file.write(rows.as_bytes(), Some(CsvExporter::HEADER.as_bytes()))
+    }
+}
+
+/// Writes each snapshot as a table with aligned columns, meant to be read by humans
+pub struct TableExporter {
+    file: RotatingFile
+}
+
+impl TableExporter {
+
+    pub fn new(directory: &str, max_size: u64, max_files: usize) -> TableExporter {
+        TableExporter { file: RotatingFile::new(Path::new(directory).join(format!("{}.txt", FILE_NAME)), max_size, max_files) }
+    }
+
+    /// Render a snapshot as a list of lines, starting with its timestamp and the column headers
+    pub fn render_lines(snapshot: &Snapshot) -> Vec<String> {
+        let rows: Vec<(&Metric, String, String)> = snapshot.metrics.iter().map(|metric| (metric, format_labels(&metric.labels), format_value(metric.value))).collect();
+        let name_width = rows.iter().map(|row| row.0.name.len()).chain(Some("METRIC".len())).max().unwrap_or(0);
+        let labels_width = rows.iter().map(|row| row.1.len()).chain(Some("LABELS".len())).max().unwrap_or(0);
+        let value_width = rows.iter().map(|row| row.2.len()).chain(Some("VALUE".len())).max().unwrap_or(0);
+
+        let mut lines = vec![
+            format!("Snapshot at {}", snapshot.timestamp),
+            format!("{:<nw$}  {:<lw$}  {:>vw$}", "METRIC", "LABELS", "VALUE", nw = name_width, lw = labels_width, vw = value_width)
+        ];
+
+        for (metric, labels, value) in rows {
+            lines.push(format!("{:<nw$}  {:<lw$}  {:>vw$}", metric.name, labels, value, nw = name_width, lw = labels_width, vw = value_width));
+        }
+
+        lines
+    }
+}
+
+impl Exporter for TableExporter {
+    fn export(&mut self, snapshot: &Snapshot) -> io::Result<()> {
+        let mut contents = TableExporter::render_lines(snapshot).join("\n");
+
+        contents.push_str("\n\n");
+        self.file.write(contents.as_bytes(), None)
+    }
+}
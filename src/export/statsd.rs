@@ -0,0 +1,200 @@
+//! Pushes the agent's metrics to a StatsD or DogStatsD server over UDP. Sends never block and
+//! metrics that can't be sent, eg. because the server isn't running, are dropped.
+
+use super::{ExportThread, Exporter, MetricType, Snapshot};
+use super::sinks::format_value;
+use super::super::config::StatsdConfig;
+use super::super::logging::logger;
+use std::collections::HashMap;
+use std::io::{self, ErrorKind};
+use std::net::{SocketAddr, ToSocketAddrs, UdpSocket};
+use std::sync::Mutex;
+
+/// Name of the agent thread sending the metrics
+pub const STATSD_THREAD_NAME: &'static str = "jvmti-statsd";
+
+lazy_static! {
+    static ref EMITTER: Mutex<Option<ExportThread>> = Mutex::new(None);
+}
+
+/// Replace the characters that aren't allowed in a metric name
+fn sanitize_name(name: &str) -> String {
+    name.chars().map(|c| if c.is_alphanumeric() || c == '_' || c == '-' || c == '.' { c } else { '_' }).collect()
+}
+
+/// Replace the characters that separate the fields and tags of a DogStatsD line
+fn sanitize_tag(tag: &str) -> String {
+    tag.chars().map(|c| if c == '|' || c == ',' || c == '#' || c.is_whitespace() { '_' } else { c }).collect()
+}
+
+///
+/// Exporter sending the metrics of each snapshot to a StatsD server. Counters are sent as the
+/// increase since the previous snapshot and left out if they haven't changed.
+///
+pub struct StatsdExporter {
+    config: StatsdConfig,
+    /// Tags sent with every metric
+    tags: Vec<String>,
+    socket: Option<(UdpSocket, SocketAddr)>,
+    /// The last value of each counter, by its name and labels
+    counters: HashMap<String, f64>
+}
+
+impl StatsdExporter {
+
+    pub fn new(config: StatsdConfig, agent_id: &str) -> StatsdExporter {
+        let tags = Some(format!("agent_id:{}", agent_id)).into_iter().chain(config.tags.iter().cloned()).map(|tag| sanitize_tag(&tag)).collect();
+
+        StatsdExporter { config: config, tags: tags, socket: None, counters: HashMap::new() }
+    }
+
+    /// Return the lines sent for a snapshot, eg. `jvmti.method_calls:3|c|#method:Hello.main`
+    pub fn lines(&mut self, snapshot: &Snapshot) -> Vec<String> {
+        let mut lines = vec![];
+
+        for metric in snapshot.metrics.iter() {
+            let (value, kind) = match MetricType::of(&metric.name) {
+                MetricType::Counter => {
+                    let key = format!("{}{:?}", metric.name, metric.labels);
+                    let previous = self.counters.insert(key, metric.value).unwrap_or(0.0);
+
+                    match metric.value - previous {
+                        delta if delta == 0.0 => continue,
+                        // The aggregates have been reset since the previous snapshot
+                        delta if delta < 0.0 => (metric.value, "c"),
+                        delta => (delta, "c")
+                    }
+                },
+                _ => (metric.value, "g")
+            };
+
+            let mut name = match self.config.prefix.is_empty() {
+                true => metric.name.clone(),
+                false => format!("{}.{}", self.config.prefix, metric.name)
+            };
+
+            if self.config.dogstatsd {
+                let tags: Vec<String> = metric.labels.iter().map(|(key, value)| sanitize_tag(&format!("{}:{}", key, value))).chain(self.tags.iter().cloned()).collect();
+
+                lines.push(format!("{}:{}|{}|#{}", sanitize_name(&name), format_value(value), kind, tags.join(",")));
+            } else {
+                for value in metric.labels.values() {
+                    name.push('.');
+                    name.push_str(&value.replace('.', "_"));
+                }
+
+                lines.push(format!("{}:{}|{}", sanitize_name(&name), format_value(value), kind));
+            }
+        }
+
+        lines
+    }
+
+    /// Return the socket and the address of the server, resolving it if it hasn't been yet
+    fn socket(&mut self) -> io::Result<&(UdpSocket, SocketAddr)> {
+        if self.socket.is_none() {
+            let address = match (self.config.host.as_str(), self.config.port).to_socket_addrs()?.next() {
+                Some(address) => address,
+                None => return Err(io::Error::new(ErrorKind::NotFound, format!("no address found for {}", self.config.host)))
+            };
+
+            let socket = UdpSocket::bind(match address {
+                SocketAddr::V4(_) => "0.0.0.0:0",
+                SocketAddr::V6(_) => "[::]:0"
+            })?;
+
+            socket.set_nonblocking(true)?;
+            self.socket = Some((socket, address));
+        }
+
+        match self.socket {
+            Some(ref socket) => Ok(socket),
+            None => Err(io::Error::new(ErrorKind::NotConnected, "no socket"))
+        }
+    }
+}
+
+///
+/// Join lines into newline separated packets of at most `max_size` bytes. Lines longer than that
+/// are sent in a packet of their own.
+///
+pub fn packets(lines: &[String], max_size: usize) -> Vec<String> {
+    let mut packets: Vec<String> = vec![];
+    let mut current = String::new();
+
+    for line in lines {
+        if !current.is_empty() && current.len() + 1 + line.len() > max_size {
+            packets.push(current);
+            current = String::new();
+        }
+
+        if !current.is_empty() {
+            current.push('\n');
+        }
+
+        current.push_str(line);
+    }
+
+    if !current.is_empty() {
+        packets.push(current);
+    }
+
+    packets
+}
+
+impl Exporter for StatsdExporter {
+    fn export(&mut self, snapshot: &Snapshot) -> io::Result<()> {
+        let lines = self.lines(snapshot);
+        let max_packet_size = self.config.max_packet_size;
+
+        match self.socket() {
+            Ok(&(ref socket, address)) => {
+                for packet in packets(&lines, max_packet_size) {
+                    if let Err(err) = socket.send_to(packet.as_bytes(), address) {
+                        debug!(logger(), "Dropped StatsD packet: {}", err);
+                    }
+                }
+            },
+            Err(err) => debug!(logger(), "Dropped StatsD metrics: {}", err)
+        }
+
+        Ok(())
+    }
+}
+
+/// Start sending the metrics of the agent with the given identifier. Returns an error if the emitter
+/// is running already.
+pub fn start(config: StatsdConfig, agent_id: &str) -> io::Result<()> {
+    let mut current = match EMITTER.lock() {
+        Ok(current) => current,
+        Err(_) => return Err(io::Error::new(ErrorKind::Other, "the StatsD emitter is unavailable"))
+    };
+
+    if current.is_some() {
+        return Err(io::Error::new(ErrorKind::AlreadyExists, "the StatsD emitter is running already"));
+    }
+
+    let (interval_ms, limit) = (config.interval_ms, config.limit);
+
+    *current = Some(ExportThread::spawn(STATSD_THREAD_NAME, interval_ms, limit, vec![Box::new(StatsdExporter::new(config, agent_id))])?);
+    Ok(())
+}
+
+/// Stop the emitter after sending the final metrics
+pub fn stop() {
+    let thread = match EMITTER.lock() {
+        Ok(mut current) => current.take(),
+        Err(_) => None
+    };
+
+    if let Some(thread) = thread {
+        thread.stop();
+    }
+}
+
+pub fn is_running() -> bool {
+    match EMITTER.lock() {
+        Ok(current) => current.is_some(),
+        Err(_) => false
+    }
+}
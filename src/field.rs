@@ -0,0 +1,5 @@
+use super::native::JavaField;
+
+pub struct FieldId {
+    pub native_id: JavaField
+}
@@ -0,0 +1,157 @@
+use super::config::GcConfig;
+use super::environment::jvmti::JVMTI;
+use super::error::NativeError;
+use super::monitor::RawMonitor;
+use super::native::TagId;
+use super::profile::gc::{GcPause, GcPauses};
+use std::io;
+use std::ptr;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicPtr, Ordering};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+use time::precise_time_ns;
+
+/// Tag given to sampled allocations so that the VM reports them when they're freed
+pub const FREED_OBJECT_TAG: TagId = 0x6763;
+
+/// Name of the raw monitor guarding the pause statistics
+const MONITOR_NAME: &'static str = "jvmti-gc-pauses";
+
+///
+/// The pause statistics. These are updated from garbage collection callbacks, which may only use
+/// raw monitors, so they're kept apart from the agent context and its read-write lock.
+///
+static PAUSES: AtomicPtr<RawMonitor<GcPauses>> = AtomicPtr::new(ptr::null_mut());
+/// Set if sampled allocations should be tagged
+static TAG_OBJECTS: AtomicBool = AtomicBool::new(false);
+
+/// Name of the thread reporting finished pauses
+const REPORTER_THREAD_NAME: &'static str = "jvmti-gc";
+/// Interval at which finished pauses are reported, in milliseconds
+const REPORT_INTERVAL_MS: u64 = 100;
+
+static REPORTING: AtomicBool = AtomicBool::new(false);
+
+lazy_static! {
+    static ref HANDLE: Mutex<Option<JoinHandle<()>>> = Mutex::new(None);
+}
+
+///
+/// Set up the pause tracking using the given JVMTI environment. This has to be called before the
+/// garbage collection events are enabled, as the callbacks can't create the statistics themselves.
+/// Returns an error if the tracking has been set up already.
+///
+pub fn init(env: Box<JVMTI>, config: &GcConfig) -> Result<(), NativeError> {
+    let pauses = GcPauses::new(config.pause_threshold_ms * 1_000_000, config.budget_ms * 1_000_000, config.budget_window_ms * 1_000_000, config.timeline_size);
+    let monitor = Box::into_raw(Box::new(RawMonitor::new(env, MONITOR_NAME, pauses)?));
+
+    if PAUSES.compare_exchange(ptr::null_mut(), monitor, Ordering::SeqCst, Ordering::SeqCst).is_err() {
+        unsafe { drop(Box::from_raw(monitor)); }
+        return Err(NativeError::IllegalArgument);
+    }
+
+    TAG_OBJECTS.store(config.count_freed_sampled, Ordering::SeqCst);
+    Ok(())
+}
+
+/// Return true if sampled allocations should be tagged with `FREED_OBJECT_TAG`
+pub fn is_tagging_objects() -> bool {
+    TAG_OBJECTS.load(Ordering::SeqCst)
+}
+
+/// Record the start of a garbage collection
+pub fn pause_start() {
+    with_pauses(|pauses| pauses.start(precise_time_ns()));
+}
+
+/// Record the end of the garbage collection in progress. The pause is passed on by the reporting
+/// thread later.
+pub fn pause_finish() {
+    with_pauses(|pauses| pauses.finish(precise_time_ns()));
+}
+
+/// Record that an object with the given tag has been freed. Objects tagged by others are ignored.
+pub fn object_freed(tag: TagId) {
+    if tag == FREED_OBJECT_TAG {
+        with_pauses(|pauses| pauses.object_freed());
+    }
+}
+
+///
+/// Run the given function with exclusive access to the pause statistics. Returns `None` if the
+/// tracking hasn't been set up or the monitor couldn't be entered.
+///
+pub fn with_pauses<R, F: FnOnce(&mut GcPauses) -> R>(f: F) -> Option<R> {
+    let monitor = PAUSES.load(Ordering::SeqCst);
+
+    if monitor.is_null() {
+        return None;
+    }
+
+    // The monitor is never released once it has been published
+    match unsafe { (*monitor).lock() } {
+        Ok(mut pauses) => Some(f(&mut *pauses)),
+        Err(_) => None
+    }
+}
+
+/// Return true if pause tracking has been set up
+pub fn is_tracking() -> bool {
+    !PAUSES.load(Ordering::SeqCst).is_null()
+}
+
+///
+/// Start passing finished pauses to the given function on a thread of its own. The garbage
+/// collection callbacks may not take locks, do I/O or log, so they only queue the pauses. Returns
+/// an error if the reporting thread is running already.
+///
+pub fn start_reporting(report: fn(GcPause)) -> io::Result<()> {
+    if REPORTING.swap(true, Ordering::SeqCst) {
+        return Err(io::Error::new(io::ErrorKind::AlreadyExists, "GC pauses are being reported already"));
+    }
+
+    match thread::Builder::new().name(REPORTER_THREAD_NAME.to_string()).spawn(move || run_reporting(report)) {
+        Ok(handle) => {
+            if let Ok(mut current) = HANDLE.lock() {
+                *current = Some(handle);
+            }
+
+            Ok(())
+        },
+        Err(err) => {
+            REPORTING.store(false, Ordering::SeqCst);
+            Err(err)
+        }
+    }
+}
+
+/// Stop the reporting thread and wait until it has reported the pauses still queued
+pub fn stop_reporting() {
+    REPORTING.store(false, Ordering::SeqCst);
+
+    let handle = match HANDLE.lock() {
+        Ok(mut handle) => handle.take(),
+        Err(_) => None
+    };
+
+    if let Some(handle) = handle {
+        let _ = handle.join();
+    }
+}
+
+/// Pass the queued pauses to the given function, outside of the monitor guarding them
+pub fn report_pauses(report: fn(GcPause)) {
+    for pause in with_pauses(|pauses| pauses.take_unreported()).unwrap_or_default() {
+        report(pause);
+    }
+}
+
+fn run_reporting(report: fn(GcPause)) {
+    while REPORTING.load(Ordering::SeqCst) {
+        thread::sleep(Duration::from_millis(REPORT_INTERVAL_MS));
+        report_pauses(report);
+    }
+
+    report_pauses(report);
+}
@@ -0,0 +1,199 @@
+use super::class::JavaType;
+use super::config::SlowInvocationsConfig;
+use super::environment::jni::{JNI, JNIEnvironment, JNIError, JObject};
+use super::environment::jvmti::JVMTI;
+use super::error::NativeError;
+use super::event::VMEvent;
+use super::logging::logger;
+use super::native::JavaObject;
+use super::runtime::MethodInvocationEvent;
+use super::stack::resolve_stack_trace;
+use super::thread::ThreadId;
+use std::cell::Cell;
+use std::ptr;
+use std::sync::atomic::{AtomicPtr, Ordering};
+
+/// Access flag of static methods
+const ACC_STATIC: u32 = 0x0008;
+
+/// The environment the event callbacks have been registered with, along with the capture settings
+struct Watch {
+    env: Box<JVMTI>,
+    max_argument_length: usize,
+    stack_depth: usize
+}
+
+/// JVMTI environments aren't bound to the thread they've been acquired on, unlike JNI ones, and
+/// the watch is never modified once it's been published.
+unsafe impl Send for Watch { }
+
+unsafe impl Sync for Watch { }
+
+///
+/// Method exit events have to be enabled for a thread through the same environment the callbacks
+/// have been registered with, which the callbacks themselves aren't given.
+///
+static WATCH: AtomicPtr<Watch> = AtomicPtr::new(ptr::null_mut());
+
+thread_local! {
+    /// Set while the current thread calls into Java to capture a value
+    static CAPTURING: Cell<bool> = Cell::new(false);
+}
+
+///
+/// Set up the slow invocation detector using the environment of the agent. Method exit events are
+/// disabled globally unless `keep_exit_events` is set, they're enabled for a thread while it's
+/// inside a watched method. Returns an error if the detector has been set up already.
+///
+pub fn init(env: Box<JVMTI>, config: &SlowInvocationsConfig, keep_exit_events: bool) -> Result<(), NativeError> {
+    if !keep_exit_events {
+        if let Some(err) = env.set_event_notification_mode(VMEvent::MethodExit, false) {
+            return Err(err);
+        }
+    }
+
+    let watch = Box::into_raw(Box::new(Watch { env: env, max_argument_length: config.max_argument_length, stack_depth: config.stack_depth }));
+
+    if WATCH.compare_exchange(ptr::null_mut(), watch, Ordering::SeqCst, Ordering::SeqCst).is_err() {
+        unsafe { drop(Box::from_raw(watch)); }
+        return Err(NativeError::IllegalArgument);
+    }
+
+    Ok(())
+}
+
+fn with_watch<R, F: FnOnce(&Watch) -> R>(f: F) -> Option<R> {
+    let watch = WATCH.load(Ordering::SeqCst);
+
+    // The watch is never released once it has been published
+    match watch.is_null() {
+        true => None,
+        false => Some(f(unsafe { &*watch }))
+    }
+}
+
+/// Return true if the detector has been set up, which watching methods at runtime depends on
+pub fn is_initialised() -> bool {
+    !WATCH.load(Ordering::SeqCst).is_null()
+}
+
+/// Call `f` with the environment the event callbacks have been registered with, if the detector
+/// has been set up. Events can only be enabled or disabled through that environment.
+pub fn with_environment<R, F: FnOnce(&JVMTI) -> R>(f: F) -> Option<R> {
+    with_watch(|w| f(&*w.env))
+}
+
+///
+/// Enable or disable exception and exception catch events, which are registered but switched off
+/// when exception profiling can be turned on at runtime. Returns `NotAvailable` if the environment
+/// of the agent hasn't been published.
+///
+pub fn set_exception_events(enabled: bool) -> Result<(), NativeError> {
+    let result = with_environment(|env| {
+        match env.set_event_notification_mode(VMEvent::Exception, enabled) {
+            Some(err) => Err(err),
+            None => match env.set_event_notification_mode(VMEvent::ExceptionCatch, enabled) {
+                Some(err) => Err(err),
+                None => Ok(())
+            }
+        }
+    });
+
+    result.unwrap_or(Err(NativeError::NotAvailable))
+}
+
+/// Enable or disable method exit events for the given thread
+pub fn watch_thread(thread_id: &ThreadId, watch: bool) {
+    with_watch(|w| {
+        if let Some(err) = w.env.set_thread_event_notification_mode(VMEvent::MethodExit, watch, &thread_id.native_id) {
+            warn!(logger(), "Couldn't set method exit events of thread: {}", err);
+        }
+    });
+}
+
+///
+/// Return the values of the arguments of the method that has just been entered. Primitives are
+/// formatted as they are, objects by calling their `toString()` method. Values that can't be
+/// read are shown as `?`.
+///
+pub fn capture_arguments(event: &MethodInvocationEvent) -> Vec<String> {
+    with_watch(|w| {
+        let descriptor = match w.env.get_method_descriptor(&event.method_id) {
+            Ok(descriptor) => descriptor,
+            Err(_) => return vec![]
+        };
+
+        let is_static = w.env.get_method_modifiers(&event.method_id).map(|modifiers| modifiers & ACC_STATIC != 0).unwrap_or(false);
+        let thread = &event.thread.id.native_id;
+        let mut slot = if is_static { 0 } else { 1 };
+
+        JavaType::parse_arguments(&descriptor).unwrap_or_default().iter().map(|arg_type| {
+            let value = match *arg_type {
+                JavaType::Boolean => w.env.get_local_int(thread, 0, slot).map(|value| (value != 0).to_string()),
+                JavaType::Char => w.env.get_local_int(thread, 0, slot).map(|value| ::std::char::from_u32(value as u32).unwrap_or('?').to_string()),
+                JavaType::Byte | JavaType::Short | JavaType::Int => w.env.get_local_int(thread, 0, slot).map(|value| value.to_string()),
+                JavaType::Long => w.env.get_local_long(thread, 0, slot).map(|value| value.to_string()),
+                JavaType::Float => w.env.get_local_float(thread, 0, slot).map(|value| value.to_string()),
+                JavaType::Double => w.env.get_local_double(thread, 0, slot).map(|value| value.to_string()),
+                _ => w.env.get_local_object(thread, 0, slot).map(object_to_string)
+            };
+
+            slot += arg_type.slot_size();
+            truncate(value.unwrap_or("?".to_string()), w.max_argument_length)
+        }).collect()
+    }).unwrap_or_default()
+}
+
+///
+/// Return true if the current thread is calling into Java to capture a value. The methods called
+/// raise method events themselves, which have to be ignored so they aren't reported as invocations
+/// and don't capture values recursively.
+///
+pub fn is_capturing() -> bool {
+    CAPTURING.with(|capturing| capturing.get())
+}
+
+/// Call `f` with the current thread marked as capturing, restoring the previous state afterwards
+pub fn capturing<R, F: FnOnce() -> R>(f: F) -> R {
+    let previous = CAPTURING.with(|capturing| capturing.replace(true));
+    let result = f();
+
+    CAPTURING.with(|capturing| capturing.set(previous));
+    result
+}
+
+/// Return the stack of the given thread, starting with the current frame
+pub fn stack_trace(thread_id: &ThreadId) -> Vec<String> {
+    with_watch(|w| match w.env.get_stack_trace(&thread_id.native_id, w.stack_depth) {
+        Ok(frames) => resolve_stack_trace(&*w.env, &frames).iter().map(|element| element.to_string()).collect(),
+        Err(_) => vec![]
+    }).unwrap_or_default()
+}
+
+/// Call `toString()` on a local reference, releasing the reference afterwards. Values that can't
+/// be converted, eg. because `toString()` threw an exception, are shown as "?".
+fn object_to_string(object: JavaObject) -> String {
+    let jni = match JNIEnvironment::current() {
+        Ok(jni) => jni,
+        Err(_) => return "?".to_string()
+    };
+    let object = unsafe { JObject::from_raw(object) };
+    let result = capturing(|| jni.to_string(&object));
+
+    if result == Err(JNIError::JavaException) {
+        jni.exception_clear();
+    }
+
+    if !object.is_null() {
+        jni.delete_local_ref(object);
+    }
+
+    result.unwrap_or("?".to_string())
+}
+
+fn truncate(value: String, max_length: usize) -> String {
+    match value.char_indices().nth(max_length) {
+        Some((idx, _)) => format!("{}...", &value[..idx]),
+        None => value
+    }
+}
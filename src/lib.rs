@@ -5,19 +5,32 @@ extern crate time;
 extern crate toml;
 #[macro_use]
 extern crate serde_derive;
+extern crate serde_json;
+#[macro_use]
+extern crate slog;
+extern crate slog_async;
+extern crate slog_term;
 
 use agent::Agent;
 use bytecode::printer::ClassfilePrinter;
 use bytecode::classfile::Constant;
 use bytecode::io::ClassWriter;
-use config::Config;
+use config::{AllocationsConfig, Config, ExceptionsConfig, GcConfig, SlowInvocationsConfig, DEFAULT_CONFIG_FILE};
 use context::static_context;
 use instrumentation::asm::transformer::Transformer;
-use native::{JavaVMPtr, MutString, VoidPtr, ReturnValue};
+use logging::{logger, LogConfig};
+use environment::jvm::{JVMF, JVMAgent};
+use native::{JavaVMPtr, MutString, VoidPtr, ReturnValue, TagId};
 use options::Options;
+use profile::flamegraph::{write_flame_graph, FoldedStacks, ToFolded};
+use profile::gc::GcPause;
+use profile::trace::FileTraceSink;
+use recording::Event;
 use runtime::*;
 use std::io::Cursor;
+use std::path::Path;
 use thread::Thread;
+use time::precise_time_ns;
 use util::stringify;
 
 pub mod agent;
@@ -26,17 +39,30 @@ pub mod capabilities;
 pub mod class;
 pub mod config;
 pub mod context;
+pub mod control;
 pub mod emulator;
 pub mod environment;
 pub mod error;
 pub mod event;
 pub mod event_handler;
+pub mod export;
+pub mod field;
+pub mod gc;
 pub mod instrumentation;
+pub mod invocations;
+pub mod logging;
 pub mod mem;
 pub mod method;
+pub mod monitor;
+pub mod mutf8;
 pub mod native;
 pub mod options;
+pub mod profile;
+pub mod recording;
+pub mod reload;
 pub mod runtime;
+pub mod sampler;
+pub mod stack;
 pub mod thread;
 pub mod util;
 pub mod version;
@@ -48,78 +74,166 @@ pub mod version;
  */
 
 fn on_method_entry(event: MethodInvocationEvent) {
-    let shall_record = match static_context().config.read() {
-        Ok(cfg) => (*cfg).entry_points.iter().any(|item| *item == format!("{}.{}.{}", event.class_sig.package, event.class_sig.name, event.method_sig.name) ), //event.class_name.as_str() == item),
-        _ => false
-    };
+    // Methods called while capturing arguments aren't part of the application
+    if invocations::is_capturing() {
+        return;
+    }
+
+    let (tracing, shall_record, watched, timing) = method_filters(&event);
 
-    if !shall_record {
-        println!("[M-{}.{}{}]", event.class_sig.package, event.class_sig.name, event.method_sig.name);
+    if watched && static_context().invocation_enter(&event, invocations::capture_arguments(&event)) {
+        invocations::watch_thread(&event.thread.id, true);
     }
 
-    static_context().method_enter(&event.thread.id);
+    if timing {
+        static_context().method_enter(&event);
+    }
+
+    if tracing {
+        recording::record(|| Event::MethodEntry { time: precise_time_ns(), thread: event.thread.name.clone(), class: event.class_sig.to_string(), method: event.method_sig.name.clone() });
+        static_context().trace_enter(&event, shall_record);
+    }
 }
 
 fn on_method_exit(event: MethodInvocationEvent) {
-    match static_context().method_exit(&event.thread.id) {
-        //Some(_) => (),
-        Some(duration) => println!("Method {} exited after {}", event.method_sig.name, duration),
-        None => println!("Method has no start: {}", event.method_sig.name)
+    if invocations::is_capturing() {
+        return;
+    }
+
+    let (tracing, _, watched, timing) = method_filters(&event);
+
+    if watched {
+        if let Some(mut invocation) = static_context().invocation_exit(&event) {
+            invocation.stack_trace = invocations::stack_trace(&event.thread.id);
+
+            warn!(logger(), "{}", invocation.render_lines().join("\n"));
+            static_context().record_slow_invocation(invocation);
+        }
+
+        if !static_context().is_watching_invocations(event.thread.java_id) {
+            invocations::watch_thread(&event.thread.id, false);
+        }
+    }
+
+    if timing {
+        static_context().method_exit(&event);
+    }
+
+    if tracing {
+        recording::record(|| Event::MethodExit { time: precise_time_ns(), thread: event.thread.name.clone(), class: event.class_sig.to_string(), method: event.method_sig.name.clone(), exception: event.popped_by_exception });
+
+        if let Some(trace) = static_context().trace_exit(&event) {
+            static_context().write_trace(&trace);
+        }
     }
 }
 
-fn on_thread_start(thread: Thread) {
-    println!("[TS-{}]", thread.name);
+/// Return whether trace mode is enabled, whether the method is an entry point, whether it's
+/// watched for slow invocations and whether method timing is enabled
+fn method_filters(event: &MethodInvocationEvent) -> (bool, bool, bool, bool) {
+    match static_context().config.read() {
+        Ok(cfg) => (
+            !(*cfg).instrumentation.entry_points.is_empty(),
+            (*cfg).is_entry_point(&event.class_sig.package, &event.class_sig.name, &event.method_sig.name),
+            (*cfg).slow_invocations.is_watched(&event.class_sig.package, &event.class_sig.name, &event.method_sig.name),
+            (*cfg).timing.enabled
+        ),
+        _ => (false, false, false, false)
+    }
+}
+
+fn on_thread_start(event: ThreadEvent) {
+    trace!(logger(), "[TS-{}]", event.thread.name);
 
-    static_context().thread_start(&thread.id);
+    recording::record(|| Event::ThreadStart { time: precise_time_ns(), thread: event.thread.name.clone() });
+    static_context().thread_start(&event);
 }
 
-fn on_thread_end(thread: Thread) {
-    println!("[TE-{}]", thread.name);
+fn on_thread_end(event: ThreadEvent) {
+    trace!(logger(), "[TE-{}]", event.thread.name);
 
-    match static_context().thread_end(&thread.id) {
-        Some(duration) => println!("Thread {} lived {}", thread.name, duration),
-        None => println!("Thread {} has no start", thread.name)
+    recording::record(|| Event::ThreadEnd { time: precise_time_ns(), thread: event.thread.name.clone(), cpu_time: event.cpu_time.unwrap_or(0) });
+    match static_context().thread_end(&event) {
+        Some(duration) => debug!(logger(), "Thread {} lived {}", event.thread.name, duration),
+        None => debug!(logger(), "Thread {} has no start", event.thread.name)
     }
 }
 
-fn on_monitor_wait(thread: Thread) {
-    println!("[W1-{}]", thread.name);
+fn on_monitor_wait(event: MonitorWaitEvent) {
+    trace!(logger(), "[W1-{}]", event.thread.name);
+
+    recording::record(|| Event::MonitorWait { time: precise_time_ns(), thread: event.thread.name.clone(), class: event.class_sig.to_string(), timeout: event.timeout.max(0) as u64 });
+    static_context().wait_start(&event);
 }
 
-fn on_monitor_waited(thread: Thread) {
-    println!("[W2-{}]", thread.name);
+fn on_monitor_waited(event: MonitorWaitedEvent) {
+    trace!(logger(), "[W2-{}]", event.thread.name);
+
+    recording::record(|| Event::MonitorWaited { time: precise_time_ns(), thread: event.thread.name.clone(), timed_out: event.timed_out });
+    match static_context().wait_end(&event) {
+        Some(duration) => debug!(logger(), "Thread {} waited on {} for {}{}", event.thread.name, event.class_sig.to_string(), duration, if event.timed_out { " (timed out)" } else { "" }),
+        None => debug!(logger(), "Thread {} has never started waiting", event.thread.name)
+    }
 }
 
-fn on_monitor_contended_enter(thread: Thread) {
-    println!("[C1-{}]", thread.name);
+fn on_monitor_contended_enter(event: MonitorEvent) {
+    trace!(logger(), "[C1-{}]", event.thread.name);
 
-    static_context().monitor_enter(&thread.id);
+    recording::record(|| Event::MonitorContended { time: precise_time_ns(), thread: event.thread.name.clone(), class: event.class_sig.to_string() });
+    static_context().monitor_enter(&event);
 }
 
 fn on_monitor_contended_entered(thread: Thread) {
-    println!("[C2-{}]", thread.name);
+    trace!(logger(), "[C2-{}]", thread.name);
+
+    recording::record(|| Event::MonitorEntered { time: precise_time_ns(), thread: thread.name.clone() });
+    match static_context().monitor_entered(thread.java_id) {
+        Some(duration) => debug!(logger(), "Thread {} waited {}", thread.name, duration),
+        None => debug!(logger(), "Thread {} has never waited", thread.name)
+    }
+}
 
-    match static_context().monitor_entered(&thread.id) {
-        Some(duration) => println!("Thread {} waited {}", thread.name, duration),
-        None => println!("Thread {} has never waited", thread.name)
+/// Return whether exception profiling is enabled. Exception events are switched off while it's not,
+/// this covers the ones delivered while they're being switched off.
+fn exceptions_enabled() -> bool {
+    match static_context().config.read() {
+        Ok(cfg) => (*cfg).exceptions.enabled,
+        Err(_) => false
     }
 }
 
+fn on_exception(event: ExceptionEvent) {
+    if !exceptions_enabled() {
+        return;
+    }
+
+    recording::record(|| Event::ExceptionThrown { time: precise_time_ns(), thread: event.thread.name.clone(), class: event.exception_class.to_string(), caught: event.catch_site.is_some() });
+    static_context().exception_thrown(&event);
+}
+
+fn on_exception_catch(event: ExceptionCatchEvent) {
+    if !exceptions_enabled() {
+        return;
+    }
+
+    recording::record(|| Event::ExceptionCaught { time: precise_time_ns(), thread: event.thread.name.clone(), class: event.exception_class.to_string() });
+    static_context().exception_caught(&event);
+}
+
 fn on_class_file_load(mut event: ClassFileLoadEvent) -> Option<Vec<u8>> {
     let shall_transform = match static_context().config.read() {
-        Ok(cfg) => (*cfg).entry_points.iter().any(|item| item.starts_with(event.class_name.as_str())), //event.class_name.as_str() == item),
+        Ok(cfg) => (*cfg).instrumentation.entry_points.iter().any(|item| item.starts_with(event.class_name.as_str())), //event.class_name.as_str() == item),
         _ => false
     };
 
     if shall_transform {
         {
             let mut transformer = Transformer::new(&mut event.class);
-            let result = transformer.ensure_constant(Constant::Utf8(String::from("Cde").into_bytes()));
+            let result = transformer.ensure_constant(Constant::utf8("Cde"));
 
-            println!("Result: {:?}", result);
+            debug!(logger(), "Result: {:?}", result);
         }
-        let _: Vec<()> = ClassfilePrinter::render_lines(&event.class).iter().map(|line| println!("{}", line)).collect();
+        debug!(logger(), "{}", ClassfilePrinter::render_lines(&event.class).join("\n"));
     }
 /*
     let output_class: Vec<u8> = vec![];
@@ -152,19 +266,196 @@ fn on_class_file_load(mut event: ClassFileLoadEvent) -> Option<Vec<u8>> {
 }
 
 fn on_garbage_collection_start() {
-    println!("GC Start: {:?}", std::time::Instant::now());
+    gc::pause_start();
 }
 
 fn on_garbage_collection_finish() {
-    println!("GC Finish: {:?}", std::time::Instant::now());
+    gc::pause_finish();
+}
+
+/// Pass a finished pause on to the timeline, the recording and the log. Called by the GC pause
+/// reporting thread, as the garbage collection callbacks themselves may only use raw monitors.
+fn report_gc_pause(pause: GcPause) {
+    static_context().timeline_gc_pause(&pause);
+    recording::record(|| Event::GcPause { time: pause.start, duration: pause.duration(), freed: pause.freed });
+
+    if pause.over_threshold || pause.over_budget {
+        warn!(logger(), "GC pause of {:.3} ms exceeded the {}", pause.duration() as f64 / 1e6, if pause.over_threshold { "pause threshold" } else { "pause time budget" });
+    }
 }
 
 fn on_object_alloc(event: ObjectAllocationEvent) {
-    println!("Object allocation: (size: {})", event.size);
+    trace!(logger(), "Object allocation: (size: {})", event.size);
 }
 
-fn on_object_free() {
-    println!("Object free");
+fn on_sampled_object_alloc(event: ObjectAllocationEvent) {
+    static_context().object_allocated(&event);
+}
+
+fn on_object_free(tag: TagId) {
+    gc::object_freed(tag);
+}
+
+fn on_vm_init() {
+    let (sampling, export_config, export_dir, prometheus, statsd, control, reload_enabled) = match static_context().config.read() {
+        Ok(cfg) => ((*cfg).sampling.clone(), (*cfg).export.clone(), (*cfg).export_dir(), (*cfg).prometheus.clone(), (*cfg).statsd.clone(), (*cfg).control.clone(), (*cfg).reload.enabled),
+        Err(_) => return
+    };
+
+    if gc::is_tracking() {
+        if let Err(err) = gc::start_reporting(report_gc_pause) {
+            error!(logger(), "Couldn't start reporting GC pauses: {}", err);
+        }
+    }
+
+    if sampling.enabled || sampling.cpu_interval_ms > 0 {
+        if let Err(err) = sampler::start(sampling) {
+            error!(logger(), "Couldn't start the sampling profiler: {}", err);
+        }
+    }
+
+    if export_config.is_enabled() {
+        let exporters = export::exporters(&export_config, &export_dir);
+
+        if let Err(err) = export::start(export_config, exporters) {
+            error!(logger(), "Couldn't start the metrics exporter: {}", err);
+        }
+    }
+
+    if prometheus.is_enabled() {
+        if let Err(err) = export::prometheus::start(prometheus) {
+            error!(logger(), "Couldn't start the metrics server: {}", err);
+        }
+    }
+
+    if statsd.enabled {
+        if let Err(err) = export::statsd::start(statsd, &static_context().agent_id()) {
+            error!(logger(), "Couldn't start the StatsD emitter: {}", err);
+        }
+    }
+
+    if control.enabled {
+        if let Err(err) = control::start(&control) {
+            error!(logger(), "Couldn't start the control socket: {}", err);
+        }
+    }
+
+    if let (true, Some(path)) = (reload_enabled, static_context().config_file()) {
+        match reload::start(&path) {
+            Ok(_) => info!(logger(), "Watching {} for changes", path.display()),
+            Err(err) => error!(logger(), "Couldn't watch the configuration for changes: {}", err)
+        }
+    }
+}
+
+/// Number of threads listed in the CPU time report
+const TOP_THREADS: usize = 10;
+
+fn on_vm_death() {
+    reload::stop();
+    control::stop();
+    sampler::stop();
+    export::stop();
+    export::prometheus::stop();
+    export::statsd::stop();
+    gc::stop_reporting();
+
+    let (output_dir, exceptions_enabled, slow_report_limit, timeline_file) = match static_context().config.read() {
+        Ok(cfg) => ((*cfg).output_dir.clone(), (*cfg).exceptions.enabled, (*cfg).slow_invocations.report_limit, (*cfg).timeline.file.clone()),
+        Err(_) => (String::from("."), false, 0, String::new())
+    };
+
+    gc::with_pauses(|pauses| {
+        if !pauses.is_empty() {
+            info!(logger(), "{}", pauses.render_summary());
+        }
+    });
+
+    if exceptions_enabled {
+        info!(logger(), "{}", static_context().exception_report().join("\n"));
+    }
+
+    let (slow_count, slowest) = static_context().slowest_invocations(slow_report_limit);
+
+    if slow_count > 0 {
+        info!(logger(), "Slow invocations: {}", slow_count);
+
+        for invocation in slowest {
+            info!(logger(), "  {}({}) on {}: {:.3} ms", invocation.method, invocation.arguments.join(", "), invocation.thread_name, invocation.duration as f64 / 1e6);
+        }
+    }
+
+    let (started, ended) = static_context().thread_churn();
+    info!(logger(), "Threads started: {}, ended: {}", started, ended);
+
+    for record in static_context().top_threads(TOP_THREADS) {
+        info!(logger(), "{} (id: {}{}): {:.3} ms CPU, {}", record.name, record.java_id, if record.is_daemon { ", daemon" } else { "" },
+            record.cpu_time as f64 / 1e6, if record.is_alive() { "alive" } else { "ended" });
+    }
+
+    let call_tree = match static_context().call_tree() {
+        Some(tree) => {
+            info!(logger(), "{}", tree.render_lines().iter().skip(1).cloned().collect::<Vec<String>>().join("\n"));
+            tree.to_folded()
+        },
+        None => FoldedStacks::new()
+    };
+
+    let profiles = vec![
+        ("samples", static_context().sampled_stacks(), "CPU samples", "samples"),
+        ("calltree", call_tree, "Method self time", "ns"),
+        ("allocations", static_context().allocation_stacks(), "Allocated bytes", "bytes"),
+        ("locks", static_context().lock_stacks(), "Monitor contention", "ns")
+    ];
+
+    for (name, folded, title, unit) in profiles {
+        if !folded.is_empty() {
+            if let Err(err) = write_flame_graph(&output_dir, name, &folded, title, unit) {
+                warn!(logger(), "Couldn't write {} flame graph: {}", name, err);
+            }
+        }
+    }
+
+    match recording::stop() {
+        Ok(events) if events > 0 => info!(logger(), "Recorded {} events", events),
+        Ok(_) => (),
+        Err(err) => warn!(logger(), "Couldn't write recording: {}", err)
+    }
+
+    match static_context().write_timeline(Path::new(&output_dir).join(&timeline_file)) {
+        Ok(Some(events)) => info!(logger(), "Wrote {} timeline events to {}", events, timeline_file),
+        Ok(None) => (),
+        Err(err) => warn!(logger(), "Couldn't write timeline: {}", err)
+    }
+
+    logging::shutdown();
+}
+
+///
+/// Read the configuration from the file given by the `config` option, or from `agent.conf` if it
+/// exists. Problems are logged, the defaults are used instead of an invalid configuration.
+///
+fn read_config(options: &Options) -> Option<Config> {
+    let path = match options.config_location {
+        Some(ref path) => path.clone(),
+        None if Path::new(DEFAULT_CONFIG_FILE).exists() => DEFAULT_CONFIG_FILE.to_string(),
+        None => return None
+    };
+
+    match Config::read_from_file(&path) {
+        Ok((config, warnings)) => {
+            for warning in warnings {
+                warn!(logger(), "{}: {}", path, warning);
+            }
+
+            static_context().set_config_file(&path);
+            Some(config)
+        },
+        Err(err) => {
+            error!(logger(), "Ignoring configuration {}: {}", path, err);
+            None
+        }
+    }
 }
 
 ///
@@ -175,32 +466,135 @@ fn on_object_free() {
 #[allow(non_snake_case, unused_variables)]
 pub extern fn Agent_OnLoad(vm: JavaVMPtr, options: MutString, reserved: VoidPtr) -> ReturnValue {
     let options = Options::parse(stringify(options));
-    println!("Starting up as {}", options.agent_id);
 
-    if let Some(config) = Config::read_config() {
-        println!("Setting configuration");
-        static_context().set_config(config);
+    if let Err(err) = logging::init(&LogConfig::from_options(&options)) {
+        error!(logger(), "Couldn't set up logging, falling back to stderr: {}", err);
     }
 
-    let mut agent = Agent::new(vm);
+    info!(logger(), "Starting up as {}", options.agent_id);
+    static_context().set_agent_id(&options.agent_id);
+
+    let (gc_config, allocations_config, exceptions_config, tracing, slow_config, control_enabled, reload_enabled, locks_enabled, timing_enabled) = match read_config(&options) {
+        Some(config) => {
+            debug!(logger(), "Setting configuration");
+            let configs = (config.gc.clone(), config.allocations.clone(), config.exceptions.clone(), !config.instrumentation.entry_points.is_empty(), config.slow_invocations.clone(), config.control.enabled, config.reload.enabled, config.locks.enabled, config.timing.enabled);
+
+            if config.recording.enabled {
+                match recording::start(Path::new(&config.output_dir).join(&config.recording.file), config.recording.chunk_size) {
+                    Ok(_) => info!(logger(), "Recording events to {}", config.recording.file),
+                    Err(err) => error!(logger(), "Couldn't start recording: {}", err)
+                }
+            }
+
+            if configs.3 && config.trace.output == "file" {
+                match FileTraceSink::open(Path::new(&config.output_dir).join("traces.log")) {
+                    Ok(sink) => static_context().set_trace_sink(Box::new(sink)),
+                    Err(err) => error!(logger(), "Couldn't open trace file: {}", err)
+                }
+            }
+
+            static_context().set_config(config);
+            configs
+        },
+        None => (GcConfig::default(), AllocationsConfig::default(), ExceptionsConfig::default(), false, SlowInvocationsConfig::default(), false, false, false, false)
+    };
+
+    let mut agent = match Agent::new(vm) {
+        Ok(agent) => agent,
+        Err(err) => {
+            crit!(logger(), "Could not get JVMTI environment: {}", err);
+            return err.code() as ReturnValue;
+        }
+    };
+
+    if gc_config.enabled {
+        match JVMAgent::new(vm).get_environment().and_then(|env| gc::init(env, &gc_config)) {
+            Ok(_) => {
+                agent.on_garbage_collection_start(Some(on_garbage_collection_start));
+                agent.on_garbage_collection_finish(Some(on_garbage_collection_finish));
+
+                // Only sampled allocations are tagged, which needs allocation sampling to be enabled
+                if gc_config.count_freed_sampled {
+                    agent.capabilities.can_tag_objects = true;
+                    agent.on_vm_object_free(Some(on_object_free));
+                }
+            },
+            Err(err) => error!(logger(), "Couldn't set up GC pause tracking: {}", err)
+        }
+    }
+
+    if allocations_config.enabled {
+        agent.on_sampled_object_alloc(Some(on_sampled_object_alloc));
+    }
 
-    //agent.on_garbage_collection_start(Some(on_garbage_collection_start));
-    //agent.on_garbage_collection_finish(Some(on_garbage_collection_finish));
     //agent.on_vm_object_alloc(Some(on_object_alloc));
-    //agent.on_vm_object_free(Some(on_object_free));
     //agent.on_class_file_load(Some(on_class_file_load));
-    //agent.on_method_entry(Some(on_method_entry));
-    //agent.on_method_exit(Some(on_method_exit));
+    // Entry points and watched methods may be added when the configuration is reloaded or through
+    // the control socket, the method events are enabled once they're needed
+    if tracing || timing_enabled || slow_config.is_enabled() || control_enabled || reload_enabled {
+        agent.on_method_entry(Some(on_method_entry));
+        agent.on_method_exit(Some(on_method_exit));
+    }
+
+    if slow_config.is_enabled() || control_enabled || reload_enabled {
+        agent.capabilities.can_access_local_variables = true;
+    }
+
+    agent.on_vm_init(Some(on_vm_init));
+    agent.on_vm_death(Some(on_vm_death));
+    // Exception profiling can be switched on through the control socket or a reloaded configuration,
+    // the events are switched off until then
+    if exceptions_config.enabled || control_enabled || reload_enabled {
+        agent.on_exception(Some(on_exception));
+        agent.on_exception_catch(Some(on_exception_catch));
+    }
+
+    agent.capabilities.can_get_current_thread_cpu_time = true;
+    agent.capabilities.can_get_thread_cpu_time = true;
     agent.on_thread_start(Some(on_thread_start));
     agent.on_thread_end(Some(on_thread_end));
-    agent.on_monitor_wait(Some(on_monitor_wait));
-    agent.on_monitor_waited(Some(on_monitor_waited));
-    agent.on_monitor_contended_enter(Some(on_monitor_contended_enter));
-    agent.on_monitor_contended_entered(Some(on_monitor_contended_entered));
-    //agent.on_class_file_load(Some(on_class_file_load));
+
+    if locks_enabled {
+        agent.on_monitor_wait(Some(on_monitor_wait));
+        agent.on_monitor_waited(Some(on_monitor_waited));
+        agent.on_monitor_contended_enter(Some(on_monitor_contended_enter));
+        agent.on_monitor_contended_entered(Some(on_monitor_contended_entered));
+    }
 
     agent.update();
 
+    if allocations_config.enabled {
+        if let Some(err) = agent.set_heap_sampling_interval(allocations_config.sampling_interval) {
+            error!(logger(), "Couldn't set the heap sampling interval: {}", err);
+        }
+    }
+
+    // Method exit events are only enabled for threads inside watched methods, unless every exit is
+    // needed for tracing or timing
+    if slow_config.is_enabled() || control_enabled || reload_enabled {
+        if let Err(err) = invocations::init(agent.into_environment(), &slow_config, tracing || timing_enabled) {
+            error!(logger(), "Couldn't set up slow invocation detection: {}", err);
+        }
+    }
+
+    if (control_enabled || reload_enabled) && !exceptions_config.enabled {
+        if let Err(err) = invocations::set_exception_events(false) {
+            error!(logger(), "Couldn't switch off exception events: {}", err);
+        }
+    }
+
+    // Method entry events are only needed once there are entry points, timed or watched methods
+    if control_enabled || reload_enabled {
+        let result = match static_context().config.read() {
+            Ok(cfg) => reload::set_method_events(&*cfg),
+            Err(_) => Ok(())
+        };
+
+        if let Err(err) = result {
+            error!(logger(), "Couldn't set up method events: {}", err);
+        }
+    }
+
     return 0;
 }
 
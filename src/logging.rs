@@ -0,0 +1,228 @@
+use super::options::Options;
+use slog::{Drain, Logger, OwnedKVList, Record};
+use slog_async::{Async, AsyncGuard, OverflowStrategy};
+use slog_term::{FullFormat, PlainDecorator, PlainSyncDecorator};
+use std::fs::{self, OpenOptions};
+use std::io;
+use std::os::unix::net::UnixDatagram;
+use std::path::Path;
+use std::process;
+use std::sync::{Mutex, RwLock};
+
+pub use slog::Level;
+
+/// Number of records buffered for the logging thread. Records logged while the buffer is full are
+/// dropped rather than blocking the logging thread.
+const BUFFER_SIZE: usize = 4096;
+/// Path of the local syslog socket
+pub const DEFAULT_SYSLOG_SOCKET: &'static str = "/dev/log";
+/// Name of the crate, which prefixes the path of every module
+const CRATE_NAME: &'static str = "jvmti";
+
+lazy_static! {
+    static ref LOGGER: RwLock<Logger> = RwLock::new(default_logger());
+    static ref GUARD: Mutex<Option<AsyncGuard>> = Mutex::new(None);
+}
+
+/// Where log records are written to
+#[derive(Clone, Debug, PartialEq)]
+pub enum LogOutput {
+    Stderr,
+    File(String),
+    /// A syslog socket, given by its path
+    Syslog(String)
+}
+
+impl LogOutput {
+
+    /// Parse a log destination: `stderr`, `syslog`, `syslog:<socket path>` or a file path
+    pub fn parse(value: &str) -> LogOutput {
+        match value {
+            "" | "stderr" => LogOutput::Stderr,
+            "syslog" => LogOutput::Syslog(DEFAULT_SYSLOG_SOCKET.to_string()),
+            _ if value.starts_with("syslog:") => LogOutput::Syslog(value["syslog:".len()..].to_string()),
+            _ => LogOutput::File(value.to_string())
+        }
+    }
+}
+
+///
+/// Settings of the logging subsystem. Records are written if their level is at least the level of
+/// the module that has logged them, which is the level configured for the closest enclosing module
+/// or, without one, the default level.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct LogConfig {
+    pub output: LogOutput,
+    pub level: Level,
+    /// Levels of individual modules, eg. `event_handler`, along with their submodules
+    pub modules: Vec<(String, Level)>
+}
+
+impl LogConfig {
+
+    pub fn from_options(options: &Options) -> LogConfig {
+        LogConfig {
+            output: options.log_output.as_ref().map(|output| LogOutput::parse(output)).unwrap_or(LogOutput::Stderr),
+            level: options.log_level.unwrap_or(Level::Info),
+            modules: options.log_modules.clone()
+        }
+    }
+}
+
+impl Default for LogConfig {
+
+    fn default() -> Self {
+        LogConfig { output: LogOutput::Stderr, level: Level::Info, modules: vec![] }
+    }
+}
+
+///
+/// Drain that discards the records below the level of the module they've been logged from. It
+/// wraps the asynchronous drain and runs on the thread logging the record, so that filtered
+/// records aren't even queued.
+///
+pub struct ModuleFilter<D: Drain> {
+    drain: D,
+    level: Level,
+    modules: Vec<(String, Level)>
+}
+
+impl<D: Drain> ModuleFilter<D> {
+
+    pub fn new(drain: D, level: Level, modules: &[(String, Level)]) -> ModuleFilter<D> {
+        let modules = modules.iter().map(|&(ref module, level)| match module.starts_with(CRATE_NAME) {
+            true => (module.clone(), level),
+            false => (format!("{}::{}", CRATE_NAME, module), level)
+        }).collect();
+
+        ModuleFilter { drain: drain, level: level, modules: modules }
+    }
+
+    /// Return the level of the given module path, eg. `jvmti::profile::gc`
+    pub fn level_for(&self, module: &str) -> Level {
+        self.modules.iter()
+            .filter(|&&(ref name, _)| module == name || module.starts_with(&format!("{}::", name)))
+            .max_by_key(|&&(ref name, _)| name.len())
+            .map(|&(_, level)| level)
+            .unwrap_or(self.level)
+    }
+}
+
+impl<D: Drain> Drain for ModuleFilter<D> {
+    type Ok = Option<D::Ok>;
+    type Err = D::Err;
+
+    fn log(&self, record: &Record, values: &OwnedKVList) -> Result<Self::Ok, Self::Err> {
+        match record.level().is_at_least(self.level_for(record.module())) {
+            true => self.drain.log(record, values).map(Some),
+            false => Ok(None)
+        }
+    }
+}
+
+/// Drain that sends records to a syslog socket, with the `user` facility
+pub struct SyslogDrain {
+    socket: UnixDatagram,
+    path: String
+}
+
+impl SyslogDrain {
+
+    pub fn connect(path: &str) -> io::Result<SyslogDrain> {
+        let socket = UnixDatagram::unbound()?;
+
+        socket.connect(path)?;
+        Ok(SyslogDrain { socket: socket, path: path.to_string() })
+    }
+}
+
+/// Return the syslog severity of a level
+fn severity(level: Level) -> u8 {
+    match level {
+        Level::Critical => 2,
+        Level::Error => 3,
+        Level::Warning => 4,
+        Level::Info => 6,
+        Level::Debug | Level::Trace => 7
+    }
+}
+
+impl Drain for SyslogDrain {
+    type Ok = ();
+    type Err = io::Error;
+
+    fn log(&self, record: &Record, _: &OwnedKVList) -> io::Result<()> {
+        // Facility 1 is user-level messages
+        let message = format!("<{}>{}[{}]: {}", 8 + severity(record.level()), CRATE_NAME, process::id(), record.msg());
+
+        match self.socket.send(message.as_bytes()) {
+            Ok(_) => Ok(()),
+            // The syslog daemon may have been restarted
+            Err(_) => self.socket.connect(&self.path).and_then(|_| self.socket.send(message.as_bytes())).map(|_| ())
+        }
+    }
+}
+
+///
+/// Set up logging with the given settings. Records are passed to a background thread, which does
+/// all the formatting and I/O, through a bounded buffer so logging never blocks the calling thread.
+/// Returns an error if the log file or syslog socket couldn't be opened, in which case the default
+/// logger is kept.
+///
+pub fn init(config: &LogConfig) -> io::Result<()> {
+    let (drain, guard) = match config.output {
+        LogOutput::Stderr => async_drain(FullFormat::new(PlainDecorator::new(io::stderr())).build().ignore_res()),
+        LogOutput::File(ref path) => {
+            if let Some(dir) = Path::new(path).parent() {
+                if !dir.as_os_str().is_empty() {
+                    fs::create_dir_all(dir)?;
+                }
+            }
+
+            let file = OpenOptions::new().create(true).append(true).open(path)?;
+
+            async_drain(FullFormat::new(PlainDecorator::new(file)).build().ignore_res())
+        },
+        LogOutput::Syslog(ref path) => async_drain(SyslogDrain::connect(path)?.ignore_res())
+    };
+
+    let logger = Logger::root(ModuleFilter::new(drain, config.level, &config.modules).ignore_res(), o!());
+
+    if let Ok(mut current) = LOGGER.write() {
+        *current = logger;
+    }
+
+    if let Ok(mut current) = GUARD.lock() {
+        // Dropping the previous guard flushes the records of the previous logger
+        *current = Some(guard);
+    }
+
+    Ok(())
+}
+
+fn async_drain<D: Drain<Ok=(), Err=slog::Never> + Send + 'static>(drain: D) -> (Async, AsyncGuard) {
+    Async::new(drain).chan_size(BUFFER_SIZE).overflow_strategy(OverflowStrategy::DropAndReport).thread_name(format!("{}-log", CRATE_NAME)).build_with_guard()
+}
+
+/// Write the records logged so far and stop the logging thread. Records logged afterwards are lost.
+pub fn shutdown() {
+    if let Ok(mut guard) = GUARD.lock() {
+        guard.take();
+    }
+}
+
+/// Return the logger of the agent
+pub fn logger() -> Logger {
+    match LOGGER.read() {
+        Ok(logger) => logger.clone(),
+        Err(_) => default_logger()
+    }
+}
+
+/// Logger used until logging has been set up, writing records of at least `Info` level to stderr
+fn default_logger() -> Logger {
+    let drain = FullFormat::new(PlainSyncDecorator::new(io::stderr())).build().ignore_res();
+
+    Logger::root(ModuleFilter::new(drain, Level::Info, &[]).ignore_res(), o!())
+}
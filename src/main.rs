@@ -2,10 +2,17 @@ extern crate jvmti;
 
 use std::env;
 use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+use std::process;
 //use std::io::{stdout};
 
 use jvmti::bytecode::*;
 use jvmti::bytecode::printer::*;
+use jvmti::control::{self, ControlClient};
+use jvmti::profile::flamegraph::{render_svg, FoldedStacks};
+use jvmti::recording::{convert, Event};
+use jvmti::recording::reader::RecordingReader;
 
 fn main2() {
     let class = Classfile::new();
@@ -19,6 +26,14 @@ fn main2() {
 // The main program is a simple interface to access the bytecode parsing and generating
 // functionality and as such, it's not intended for actual use.
 fn main() {
+    if env::args().nth(1) == Some(String::from("recording")) {
+        return recording(env::args().skip(2).collect());
+    }
+
+    if env::args().nth(1) == Some(String::from("ctl")) {
+        return ctl(env::args().skip(2).collect());
+    }
+
     if let (Some(action), Some(class_name)) = (env::args().nth(1), env::args().nth(2)) {
         match File::open(class_name.clone()) {
             Ok(mut file) => {
@@ -40,7 +55,10 @@ fn main() {
             Err(err) => assert!(false, format!("{:?}", err))
         }
     } else {
-        println!("Invalid arguments. Usage: jvmti [read|write] <Class file>")
+        println!("Invalid arguments. Usage: jvmti [read|write] <Class file>");
+        println!("                          jvmti recording dump <Recording file>");
+        println!("                          jvmti recording convert <Recording file> <{}> <Output file>", convert::FORMATS.join("|"));
+        println!("                          jvmti ctl <PID|Socket file> threads|histo [gc|<Method>]|profile start|profile stop [--out <File>]|stats|<Command>");
     }
 }
 
@@ -83,3 +101,123 @@ fn show_methods(class: Classfile, class_name: String ) {
         }).fold(0, |_, _| 0);
     }).fold(0, |_, _| 0);
 }
+
+// Dumps a recording made by the agent or converts it to one of the export formats
+fn recording(args: Vec<String>) {
+    let (action, path) = match (args.get(0), args.get(1)) {
+        (Some(action), Some(path)) => (action.as_str(), path),
+        _ => {
+            println!("Invalid arguments. Usage: jvmti recording [dump|convert] <Recording file> [<Format> <Output file>]");
+            process::exit(1);
+        }
+    };
+
+    let events: Vec<Event> = match RecordingReader::open(path) {
+        // Unreadable chunks are skipped, the rest of the recording is still usable
+        Ok(reader) => reader.filter_map(|event| event.map_err(|err| eprintln!("Skipping unreadable chunk: {}", err)).ok()).collect(),
+        Err(err) => {
+            eprintln!("Can't read recording {}: {}", path, err);
+            process::exit(1);
+        }
+    };
+
+    let result = match (action, args.get(2), args.get(3)) {
+        ("dump", _, _) => convert::write_text(events, &mut io::stdout()),
+        ("convert", Some(format), Some(output)) => File::create(output).map(BufWriter::new).and_then(|mut out| {
+            match format.as_str() {
+                "text" => convert::write_text(events, &mut out),
+                "jsonl" => convert::write_json_lines(events, &mut out),
+                "csv" => convert::write_csv(events, &mut out),
+                "chrome" => convert::write_chrome(events, &mut out, process::id()),
+                _ => Err(io::Error::new(io::ErrorKind::InvalidInput, format!("unknown format: {}, expected one of {}", format, convert::FORMATS.join(", "))))
+            }.and_then(|_| out.flush())
+        }),
+        _ => Err(io::Error::new(io::ErrorKind::InvalidInput, "expected dump <Recording file> or convert <Recording file> <Format> <Output file>"))
+    };
+
+    if let Err(err) = result {
+        eprintln!("Can't {} recording {}: {}", action, path, err);
+        process::exit(1);
+    }
+}
+
+// Sends commands to the control socket of a running agent, found by the PID of its VM unless the
+// path of the socket is given. Anything that isn't a shorthand is sent as it is, see `help`.
+fn ctl(args: Vec<String>) {
+    let (target, words): (&String, Vec<&str>) = match args.split_first() {
+        Some((target, words)) if !words.is_empty() => (target, words.iter().map(|word| word.as_str()).collect()),
+        _ => {
+            println!("Invalid arguments. Usage: jvmti ctl <PID|Socket file> threads|histo [gc|<Method>]|profile start|profile stop [--out <File>]|stats|<Command>");
+            process::exit(1);
+        }
+    };
+
+    let path = match target.parse::<u32>() {
+        Ok(pid) => control::default_socket_path(pid),
+        Err(_) => PathBuf::from(target)
+    };
+
+    let (commands, output) = match words.as_slice() {
+        ["histo"] => (vec!["histogram gc".to_string()], None),
+        ["histo", subject] => (vec![format!("histogram {}", subject)], None),
+        // Samples taken before are dropped so that the profile only covers what comes next
+        ["profile", "start"] => (vec!["reset samples".to_string(), "enable sampling".to_string()], None),
+        ["profile", "stop"] => (vec!["disable sampling".to_string()], None),
+        ["profile", "stop", "--out", file] => (vec!["disable sampling".to_string(), "stacks samples".to_string()], Some(file.to_string())),
+        ["stats"] => (vec!["status".to_string(), "report threads".to_string(), "report methods".to_string(), "report gc".to_string()], None),
+        _ => (vec![words.join(" ")], None)
+    };
+
+    let mut client = match ControlClient::connect(&path) {
+        Ok(client) => client,
+        Err(err) => {
+            eprintln!("Can't connect to {}: {}. Is the control socket enabled in the [control] section of the agent configuration?", path.display(), err);
+            process::exit(1);
+        }
+    };
+
+    for (idx, command) in commands.iter().enumerate() {
+        let lines = match client.send(command) {
+            Ok(Ok(lines)) => lines,
+            Ok(Err(err)) => {
+                eprintln!("{}: {}", command, err);
+                process::exit(1);
+            },
+            Err(err) => {
+                eprintln!("Can't send {} to {}: {}", command, path.display(), err);
+                process::exit(1);
+            }
+        };
+
+        match output {
+            Some(ref file) if command == "stacks samples" => write_profile(file, &lines),
+            _ => {
+                if idx > 0 {
+                    println!();
+                }
+
+                for line in lines {
+                    println!("{}", line);
+                }
+            }
+        }
+    }
+}
+
+// Writes sampled stacks as a flame graph if the file name ends with .svg, as folded stacks otherwise
+fn write_profile(file: &str, lines: &[String]) {
+    let folded = FoldedStacks::parse(&lines.join("\n"));
+
+    let content = match file.ends_with(".svg") {
+        true => render_svg(&folded, "CPU samples", "samples"),
+        false => folded.to_string()
+    };
+
+    match File::create(file).and_then(|mut out| out.write_all(content.as_bytes())) {
+        Ok(_) => println!("Wrote {} stacks ({} samples) to {}", folded.len(), folded.total(), file),
+        Err(err) => {
+            eprintln!("Can't write profile to {}: {}", file, err);
+            process::exit(1);
+        }
+    }
+}
@@ -9,16 +9,22 @@ pub struct Method {
 }
 
 pub struct MethodSignature {
-    pub name: String
+    pub name: String,
+    /// The type descriptor of the method, eg. `(ILjava/lang/String;)V`, empty if it's unknown
+    pub descriptor: String
 }
 
 impl MethodSignature {
 
     pub fn new(raw_signature: String) -> MethodSignature {
-        MethodSignature { name: raw_signature }
+        MethodSignature { name: raw_signature, descriptor: String::new() }
+    }
+
+    pub fn with_descriptor(name: String, descriptor: String) -> MethodSignature {
+        MethodSignature { name: name, descriptor: descriptor }
     }
 
     pub fn unknown() -> MethodSignature {
-        MethodSignature { name: "<UNKNOWN METHOD>".to_string() }
+        MethodSignature { name: "<UNKNOWN METHOD>".to_string(), descriptor: String::new() }
     }
 }
@@ -0,0 +1,86 @@
+use super::environment::jvmti::JVMTI;
+use super::error::NativeError;
+use super::native::jvmti_native::jrawMonitorID;
+use std::cell::UnsafeCell;
+use std::ops::{Deref, DerefMut};
+
+///
+/// Represents a JVMTI raw monitor, ie. a monitor that's not associated with any Java object.
+///
+#[derive(Eq, PartialEq, Hash, Clone)]
+pub struct RawMonitorId {
+    pub native_id: jrawMonitorID
+}
+
+/// Marker trait implementation for `Send`
+unsafe impl Send for RawMonitorId { }
+
+/// Marker trait implementation for `Sync`
+unsafe impl Sync for RawMonitorId { }
+
+///
+/// Data guarded by a JVMTI raw monitor. Unlike Rust's own locks, raw monitors may be used inside
+/// callbacks that run with most of the JVMTI API disabled, eg. garbage collection events.
+///
+pub struct RawMonitor<T> {
+    env: Box<JVMTI>,
+    id: RawMonitorId,
+    data: UnsafeCell<T>
+}
+
+/// JVMTI environments aren't bound to the thread they've been acquired on, unlike JNI ones, and
+/// the data is only ever accessed while the monitor is held.
+unsafe impl<T: Send> Send for RawMonitor<T> { }
+
+unsafe impl<T: Send> Sync for RawMonitor<T> { }
+
+impl<T> RawMonitor<T> {
+
+    /// Create a raw monitor with the given name guarding `data`. The monitor is destroyed when it
+    /// goes out of scope.
+    pub fn new(env: Box<JVMTI>, name: &str, data: T) -> Result<RawMonitor<T>, NativeError> {
+        let id = env.create_raw_monitor(name)?;
+
+        Ok(RawMonitor { env: env, id: id, data: UnsafeCell::new(data) })
+    }
+
+    /// Enter the monitor, blocking until it's available. The monitor is exited when the returned
+    /// guard is dropped. Raw monitors are reentrant, so this must not be called again by a thread
+    /// that's holding a guard already.
+    pub fn lock(&self) -> Result<RawMonitorGuard<T>, NativeError> {
+        self.env.raw_monitor_enter(&self.id)?;
+
+        Ok(RawMonitorGuard { monitor: self })
+    }
+}
+
+impl<T> Drop for RawMonitor<T> {
+    fn drop(&mut self) {
+        let _ = self.env.destroy_raw_monitor(&self.id);
+    }
+}
+
+/// Access to the data of an entered raw monitor
+pub struct RawMonitorGuard<'a, T: 'a> {
+    monitor: &'a RawMonitor<T>
+}
+
+impl<'a, T> Deref for RawMonitorGuard<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.monitor.data.get() }
+    }
+}
+
+impl<'a, T> DerefMut for RawMonitorGuard<'a, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.monitor.data.get() }
+    }
+}
+
+impl<'a, T> Drop for RawMonitorGuard<'a, T> {
+    fn drop(&mut self) {
+        let _ = self.monitor.env.raw_monitor_exit(&self.monitor.id);
+    }
+}
@@ -0,0 +1,82 @@
+//!
+//! Codec for the "modified UTF-8" encoding used by the JVM for both class file constants and the
+//! strings returned by JNI/JVMTI functions.
+//!
+//! It differs from standard UTF-8 in two ways: the NUL character is encoded using two bytes
+//! (`0xC0 0x80`) so that encoded strings never contain zero bytes and supplementary characters
+//! are encoded as a surrogate pair, each half of which takes three bytes, instead of a single
+//! four byte sequence.
+//!
+
+///
+/// Encode the given string into modified UTF-8
+///
+pub fn encode(value: &str) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(value.len());
+
+    for unit in value.encode_utf16() {
+        match unit {
+            0x0001...0x007F => bytes.push(unit as u8),
+            0x0000 | 0x0080...0x07FF => {
+                bytes.push((0xC0 | (unit >> 6)) as u8);
+                bytes.push((0x80 | (unit & 0x3F)) as u8);
+            },
+            _ => {
+                bytes.push((0xE0 | (unit >> 12)) as u8);
+                bytes.push((0x80 | ((unit >> 6) & 0x3F)) as u8);
+                bytes.push((0x80 | (unit & 0x3F)) as u8);
+            }
+        }
+    }
+
+    bytes
+}
+
+///
+/// Decode the given modified UTF-8 bytes. Returns `None` if the input isn't a valid modified
+/// UTF-8 sequence (eg. it contains a raw zero byte, four byte sequences or unpaired surrogates).
+///
+pub fn decode(bytes: &[u8]) -> Option<String> {
+    // Plain ASCII strings are encoded identically so they can be taken as they are
+    if bytes.iter().all(|&byte| byte > 0 && byte < 0x80) {
+        return String::from_utf8(bytes.to_vec()).ok();
+    }
+
+    let mut units: Vec<u16> = Vec::with_capacity(bytes.len());
+    let mut idx = 0;
+
+    while idx < bytes.len() {
+        let first = bytes[idx] as u16;
+
+        match first {
+            0x01...0x7F => {
+                units.push(first);
+                idx += 1;
+            },
+            0xC0...0xDF => {
+                let second = continuation(bytes, idx + 1)?;
+
+                units.push(((first & 0x1F) << 6) | second);
+                idx += 2;
+            },
+            0xE0...0xEF => {
+                let second = continuation(bytes, idx + 1)?;
+                let third = continuation(bytes, idx + 2)?;
+
+                units.push(((first & 0x0F) << 12) | (second << 6) | third);
+                idx += 3;
+            },
+            _ => return None
+        }
+    }
+
+    String::from_utf16(&units).ok()
+}
+
+/// Return the payload bits of the continuation byte at the given index
+fn continuation(bytes: &[u8], idx: usize) -> Option<u16> {
+    match bytes.get(idx) {
+        Some(&byte) if byte & 0xC0 == 0x80 => Some((byte & 0x3F) as u16),
+        _ => None
+    }
+}
@@ -0,0 +1,990 @@
+//!
+//! Declarations of the JVMTI types, constants and function tables as defined by `jvmti.h`
+//! (JVMTI 11). Functions and events added after JVMTI 1.2 are only present in the function table
+//! of virtual machines implementing the corresponding version, so callers must check the version
+//! number of the environment before using them.
+//!
+use libc::{c_char, c_uchar, c_int, c_uint, c_void};
+use super::jni::*;
+
+pub type Enum_jvmtiVersion = c_uint;
+pub const JVMTI_VERSION_1: c_uint = 0x30010000;
+pub const JVMTI_VERSION_1_0: c_uint = 0x30010000;
+pub const JVMTI_VERSION_1_1: c_uint = 0x30010100;
+pub const JVMTI_VERSION_1_2: c_uint = 0x30010200;
+pub const JVMTI_VERSION_9: c_uint = 0x30090000;
+pub const JVMTI_VERSION_11: c_uint = 0x300B0000;
+/// The version requested by the agent when acquiring an environment, matching these declarations
+pub const JVMTI_VERSION: c_int = JVMTI_VERSION_11 as c_int;
+/// The version requested from virtual machines that don't implement JVMTI 11, eg. Java 8 ones
+pub const JVMTI_VERSION_LEGACY: c_int = 0x30010201;
+
+pub type jvmtiEnv = *const Struct_jvmtiInterface_1_;
+pub type jthread = jobject;
+pub type jthreadGroup = jobject;
+pub type jlocation = jlong;
+pub enum Struct__jrawMonitorID { }
+pub type jrawMonitorID = *mut Struct__jrawMonitorID;
+pub type jniNativeInterface = JNINativeInterface;
+
+pub type Enum_jvmtiThreadState = c_uint;
+pub const JVMTI_THREAD_STATE_ALIVE: c_uint = 0x0001;
+pub const JVMTI_THREAD_STATE_TERMINATED: c_uint = 0x0002;
+pub const JVMTI_THREAD_STATE_RUNNABLE: c_uint = 0x0004;
+pub const JVMTI_THREAD_STATE_BLOCKED_ON_MONITOR_ENTER: c_uint = 0x0400;
+pub const JVMTI_THREAD_STATE_WAITING: c_uint = 0x0080;
+pub const JVMTI_THREAD_STATE_WAITING_INDEFINITELY: c_uint = 0x0010;
+pub const JVMTI_THREAD_STATE_WAITING_WITH_TIMEOUT: c_uint = 0x0020;
+pub const JVMTI_THREAD_STATE_SLEEPING: c_uint = 0x0040;
+pub const JVMTI_THREAD_STATE_IN_OBJECT_WAIT: c_uint = 0x0100;
+pub const JVMTI_THREAD_STATE_PARKED: c_uint = 0x0200;
+pub const JVMTI_THREAD_STATE_SUSPENDED: c_uint = 0x100000;
+pub const JVMTI_THREAD_STATE_INTERRUPTED: c_uint = 0x200000;
+pub const JVMTI_THREAD_STATE_IN_NATIVE: c_uint = 0x400000;
+pub const JVMTI_THREAD_STATE_VENDOR_1: c_uint = 0x10000000;
+pub const JVMTI_THREAD_STATE_VENDOR_2: c_uint = 0x20000000;
+pub const JVMTI_THREAD_STATE_VENDOR_3: c_uint = 0x40000000;
+
+pub type Enum_jvmtiJavaLangThreadState = c_uint;
+pub const JVMTI_JAVA_LANG_THREAD_STATE_MASK: c_uint = 1207;
+pub const JVMTI_JAVA_LANG_THREAD_STATE_NEW: c_uint = 0;
+pub const JVMTI_JAVA_LANG_THREAD_STATE_TERMINATED: c_uint = 2;
+pub const JVMTI_JAVA_LANG_THREAD_STATE_RUNNABLE: c_uint = 5;
+pub const JVMTI_JAVA_LANG_THREAD_STATE_BLOCKED: c_uint = 1025;
+pub const JVMTI_JAVA_LANG_THREAD_STATE_WAITING: c_uint = 145;
+pub const JVMTI_JAVA_LANG_THREAD_STATE_TIMED_WAITING: c_uint = 161;
+
+pub type Enum_jvmtiThreadPriority = c_uint;
+pub const JVMTI_THREAD_MIN_PRIORITY: c_uint = 1;
+pub const JVMTI_THREAD_NORM_PRIORITY: c_uint = 5;
+pub const JVMTI_THREAD_MAX_PRIORITY: c_uint = 10;
+
+pub type Enum_jvmtiHeapFilter = c_uint;
+pub const JVMTI_HEAP_FILTER_TAGGED: c_uint = 4;
+pub const JVMTI_HEAP_FILTER_UNTAGGED: c_uint = 8;
+pub const JVMTI_HEAP_FILTER_CLASS_TAGGED: c_uint = 16;
+pub const JVMTI_HEAP_FILTER_CLASS_UNTAGGED: c_uint = 32;
+
+pub type Enum_jvmtiHeapVisitControl = c_uint;
+pub const JVMTI_VISIT_OBJECTS: c_uint = 256;
+pub const JVMTI_VISIT_ABORT: c_uint = 32768;
+
+pub type jvmtiHeapReferenceKind = c_uint;
+pub const JVMTI_HEAP_REFERENCE_CLASS: c_uint = 1;
+pub const JVMTI_HEAP_REFERENCE_FIELD: c_uint = 2;
+pub const JVMTI_HEAP_REFERENCE_ARRAY_ELEMENT: c_uint = 3;
+pub const JVMTI_HEAP_REFERENCE_CLASS_LOADER: c_uint = 4;
+pub const JVMTI_HEAP_REFERENCE_SIGNERS: c_uint = 5;
+pub const JVMTI_HEAP_REFERENCE_PROTECTION_DOMAIN: c_uint = 6;
+pub const JVMTI_HEAP_REFERENCE_INTERFACE: c_uint = 7;
+pub const JVMTI_HEAP_REFERENCE_STATIC_FIELD: c_uint = 8;
+pub const JVMTI_HEAP_REFERENCE_CONSTANT_POOL: c_uint = 9;
+pub const JVMTI_HEAP_REFERENCE_SUPERCLASS: c_uint = 10;
+pub const JVMTI_HEAP_REFERENCE_JNI_GLOBAL: c_uint = 21;
+pub const JVMTI_HEAP_REFERENCE_SYSTEM_CLASS: c_uint = 22;
+pub const JVMTI_HEAP_REFERENCE_MONITOR: c_uint = 23;
+pub const JVMTI_HEAP_REFERENCE_STACK_LOCAL: c_uint = 24;
+pub const JVMTI_HEAP_REFERENCE_JNI_LOCAL: c_uint = 25;
+pub const JVMTI_HEAP_REFERENCE_THREAD: c_uint = 26;
+pub const JVMTI_HEAP_REFERENCE_OTHER: c_uint = 27;
+
+pub type jvmtiPrimitiveType = c_uint;
+pub const JVMTI_PRIMITIVE_TYPE_BOOLEAN: c_uint = 90;
+pub const JVMTI_PRIMITIVE_TYPE_BYTE: c_uint = 66;
+pub const JVMTI_PRIMITIVE_TYPE_CHAR: c_uint = 67;
+pub const JVMTI_PRIMITIVE_TYPE_SHORT: c_uint = 83;
+pub const JVMTI_PRIMITIVE_TYPE_INT: c_uint = 73;
+pub const JVMTI_PRIMITIVE_TYPE_LONG: c_uint = 74;
+pub const JVMTI_PRIMITIVE_TYPE_FLOAT: c_uint = 70;
+pub const JVMTI_PRIMITIVE_TYPE_DOUBLE: c_uint = 68;
+
+pub type jvmtiHeapObjectFilter = c_uint;
+pub const JVMTI_HEAP_OBJECT_TAGGED: c_uint = 1;
+pub const JVMTI_HEAP_OBJECT_UNTAGGED: c_uint = 2;
+pub const JVMTI_HEAP_OBJECT_EITHER: c_uint = 3;
+
+pub type jvmtiHeapRootKind = c_uint;
+pub const JVMTI_HEAP_ROOT_JNI_GLOBAL: c_uint = 1;
+pub const JVMTI_HEAP_ROOT_SYSTEM_CLASS: c_uint = 2;
+pub const JVMTI_HEAP_ROOT_MONITOR: c_uint = 3;
+pub const JVMTI_HEAP_ROOT_STACK_LOCAL: c_uint = 4;
+pub const JVMTI_HEAP_ROOT_JNI_LOCAL: c_uint = 5;
+pub const JVMTI_HEAP_ROOT_THREAD: c_uint = 6;
+pub const JVMTI_HEAP_ROOT_OTHER: c_uint = 7;
+
+pub type jvmtiObjectReferenceKind = c_uint;
+pub const JVMTI_REFERENCE_CLASS: c_uint = 1;
+pub const JVMTI_REFERENCE_FIELD: c_uint = 2;
+pub const JVMTI_REFERENCE_ARRAY_ELEMENT: c_uint = 3;
+pub const JVMTI_REFERENCE_CLASS_LOADER: c_uint = 4;
+pub const JVMTI_REFERENCE_SIGNERS: c_uint = 5;
+pub const JVMTI_REFERENCE_PROTECTION_DOMAIN: c_uint = 6;
+pub const JVMTI_REFERENCE_INTERFACE: c_uint = 7;
+pub const JVMTI_REFERENCE_STATIC_FIELD: c_uint = 8;
+pub const JVMTI_REFERENCE_CONSTANT_POOL: c_uint = 9;
+
+pub type jvmtiIterationControl = c_uint;
+pub const JVMTI_ITERATION_CONTINUE: c_uint = 1;
+pub const JVMTI_ITERATION_IGNORE: c_uint = 2;
+pub const JVMTI_ITERATION_ABORT: c_uint = 0;
+
+pub type Enum_jvmtiClassStatus = c_uint;
+pub const JVMTI_CLASS_STATUS_VERIFIED: c_uint = 1;
+pub const JVMTI_CLASS_STATUS_PREPARED: c_uint = 2;
+pub const JVMTI_CLASS_STATUS_INITIALIZED: c_uint = 4;
+pub const JVMTI_CLASS_STATUS_ERROR: c_uint = 8;
+pub const JVMTI_CLASS_STATUS_ARRAY: c_uint = 16;
+pub const JVMTI_CLASS_STATUS_PRIMITIVE: c_uint = 32;
+
+pub type jvmtiEventMode = c_uint;
+pub const JVMTI_ENABLE: c_uint = 1;
+pub const JVMTI_DISABLE: c_uint = 0;
+
+pub type jvmtiParamTypes = c_uint;
+pub const JVMTI_TYPE_JBYTE: c_uint = 101;
+pub const JVMTI_TYPE_JCHAR: c_uint = 102;
+pub const JVMTI_TYPE_JSHORT: c_uint = 103;
+pub const JVMTI_TYPE_JINT: c_uint = 104;
+pub const JVMTI_TYPE_JLONG: c_uint = 105;
+pub const JVMTI_TYPE_JFLOAT: c_uint = 106;
+pub const JVMTI_TYPE_JDOUBLE: c_uint = 107;
+pub const JVMTI_TYPE_JBOOLEAN: c_uint = 108;
+pub const JVMTI_TYPE_JOBJECT: c_uint = 109;
+pub const JVMTI_TYPE_JTHREAD: c_uint = 110;
+pub const JVMTI_TYPE_JCLASS: c_uint = 111;
+pub const JVMTI_TYPE_JVALUE: c_uint = 112;
+pub const JVMTI_TYPE_JFIELDID: c_uint = 113;
+pub const JVMTI_TYPE_JMETHODID: c_uint = 114;
+pub const JVMTI_TYPE_CCHAR: c_uint = 115;
+pub const JVMTI_TYPE_CVOID: c_uint = 116;
+pub const JVMTI_TYPE_JNIENV: c_uint = 117;
+
+pub type jvmtiParamKind = c_uint;
+pub const JVMTI_KIND_IN: c_uint = 91;
+pub const JVMTI_KIND_IN_PTR: c_uint = 92;
+pub const JVMTI_KIND_IN_BUF: c_uint = 93;
+pub const JVMTI_KIND_ALLOC_BUF: c_uint = 94;
+pub const JVMTI_KIND_ALLOC_ALLOC_BUF: c_uint = 95;
+pub const JVMTI_KIND_OUT: c_uint = 96;
+pub const JVMTI_KIND_OUT_BUF: c_uint = 97;
+
+pub type jvmtiTimerKind = c_uint;
+pub const JVMTI_TIMER_USER_CPU: c_uint = 30;
+pub const JVMTI_TIMER_TOTAL_CPU: c_uint = 31;
+pub const JVMTI_TIMER_ELAPSED: c_uint = 32;
+
+pub type jvmtiPhase = c_uint;
+pub const JVMTI_PHASE_ONLOAD: c_uint = 1;
+pub const JVMTI_PHASE_PRIMORDIAL: c_uint = 2;
+pub const JVMTI_PHASE_START: c_uint = 6;
+pub const JVMTI_PHASE_LIVE: c_uint = 4;
+pub const JVMTI_PHASE_DEAD: c_uint = 8;
+
+pub type Enum_jvmtiVersionInterfaceTypes = c_uint;
+pub const JVMTI_VERSION_INTERFACE_JNI: c_uint = 0x00000000;
+pub const JVMTI_VERSION_INTERFACE_JVMTI: c_uint = 0x30000000;
+
+pub type Enum_jvmtiVersionMasks = c_uint;
+pub const JVMTI_VERSION_MASK_INTERFACE_TYPE: c_uint = 0x70000000;
+pub const JVMTI_VERSION_MASK_MAJOR: c_uint = 0x0FFF0000;
+pub const JVMTI_VERSION_MASK_MINOR: c_uint = 0x0000FF00;
+pub const JVMTI_VERSION_MASK_MICRO: c_uint = 0x000000FF;
+
+pub type Enum_jvmtiVersionShifts = c_uint;
+pub const JVMTI_VERSION_SHIFT_MAJOR: c_uint = 16;
+pub const JVMTI_VERSION_SHIFT_MINOR: c_uint = 8;
+pub const JVMTI_VERSION_SHIFT_MICRO: c_uint = 0;
+
+pub type jvmtiVerboseFlag = c_uint;
+pub const JVMTI_VERBOSE_OTHER: c_uint = 0;
+pub const JVMTI_VERBOSE_GC: c_uint = 1;
+pub const JVMTI_VERBOSE_CLASS: c_uint = 2;
+pub const JVMTI_VERBOSE_JNI: c_uint = 4;
+
+pub type jvmtiJlocationFormat = c_uint;
+pub const JVMTI_JLOCATION_JVMBCI: c_uint = 1;
+pub const JVMTI_JLOCATION_MACHINEPC: c_uint = 2;
+pub const JVMTI_JLOCATION_OTHER: c_uint = 0;
+
+pub type Enum_jvmtiResourceExhaustionFlags = c_uint;
+pub const JVMTI_RESOURCE_EXHAUSTED_OOM_ERROR: c_uint = 1;
+pub const JVMTI_RESOURCE_EXHAUSTED_JAVA_HEAP: c_uint = 2;
+pub const JVMTI_RESOURCE_EXHAUSTED_THREADS: c_uint = 4;
+
+pub type jvmtiError = c_uint;
+pub const JVMTI_ERROR_NONE: c_uint = 0;
+pub const JVMTI_ERROR_INVALID_THREAD: c_uint = 10;
+pub const JVMTI_ERROR_INVALID_THREAD_GROUP: c_uint = 11;
+pub const JVMTI_ERROR_INVALID_PRIORITY: c_uint = 12;
+pub const JVMTI_ERROR_THREAD_NOT_SUSPENDED: c_uint = 13;
+pub const JVMTI_ERROR_THREAD_SUSPENDED: c_uint = 14;
+pub const JVMTI_ERROR_THREAD_NOT_ALIVE: c_uint = 15;
+pub const JVMTI_ERROR_INVALID_OBJECT: c_uint = 20;
+pub const JVMTI_ERROR_INVALID_CLASS: c_uint = 21;
+pub const JVMTI_ERROR_CLASS_NOT_PREPARED: c_uint = 22;
+pub const JVMTI_ERROR_INVALID_METHODID: c_uint = 23;
+pub const JVMTI_ERROR_INVALID_LOCATION: c_uint = 24;
+pub const JVMTI_ERROR_INVALID_FIELDID: c_uint = 25;
+pub const JVMTI_ERROR_INVALID_MODULE: c_uint = 26;
+pub const JVMTI_ERROR_NO_MORE_FRAMES: c_uint = 31;
+pub const JVMTI_ERROR_OPAQUE_FRAME: c_uint = 32;
+pub const JVMTI_ERROR_TYPE_MISMATCH: c_uint = 34;
+pub const JVMTI_ERROR_INVALID_SLOT: c_uint = 35;
+pub const JVMTI_ERROR_DUPLICATE: c_uint = 40;
+pub const JVMTI_ERROR_NOT_FOUND: c_uint = 41;
+pub const JVMTI_ERROR_INVALID_MONITOR: c_uint = 50;
+pub const JVMTI_ERROR_NOT_MONITOR_OWNER: c_uint = 51;
+pub const JVMTI_ERROR_INTERRUPT: c_uint = 52;
+pub const JVMTI_ERROR_INVALID_CLASS_FORMAT: c_uint = 60;
+pub const JVMTI_ERROR_CIRCULAR_CLASS_DEFINITION: c_uint = 61;
+pub const JVMTI_ERROR_FAILS_VERIFICATION: c_uint = 62;
+pub const JVMTI_ERROR_UNSUPPORTED_REDEFINITION_METHOD_ADDED: c_uint = 63;
+pub const JVMTI_ERROR_UNSUPPORTED_REDEFINITION_SCHEMA_CHANGED: c_uint = 64;
+pub const JVMTI_ERROR_INVALID_TYPESTATE: c_uint = 65;
+pub const JVMTI_ERROR_UNSUPPORTED_REDEFINITION_HIERARCHY_CHANGED: c_uint = 66;
+pub const JVMTI_ERROR_UNSUPPORTED_REDEFINITION_METHOD_DELETED: c_uint = 67;
+pub const JVMTI_ERROR_UNSUPPORTED_VERSION: c_uint = 68;
+pub const JVMTI_ERROR_NAMES_DONT_MATCH: c_uint = 69;
+pub const JVMTI_ERROR_UNSUPPORTED_REDEFINITION_CLASS_MODIFIERS_CHANGED: c_uint = 70;
+pub const JVMTI_ERROR_UNSUPPORTED_REDEFINITION_METHOD_MODIFIERS_CHANGED: c_uint = 71;
+pub const JVMTI_ERROR_UNSUPPORTED_REDEFINITION_CLASS_ATTRIBUTE_CHANGED: c_uint = 72;
+pub const JVMTI_ERROR_UNMODIFIABLE_CLASS: c_uint = 79;
+pub const JVMTI_ERROR_UNMODIFIABLE_MODULE: c_uint = 80;
+pub const JVMTI_ERROR_NOT_AVAILABLE: c_uint = 98;
+pub const JVMTI_ERROR_MUST_POSSESS_CAPABILITY: c_uint = 99;
+pub const JVMTI_ERROR_NULL_POINTER: c_uint = 100;
+pub const JVMTI_ERROR_ABSENT_INFORMATION: c_uint = 101;
+pub const JVMTI_ERROR_INVALID_EVENT_TYPE: c_uint = 102;
+pub const JVMTI_ERROR_ILLEGAL_ARGUMENT: c_uint = 103;
+pub const JVMTI_ERROR_NATIVE_METHOD: c_uint = 104;
+pub const JVMTI_ERROR_CLASS_LOADER_UNSUPPORTED: c_uint = 106;
+pub const JVMTI_ERROR_OUT_OF_MEMORY: c_uint = 110;
+pub const JVMTI_ERROR_ACCESS_DENIED: c_uint = 111;
+pub const JVMTI_ERROR_WRONG_PHASE: c_uint = 112;
+pub const JVMTI_ERROR_INTERNAL: c_uint = 113;
+pub const JVMTI_ERROR_UNATTACHED_THREAD: c_uint = 115;
+pub const JVMTI_ERROR_INVALID_ENVIRONMENT: c_uint = 116;
+pub const JVMTI_ERROR_MAX: c_uint = 116;
+
+pub type jvmtiEvent = c_uint;
+pub const JVMTI_MIN_EVENT_TYPE_VAL: c_uint = 50;
+pub const JVMTI_EVENT_VM_INIT: c_uint = 50;
+pub const JVMTI_EVENT_VM_DEATH: c_uint = 51;
+pub const JVMTI_EVENT_THREAD_START: c_uint = 52;
+pub const JVMTI_EVENT_THREAD_END: c_uint = 53;
+pub const JVMTI_EVENT_CLASS_FILE_LOAD_HOOK: c_uint = 54;
+pub const JVMTI_EVENT_CLASS_LOAD: c_uint = 55;
+pub const JVMTI_EVENT_CLASS_PREPARE: c_uint = 56;
+pub const JVMTI_EVENT_VM_START: c_uint = 57;
+pub const JVMTI_EVENT_EXCEPTION: c_uint = 58;
+pub const JVMTI_EVENT_EXCEPTION_CATCH: c_uint = 59;
+pub const JVMTI_EVENT_SINGLE_STEP: c_uint = 60;
+pub const JVMTI_EVENT_FRAME_POP: c_uint = 61;
+pub const JVMTI_EVENT_BREAKPOINT: c_uint = 62;
+pub const JVMTI_EVENT_FIELD_ACCESS: c_uint = 63;
+pub const JVMTI_EVENT_FIELD_MODIFICATION: c_uint = 64;
+pub const JVMTI_EVENT_METHOD_ENTRY: c_uint = 65;
+pub const JVMTI_EVENT_METHOD_EXIT: c_uint = 66;
+pub const JVMTI_EVENT_NATIVE_METHOD_BIND: c_uint = 67;
+pub const JVMTI_EVENT_COMPILED_METHOD_LOAD: c_uint = 68;
+pub const JVMTI_EVENT_COMPILED_METHOD_UNLOAD: c_uint = 69;
+pub const JVMTI_EVENT_DYNAMIC_CODE_GENERATED: c_uint = 70;
+pub const JVMTI_EVENT_DATA_DUMP_REQUEST: c_uint = 71;
+pub const JVMTI_EVENT_MONITOR_WAIT: c_uint = 73;
+pub const JVMTI_EVENT_MONITOR_WAITED: c_uint = 74;
+pub const JVMTI_EVENT_MONITOR_CONTENDED_ENTER: c_uint = 75;
+pub const JVMTI_EVENT_MONITOR_CONTENDED_ENTERED: c_uint = 76;
+pub const JVMTI_EVENT_RESOURCE_EXHAUSTED: c_uint = 80;
+pub const JVMTI_EVENT_GARBAGE_COLLECTION_START: c_uint = 81;
+pub const JVMTI_EVENT_GARBAGE_COLLECTION_FINISH: c_uint = 82;
+pub const JVMTI_EVENT_OBJECT_FREE: c_uint = 83;
+pub const JVMTI_EVENT_VM_OBJECT_ALLOC: c_uint = 84;
+pub const JVMTI_EVENT_SAMPLED_OBJECT_ALLOC: c_uint = 86;
+pub const JVMTI_MAX_EVENT_TYPE_VAL: c_uint = 86;
+
+pub type jvmtiThreadInfo = Struct__jvmtiThreadInfo;
+pub type jvmtiMonitorStackDepthInfo = Struct__jvmtiMonitorStackDepthInfo;
+pub type jvmtiThreadGroupInfo = Struct__jvmtiThreadGroupInfo;
+pub type jvmtiFrameInfo = Struct__jvmtiFrameInfo;
+pub type jvmtiStackInfo = Struct__jvmtiStackInfo;
+pub type jvmtiHeapReferenceInfoField = Struct__jvmtiHeapReferenceInfoField;
+pub type jvmtiHeapReferenceInfoArray = Struct__jvmtiHeapReferenceInfoArray;
+pub type jvmtiHeapReferenceInfoConstantPool = Struct__jvmtiHeapReferenceInfoConstantPool;
+pub type jvmtiHeapReferenceInfoStackLocal = Struct__jvmtiHeapReferenceInfoStackLocal;
+pub type jvmtiHeapReferenceInfoJniLocal = Struct__jvmtiHeapReferenceInfoJniLocal;
+pub type jvmtiHeapReferenceInfoReserved = Struct__jvmtiHeapReferenceInfoReserved;
+pub type jvmtiHeapReferenceInfo = Union__jvmtiHeapReferenceInfo;
+pub type jvmtiHeapCallbacks = Struct__jvmtiHeapCallbacks;
+pub type jvmtiClassDefinition = Struct__jvmtiClassDefinition;
+pub type jvmtiMonitorUsage = Struct__jvmtiMonitorUsage;
+pub type jvmtiLineNumberEntry = Struct__jvmtiLineNumberEntry;
+pub type jvmtiLocalVariableEntry = Struct__jvmtiLocalVariableEntry;
+pub type jvmtiParamInfo = Struct__jvmtiParamInfo;
+pub type jvmtiExtensionFunctionInfo = Struct__jvmtiExtensionFunctionInfo;
+pub type jvmtiExtensionEventInfo = Struct__jvmtiExtensionEventInfo;
+pub type jvmtiTimerInfo = Struct__jvmtiTimerInfo;
+pub type jvmtiAddrLocationMap = Struct__jvmtiAddrLocationMap;
+pub type jvmtiCapabilities = Struct__jvmtiCapabilities;
+pub type jvmtiEventCallbacks = Struct__jvmtiEventCallbacks;
+pub type jvmtiInterface_1 = Struct_jvmtiInterface_1_;
+
+pub type jvmtiStartFunction =
+    Option<unsafe extern "C" fn(jvmti_env: *mut jvmtiEnv, jni_env: *mut JNIEnv, arg: *mut c_void) -> ()>;
+pub type jvmtiHeapIterationCallback =
+    Option<unsafe extern "C" fn(class_tag: jlong, size: jlong, tag_ptr: *mut jlong, length: jint, user_data: *mut c_void) -> jint>;
+pub type jvmtiHeapReferenceCallback =
+    Option<unsafe extern "C" fn(reference_kind: jvmtiHeapReferenceKind, reference_info: *const jvmtiHeapReferenceInfo, class_tag: jlong, referrer_class_tag: jlong, size: jlong, tag_ptr: *mut jlong, referrer_tag_ptr: *mut jlong, length: jint, user_data: *mut c_void) -> jint>;
+pub type jvmtiPrimitiveFieldCallback =
+    Option<unsafe extern "C" fn(kind: jvmtiHeapReferenceKind, info: *const jvmtiHeapReferenceInfo, object_class_tag: jlong, object_tag_ptr: *mut jlong, value: jvalue, value_type: jvmtiPrimitiveType, user_data: *mut c_void) -> jint>;
+pub type jvmtiArrayPrimitiveValueCallback =
+    Option<unsafe extern "C" fn(class_tag: jlong, size: jlong, tag_ptr: *mut jlong, element_count: jint, element_type: jvmtiPrimitiveType, elements: *const c_void, user_data: *mut c_void) -> jint>;
+pub type jvmtiStringPrimitiveValueCallback =
+    Option<unsafe extern "C" fn(class_tag: jlong, size: jlong, tag_ptr: *mut jlong, value: *const jchar, value_length: jint, user_data: *mut c_void) -> jint>;
+pub type jvmtiReservedCallback =
+    Option<extern "C" fn() -> jint>;
+pub type jvmtiHeapObjectCallback =
+    Option<unsafe extern "C" fn(class_tag: jlong, size: jlong, tag_ptr: *mut jlong, user_data: *mut c_void) -> jvmtiIterationControl>;
+pub type jvmtiHeapRootCallback =
+    Option<unsafe extern "C" fn(root_kind: jvmtiHeapRootKind, class_tag: jlong, size: jlong, tag_ptr: *mut jlong, user_data: *mut c_void) -> jvmtiIterationControl>;
+pub type jvmtiStackReferenceCallback =
+    Option<unsafe extern "C" fn(root_kind: jvmtiHeapRootKind, class_tag: jlong, size: jlong, tag_ptr: *mut jlong, thread_tag: jlong, depth: jint, method: jmethodID, slot: jint, user_data: *mut c_void) -> jvmtiIterationControl>;
+pub type jvmtiObjectReferenceCallback =
+    Option<unsafe extern "C" fn(reference_kind: jvmtiObjectReferenceKind, class_tag: jlong, size: jlong, tag_ptr: *mut jlong, referrer_tag: jlong, referrer_index: jint, user_data: *mut c_void) -> jvmtiIterationControl>;
+pub type jvmtiExtensionFunction =
+    Option<unsafe extern "C" fn(jvmti_env: *mut jvmtiEnv, ...) -> jvmtiError>;
+pub type jvmtiExtensionEvent =
+    Option<unsafe extern "C" fn(jvmti_env: *mut jvmtiEnv, ...) -> ()>;
+#[repr(C)]
+#[derive(Copy)]
+pub struct Struct__jvmtiThreadInfo {
+    pub name: *mut c_char,
+    pub priority: jint,
+    pub is_daemon: jboolean,
+    pub thread_group: jthreadGroup,
+    pub context_class_loader: jobject,
+}
+impl ::std::clone::Clone for Struct__jvmtiThreadInfo {
+    fn clone(&self) -> Self { *self }
+}
+impl ::std::default::Default for Struct__jvmtiThreadInfo {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+#[repr(C)]
+#[derive(Copy)]
+pub struct Struct__jvmtiMonitorStackDepthInfo {
+    pub monitor: jobject,
+    pub stack_depth: jint,
+}
+impl ::std::clone::Clone for Struct__jvmtiMonitorStackDepthInfo {
+    fn clone(&self) -> Self { *self }
+}
+impl ::std::default::Default for Struct__jvmtiMonitorStackDepthInfo {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+#[repr(C)]
+#[derive(Copy)]
+pub struct Struct__jvmtiThreadGroupInfo {
+    pub parent: jthreadGroup,
+    pub name: *mut c_char,
+    pub max_priority: jint,
+    pub is_daemon: jboolean,
+}
+impl ::std::clone::Clone for Struct__jvmtiThreadGroupInfo {
+    fn clone(&self) -> Self { *self }
+}
+impl ::std::default::Default for Struct__jvmtiThreadGroupInfo {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+#[repr(C)]
+#[derive(Copy)]
+pub struct Struct__jvmtiFrameInfo {
+    pub method: jmethodID,
+    pub location: jlocation,
+}
+impl ::std::clone::Clone for Struct__jvmtiFrameInfo {
+    fn clone(&self) -> Self { *self }
+}
+impl ::std::default::Default for Struct__jvmtiFrameInfo {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+#[repr(C)]
+#[derive(Copy)]
+pub struct Struct__jvmtiStackInfo {
+    pub thread: jthread,
+    pub state: jint,
+    pub frame_buffer: *mut jvmtiFrameInfo,
+    pub frame_count: jint,
+}
+impl ::std::clone::Clone for Struct__jvmtiStackInfo {
+    fn clone(&self) -> Self { *self }
+}
+impl ::std::default::Default for Struct__jvmtiStackInfo {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+#[repr(C)]
+#[derive(Copy)]
+pub struct Struct__jvmtiHeapReferenceInfoField {
+    pub index: jint,
+}
+impl ::std::clone::Clone for Struct__jvmtiHeapReferenceInfoField {
+    fn clone(&self) -> Self { *self }
+}
+impl ::std::default::Default for Struct__jvmtiHeapReferenceInfoField {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+#[repr(C)]
+#[derive(Copy)]
+pub struct Struct__jvmtiHeapReferenceInfoArray {
+    pub index: jint,
+}
+impl ::std::clone::Clone for Struct__jvmtiHeapReferenceInfoArray {
+    fn clone(&self) -> Self { *self }
+}
+impl ::std::default::Default for Struct__jvmtiHeapReferenceInfoArray {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+#[repr(C)]
+#[derive(Copy)]
+pub struct Struct__jvmtiHeapReferenceInfoConstantPool {
+    pub index: jint,
+}
+impl ::std::clone::Clone for Struct__jvmtiHeapReferenceInfoConstantPool {
+    fn clone(&self) -> Self { *self }
+}
+impl ::std::default::Default for Struct__jvmtiHeapReferenceInfoConstantPool {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+#[repr(C)]
+#[derive(Copy)]
+pub struct Struct__jvmtiHeapReferenceInfoStackLocal {
+    pub thread_tag: jlong,
+    pub thread_id: jlong,
+    pub depth: jint,
+    pub method: jmethodID,
+    pub location: jlocation,
+    pub slot: jint,
+}
+impl ::std::clone::Clone for Struct__jvmtiHeapReferenceInfoStackLocal {
+    fn clone(&self) -> Self { *self }
+}
+impl ::std::default::Default for Struct__jvmtiHeapReferenceInfoStackLocal {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+#[repr(C)]
+#[derive(Copy)]
+pub struct Struct__jvmtiHeapReferenceInfoJniLocal {
+    pub thread_tag: jlong,
+    pub thread_id: jlong,
+    pub depth: jint,
+    pub method: jmethodID,
+}
+impl ::std::clone::Clone for Struct__jvmtiHeapReferenceInfoJniLocal {
+    fn clone(&self) -> Self { *self }
+}
+impl ::std::default::Default for Struct__jvmtiHeapReferenceInfoJniLocal {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+#[repr(C)]
+#[derive(Copy)]
+pub struct Struct__jvmtiHeapReferenceInfoReserved {
+    pub reserved1: jlong,
+    pub reserved2: jlong,
+    pub reserved3: jlong,
+    pub reserved4: jlong,
+    pub reserved5: jlong,
+    pub reserved6: jlong,
+    pub reserved7: jlong,
+    pub reserved8: jlong,
+}
+impl ::std::clone::Clone for Struct__jvmtiHeapReferenceInfoReserved {
+    fn clone(&self) -> Self { *self }
+}
+impl ::std::default::Default for Struct__jvmtiHeapReferenceInfoReserved {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+#[repr(C)]
+#[derive(Copy)]
+pub union Union__jvmtiHeapReferenceInfo {
+    pub field: jvmtiHeapReferenceInfoField,
+    pub array: jvmtiHeapReferenceInfoArray,
+    pub constant_pool: jvmtiHeapReferenceInfoConstantPool,
+    pub stack_local: jvmtiHeapReferenceInfoStackLocal,
+    pub jni_local: jvmtiHeapReferenceInfoJniLocal,
+    pub other: jvmtiHeapReferenceInfoReserved,
+}
+impl ::std::clone::Clone for Union__jvmtiHeapReferenceInfo {
+    fn clone(&self) -> Self { *self }
+}
+impl ::std::default::Default for Union__jvmtiHeapReferenceInfo {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+#[repr(C)]
+#[derive(Copy)]
+pub struct Struct__jvmtiHeapCallbacks {
+    pub heap_iteration_callback: jvmtiHeapIterationCallback,
+    pub heap_reference_callback: jvmtiHeapReferenceCallback,
+    pub primitive_field_callback: jvmtiPrimitiveFieldCallback,
+    pub array_primitive_value_callback: jvmtiArrayPrimitiveValueCallback,
+    pub string_primitive_value_callback: jvmtiStringPrimitiveValueCallback,
+    pub reserved5: jvmtiReservedCallback,
+    pub reserved6: jvmtiReservedCallback,
+    pub reserved7: jvmtiReservedCallback,
+    pub reserved8: jvmtiReservedCallback,
+    pub reserved9: jvmtiReservedCallback,
+    pub reserved10: jvmtiReservedCallback,
+    pub reserved11: jvmtiReservedCallback,
+    pub reserved12: jvmtiReservedCallback,
+    pub reserved13: jvmtiReservedCallback,
+    pub reserved14: jvmtiReservedCallback,
+    pub reserved15: jvmtiReservedCallback,
+}
+impl ::std::clone::Clone for Struct__jvmtiHeapCallbacks {
+    fn clone(&self) -> Self { *self }
+}
+impl ::std::default::Default for Struct__jvmtiHeapCallbacks {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+#[repr(C)]
+#[derive(Copy)]
+pub struct Struct__jvmtiClassDefinition {
+    pub klass: jclass,
+    pub class_byte_count: jint,
+    pub class_bytes: *const c_uchar,
+}
+impl ::std::clone::Clone for Struct__jvmtiClassDefinition {
+    fn clone(&self) -> Self { *self }
+}
+impl ::std::default::Default for Struct__jvmtiClassDefinition {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+#[repr(C)]
+#[derive(Copy)]
+pub struct Struct__jvmtiMonitorUsage {
+    pub owner: jthread,
+    pub entry_count: jint,
+    pub waiter_count: jint,
+    pub waiters: *mut jthread,
+    pub notify_waiter_count: jint,
+    pub notify_waiters: *mut jthread,
+}
+impl ::std::clone::Clone for Struct__jvmtiMonitorUsage {
+    fn clone(&self) -> Self { *self }
+}
+impl ::std::default::Default for Struct__jvmtiMonitorUsage {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+#[repr(C)]
+#[derive(Copy)]
+pub struct Struct__jvmtiLineNumberEntry {
+    pub start_location: jlocation,
+    pub line_number: jint,
+}
+impl ::std::clone::Clone for Struct__jvmtiLineNumberEntry {
+    fn clone(&self) -> Self { *self }
+}
+impl ::std::default::Default for Struct__jvmtiLineNumberEntry {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+#[repr(C)]
+#[derive(Copy)]
+pub struct Struct__jvmtiLocalVariableEntry {
+    pub start_location: jlocation,
+    pub length: jint,
+    pub name: *mut c_char,
+    pub signature: *mut c_char,
+    pub generic_signature: *mut c_char,
+    pub slot: jint,
+}
+impl ::std::clone::Clone for Struct__jvmtiLocalVariableEntry {
+    fn clone(&self) -> Self { *self }
+}
+impl ::std::default::Default for Struct__jvmtiLocalVariableEntry {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+#[repr(C)]
+#[derive(Copy)]
+pub struct Struct__jvmtiParamInfo {
+    pub name: *mut c_char,
+    pub kind: jvmtiParamKind,
+    pub base_type: jvmtiParamTypes,
+    pub null_ok: jboolean,
+}
+impl ::std::clone::Clone for Struct__jvmtiParamInfo {
+    fn clone(&self) -> Self { *self }
+}
+impl ::std::default::Default for Struct__jvmtiParamInfo {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+#[repr(C)]
+#[derive(Copy)]
+pub struct Struct__jvmtiExtensionFunctionInfo {
+    pub func: jvmtiExtensionFunction,
+    pub id: *mut c_char,
+    pub short_description: *mut c_char,
+    pub param_count: jint,
+    pub params: *mut jvmtiParamInfo,
+    pub error_count: jint,
+    pub errors: *mut jvmtiError,
+}
+impl ::std::clone::Clone for Struct__jvmtiExtensionFunctionInfo {
+    fn clone(&self) -> Self { *self }
+}
+impl ::std::default::Default for Struct__jvmtiExtensionFunctionInfo {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+#[repr(C)]
+#[derive(Copy)]
+pub struct Struct__jvmtiExtensionEventInfo {
+    pub extension_event_index: jint,
+    pub id: *mut c_char,
+    pub short_description: *mut c_char,
+    pub param_count: jint,
+    pub params: *mut jvmtiParamInfo,
+}
+impl ::std::clone::Clone for Struct__jvmtiExtensionEventInfo {
+    fn clone(&self) -> Self { *self }
+}
+impl ::std::default::Default for Struct__jvmtiExtensionEventInfo {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+#[repr(C)]
+#[derive(Copy)]
+pub struct Struct__jvmtiTimerInfo {
+    pub max_value: jlong,
+    pub may_skip_forward: jboolean,
+    pub may_skip_backward: jboolean,
+    pub kind: jvmtiTimerKind,
+    pub reserved1: jlong,
+    pub reserved2: jlong,
+}
+impl ::std::clone::Clone for Struct__jvmtiTimerInfo {
+    fn clone(&self) -> Self { *self }
+}
+impl ::std::default::Default for Struct__jvmtiTimerInfo {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+#[repr(C)]
+#[derive(Copy)]
+pub struct Struct__jvmtiAddrLocationMap {
+    pub start_address: *const c_void,
+    pub location: jlocation,
+}
+impl ::std::clone::Clone for Struct__jvmtiAddrLocationMap {
+    fn clone(&self) -> Self { *self }
+}
+impl ::std::default::Default for Struct__jvmtiAddrLocationMap {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+/// The capabilities are stored as C bitfields. Both supported targets are little-endian and
+/// allocate bitfields starting from the least significant bit, so the first capability
+/// (`can_tag_objects`) is bit 0 of `_bindgen_bitfield_1_` and the 33rd
+/// (`can_generate_object_free_events`) is bit 0 of `_bindgen_bitfield_2_`.
+#[repr(C)]
+#[derive(Copy)]
+pub struct Struct__jvmtiCapabilities {
+    pub _bindgen_bitfield_1_: c_uint,
+    pub _bindgen_bitfield_2_: c_uint,
+    pub _bindgen_bitfield_3_: c_uint,
+    pub _bindgen_bitfield_4_: c_uint,
+}
+impl ::std::clone::Clone for Struct__jvmtiCapabilities {
+    fn clone(&self) -> Self { *self }
+}
+impl ::std::default::Default for Struct__jvmtiCapabilities {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+
+pub type jvmtiEventReserved = Option<extern "C" fn() -> ()>;
+pub type jvmtiEventBreakpoint =
+    Option<unsafe extern "C" fn(jvmti_env: *mut jvmtiEnv, jni_env: *mut JNIEnv, thread: jthread, method: jmethodID, location: jlocation) -> ()>;
+pub type jvmtiEventClassFileLoadHook =
+    Option<unsafe extern "C" fn(jvmti_env: *mut jvmtiEnv, jni_env: *mut JNIEnv, class_being_redefined: jclass, loader: jobject, name: *const c_char, protection_domain: jobject, class_data_len: jint, class_data: *const c_uchar, new_class_data_len: *mut jint, new_class_data: *mut *mut c_uchar) -> ()>;
+pub type jvmtiEventClassLoad =
+    Option<unsafe extern "C" fn(jvmti_env: *mut jvmtiEnv, jni_env: *mut JNIEnv, thread: jthread, klass: jclass) -> ()>;
+pub type jvmtiEventClassPrepare =
+    Option<unsafe extern "C" fn(jvmti_env: *mut jvmtiEnv, jni_env: *mut JNIEnv, thread: jthread, klass: jclass) -> ()>;
+pub type jvmtiEventCompiledMethodLoad =
+    Option<unsafe extern "C" fn(jvmti_env: *mut jvmtiEnv, method: jmethodID, code_size: jint, code_addr: *const c_void, map_length: jint, map: *const jvmtiAddrLocationMap, compile_info: *const c_void) -> ()>;
+pub type jvmtiEventCompiledMethodUnload =
+    Option<unsafe extern "C" fn(jvmti_env: *mut jvmtiEnv, method: jmethodID, code_addr: *const c_void) -> ()>;
+pub type jvmtiEventDataDumpRequest =
+    Option<unsafe extern "C" fn(jvmti_env: *mut jvmtiEnv) -> ()>;
+pub type jvmtiEventDynamicCodeGenerated =
+    Option<unsafe extern "C" fn(jvmti_env: *mut jvmtiEnv, name: *const c_char, address: *const c_void, length: jint) -> ()>;
+pub type jvmtiEventException =
+    Option<unsafe extern "C" fn(jvmti_env: *mut jvmtiEnv, jni_env: *mut JNIEnv, thread: jthread, method: jmethodID, location: jlocation, exception: jobject, catch_method: jmethodID, catch_location: jlocation) -> ()>;
+pub type jvmtiEventExceptionCatch =
+    Option<unsafe extern "C" fn(jvmti_env: *mut jvmtiEnv, jni_env: *mut JNIEnv, thread: jthread, method: jmethodID, location: jlocation, exception: jobject) -> ()>;
+pub type jvmtiEventFieldAccess =
+    Option<unsafe extern "C" fn(jvmti_env: *mut jvmtiEnv, jni_env: *mut JNIEnv, thread: jthread, method: jmethodID, location: jlocation, field_klass: jclass, object: jobject, field: jfieldID) -> ()>;
+pub type jvmtiEventFieldModification =
+    Option<unsafe extern "C" fn(jvmti_env: *mut jvmtiEnv, jni_env: *mut JNIEnv, thread: jthread, method: jmethodID, location: jlocation, field_klass: jclass, object: jobject, field: jfieldID, signature_type: c_char, new_value: jvalue) -> ()>;
+pub type jvmtiEventFramePop =
+    Option<unsafe extern "C" fn(jvmti_env: *mut jvmtiEnv, jni_env: *mut JNIEnv, thread: jthread, method: jmethodID, was_popped_by_exception: jboolean) -> ()>;
+pub type jvmtiEventGarbageCollectionFinish =
+    Option<unsafe extern "C" fn(jvmti_env: *mut jvmtiEnv) -> ()>;
+pub type jvmtiEventGarbageCollectionStart =
+    Option<unsafe extern "C" fn(jvmti_env: *mut jvmtiEnv) -> ()>;
+pub type jvmtiEventMethodEntry =
+    Option<unsafe extern "C" fn(jvmti_env: *mut jvmtiEnv, jni_env: *mut JNIEnv, thread: jthread, method: jmethodID) -> ()>;
+pub type jvmtiEventMethodExit =
+    Option<unsafe extern "C" fn(jvmti_env: *mut jvmtiEnv, jni_env: *mut JNIEnv, thread: jthread, method: jmethodID, was_popped_by_exception: jboolean, return_value: jvalue) -> ()>;
+pub type jvmtiEventMonitorContendedEnter =
+    Option<unsafe extern "C" fn(jvmti_env: *mut jvmtiEnv, jni_env: *mut JNIEnv, thread: jthread, object: jobject) -> ()>;
+pub type jvmtiEventMonitorContendedEntered =
+    Option<unsafe extern "C" fn(jvmti_env: *mut jvmtiEnv, jni_env: *mut JNIEnv, thread: jthread, object: jobject) -> ()>;
+pub type jvmtiEventMonitorWait =
+    Option<unsafe extern "C" fn(jvmti_env: *mut jvmtiEnv, jni_env: *mut JNIEnv, thread: jthread, object: jobject, timeout: jlong) -> ()>;
+pub type jvmtiEventMonitorWaited =
+    Option<unsafe extern "C" fn(jvmti_env: *mut jvmtiEnv, jni_env: *mut JNIEnv, thread: jthread, object: jobject, timed_out: jboolean) -> ()>;
+pub type jvmtiEventNativeMethodBind =
+    Option<unsafe extern "C" fn(jvmti_env: *mut jvmtiEnv, jni_env: *mut JNIEnv, thread: jthread, method: jmethodID, address: *mut c_void, new_address_ptr: *mut *mut c_void) -> ()>;
+pub type jvmtiEventObjectFree =
+    Option<unsafe extern "C" fn(jvmti_env: *mut jvmtiEnv, tag: jlong) -> ()>;
+pub type jvmtiEventResourceExhausted =
+    Option<unsafe extern "C" fn(jvmti_env: *mut jvmtiEnv, jni_env: *mut JNIEnv, flags: jint, reserved: *const c_void, description: *const c_char) -> ()>;
+pub type jvmtiEventSingleStep =
+    Option<unsafe extern "C" fn(jvmti_env: *mut jvmtiEnv, jni_env: *mut JNIEnv, thread: jthread, method: jmethodID, location: jlocation) -> ()>;
+pub type jvmtiEventThreadEnd =
+    Option<unsafe extern "C" fn(jvmti_env: *mut jvmtiEnv, jni_env: *mut JNIEnv, thread: jthread) -> ()>;
+pub type jvmtiEventThreadStart =
+    Option<unsafe extern "C" fn(jvmti_env: *mut jvmtiEnv, jni_env: *mut JNIEnv, thread: jthread) -> ()>;
+pub type jvmtiEventVMDeath =
+    Option<unsafe extern "C" fn(jvmti_env: *mut jvmtiEnv, jni_env: *mut JNIEnv) -> ()>;
+pub type jvmtiEventVMInit =
+    Option<unsafe extern "C" fn(jvmti_env: *mut jvmtiEnv, jni_env: *mut JNIEnv, thread: jthread) -> ()>;
+pub type jvmtiEventVMObjectAlloc =
+    Option<unsafe extern "C" fn(jvmti_env: *mut jvmtiEnv, jni_env: *mut JNIEnv, thread: jthread, object: jobject, object_klass: jclass, size: jlong) -> ()>;
+pub type jvmtiEventVMStart =
+    Option<unsafe extern "C" fn(jvmti_env: *mut jvmtiEnv, jni_env: *mut JNIEnv) -> ()>;
+pub type jvmtiEventSampledObjectAlloc =
+    Option<unsafe extern "C" fn(jvmti_env: *mut jvmtiEnv, jni_env: *mut JNIEnv, thread: jthread, object: jobject, object_klass: jclass, size: jlong) -> ()>;
+
+#[repr(C)]
+#[derive(Copy)]
+pub struct Struct__jvmtiEventCallbacks {
+    pub VMInit: jvmtiEventVMInit,
+    pub VMDeath: jvmtiEventVMDeath,
+    pub ThreadStart: jvmtiEventThreadStart,
+    pub ThreadEnd: jvmtiEventThreadEnd,
+    pub ClassFileLoadHook: jvmtiEventClassFileLoadHook,
+    pub ClassLoad: jvmtiEventClassLoad,
+    pub ClassPrepare: jvmtiEventClassPrepare,
+    pub VMStart: jvmtiEventVMStart,
+    pub Exception: jvmtiEventException,
+    pub ExceptionCatch: jvmtiEventExceptionCatch,
+    pub SingleStep: jvmtiEventSingleStep,
+    pub FramePop: jvmtiEventFramePop,
+    pub Breakpoint: jvmtiEventBreakpoint,
+    pub FieldAccess: jvmtiEventFieldAccess,
+    pub FieldModification: jvmtiEventFieldModification,
+    pub MethodEntry: jvmtiEventMethodEntry,
+    pub MethodExit: jvmtiEventMethodExit,
+    pub NativeMethodBind: jvmtiEventNativeMethodBind,
+    pub CompiledMethodLoad: jvmtiEventCompiledMethodLoad,
+    pub CompiledMethodUnload: jvmtiEventCompiledMethodUnload,
+    pub DynamicCodeGenerated: jvmtiEventDynamicCodeGenerated,
+    pub DataDumpRequest: jvmtiEventDataDumpRequest,
+    pub reserved72: jvmtiEventReserved,
+    pub MonitorWait: jvmtiEventMonitorWait,
+    pub MonitorWaited: jvmtiEventMonitorWaited,
+    pub MonitorContendedEnter: jvmtiEventMonitorContendedEnter,
+    pub MonitorContendedEntered: jvmtiEventMonitorContendedEntered,
+    pub reserved77: jvmtiEventReserved,
+    pub reserved78: jvmtiEventReserved,
+    pub reserved79: jvmtiEventReserved,
+    pub ResourceExhausted: jvmtiEventResourceExhausted,
+    pub GarbageCollectionStart: jvmtiEventGarbageCollectionStart,
+    pub GarbageCollectionFinish: jvmtiEventGarbageCollectionFinish,
+    pub ObjectFree: jvmtiEventObjectFree,
+    pub VMObjectAlloc: jvmtiEventVMObjectAlloc,
+    pub reserved85: jvmtiEventReserved,
+    pub SampledObjectAlloc: jvmtiEventSampledObjectAlloc,
+}
+impl ::std::clone::Clone for Struct__jvmtiEventCallbacks {
+    fn clone(&self) -> Self { *self }
+}
+impl ::std::default::Default for Struct__jvmtiEventCallbacks {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+#[repr(C)]
+#[derive(Copy)]
+pub struct Struct_jvmtiInterface_1_ {
+    pub reserved1: *mut c_void,
+    pub SetEventNotificationMode: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, mode: jvmtiEventMode, event_type: jvmtiEvent, event_thread: jthread, ...) -> jvmtiError>,
+    pub GetAllModules: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, module_count_ptr: *mut jint, modules_ptr: *mut *mut jobject) -> jvmtiError>,
+    pub GetAllThreads: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, threads_count_ptr: *mut jint, threads_ptr: *mut *mut jthread) -> jvmtiError>,
+    pub SuspendThread: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, thread: jthread) -> jvmtiError>,
+    pub ResumeThread: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, thread: jthread) -> jvmtiError>,
+    pub StopThread: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, thread: jthread, exception: jobject) -> jvmtiError>,
+    pub InterruptThread: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, thread: jthread) -> jvmtiError>,
+    pub GetThreadInfo: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, thread: jthread, info_ptr: *mut jvmtiThreadInfo) -> jvmtiError>,
+    pub GetOwnedMonitorInfo: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, thread: jthread, owned_monitor_count_ptr: *mut jint, owned_monitors_ptr: *mut *mut jobject) -> jvmtiError>,
+    pub GetCurrentContendedMonitor: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, thread: jthread, monitor_ptr: *mut jobject) -> jvmtiError>,
+    pub RunAgentThread: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, thread: jthread, _proc: jvmtiStartFunction, arg: *const c_void, priority: jint) -> jvmtiError>,
+    pub GetTopThreadGroups: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, group_count_ptr: *mut jint, groups_ptr: *mut *mut jthreadGroup) -> jvmtiError>,
+    pub GetThreadGroupInfo: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, group: jthreadGroup, info_ptr: *mut jvmtiThreadGroupInfo) -> jvmtiError>,
+    pub GetThreadGroupChildren: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, group: jthreadGroup, thread_count_ptr: *mut jint, threads_ptr: *mut *mut jthread, group_count_ptr: *mut jint, groups_ptr: *mut *mut jthreadGroup) -> jvmtiError>,
+    pub GetFrameCount: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, thread: jthread, count_ptr: *mut jint) -> jvmtiError>,
+    pub GetThreadState: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, thread: jthread, thread_state_ptr: *mut jint) -> jvmtiError>,
+    pub GetCurrentThread: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, thread_ptr: *mut jthread) -> jvmtiError>,
+    pub GetFrameLocation: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, thread: jthread, depth: jint, method_ptr: *mut jmethodID, location_ptr: *mut jlocation) -> jvmtiError>,
+    pub NotifyFramePop: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, thread: jthread, depth: jint) -> jvmtiError>,
+    pub GetLocalObject: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, thread: jthread, depth: jint, slot: jint, value_ptr: *mut jobject) -> jvmtiError>,
+    pub GetLocalInt: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, thread: jthread, depth: jint, slot: jint, value_ptr: *mut jint) -> jvmtiError>,
+    pub GetLocalLong: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, thread: jthread, depth: jint, slot: jint, value_ptr: *mut jlong) -> jvmtiError>,
+    pub GetLocalFloat: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, thread: jthread, depth: jint, slot: jint, value_ptr: *mut jfloat) -> jvmtiError>,
+    pub GetLocalDouble: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, thread: jthread, depth: jint, slot: jint, value_ptr: *mut jdouble) -> jvmtiError>,
+    pub SetLocalObject: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, thread: jthread, depth: jint, slot: jint, value: jobject) -> jvmtiError>,
+    pub SetLocalInt: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, thread: jthread, depth: jint, slot: jint, value: jint) -> jvmtiError>,
+    pub SetLocalLong: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, thread: jthread, depth: jint, slot: jint, value: jlong) -> jvmtiError>,
+    pub SetLocalFloat: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, thread: jthread, depth: jint, slot: jint, value: jfloat) -> jvmtiError>,
+    pub SetLocalDouble: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, thread: jthread, depth: jint, slot: jint, value: jdouble) -> jvmtiError>,
+    pub CreateRawMonitor: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, name: *const c_char, monitor_ptr: *mut jrawMonitorID) -> jvmtiError>,
+    pub DestroyRawMonitor: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, monitor: jrawMonitorID) -> jvmtiError>,
+    pub RawMonitorEnter: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, monitor: jrawMonitorID) -> jvmtiError>,
+    pub RawMonitorExit: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, monitor: jrawMonitorID) -> jvmtiError>,
+    pub RawMonitorWait: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, monitor: jrawMonitorID, millis: jlong) -> jvmtiError>,
+    pub RawMonitorNotify: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, monitor: jrawMonitorID) -> jvmtiError>,
+    pub RawMonitorNotifyAll: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, monitor: jrawMonitorID) -> jvmtiError>,
+    pub SetBreakpoint: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, method: jmethodID, location: jlocation) -> jvmtiError>,
+    pub ClearBreakpoint: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, method: jmethodID, location: jlocation) -> jvmtiError>,
+    pub GetNamedModule: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, class_loader: jobject, package_name: *const c_char, module_ptr: *mut jobject) -> jvmtiError>,
+    pub SetFieldAccessWatch: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, klass: jclass, field: jfieldID) -> jvmtiError>,
+    pub ClearFieldAccessWatch: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, klass: jclass, field: jfieldID) -> jvmtiError>,
+    pub SetFieldModificationWatch: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, klass: jclass, field: jfieldID) -> jvmtiError>,
+    pub ClearFieldModificationWatch: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, klass: jclass, field: jfieldID) -> jvmtiError>,
+    pub IsModifiableClass: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, klass: jclass, is_modifiable_class_ptr: *mut jboolean) -> jvmtiError>,
+    pub Allocate: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, size: jlong, mem_ptr: *mut *mut c_uchar) -> jvmtiError>,
+    pub Deallocate: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, mem: *mut c_uchar) -> jvmtiError>,
+    pub GetClassSignature: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, klass: jclass, signature_ptr: *mut *mut c_char, generic_ptr: *mut *mut c_char) -> jvmtiError>,
+    pub GetClassStatus: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, klass: jclass, status_ptr: *mut jint) -> jvmtiError>,
+    pub GetSourceFileName: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, klass: jclass, source_name_ptr: *mut *mut c_char) -> jvmtiError>,
+    pub GetClassModifiers: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, klass: jclass, modifiers_ptr: *mut jint) -> jvmtiError>,
+    pub GetClassMethods: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, klass: jclass, method_count_ptr: *mut jint, methods_ptr: *mut *mut jmethodID) -> jvmtiError>,
+    pub GetClassFields: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, klass: jclass, field_count_ptr: *mut jint, fields_ptr: *mut *mut jfieldID) -> jvmtiError>,
+    pub GetImplementedInterfaces: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, klass: jclass, interface_count_ptr: *mut jint, interfaces_ptr: *mut *mut jclass) -> jvmtiError>,
+    pub IsInterface: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, klass: jclass, is_interface_ptr: *mut jboolean) -> jvmtiError>,
+    pub IsArrayClass: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, klass: jclass, is_array_class_ptr: *mut jboolean) -> jvmtiError>,
+    pub GetClassLoader: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, klass: jclass, classloader_ptr: *mut jobject) -> jvmtiError>,
+    pub GetObjectHashCode: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, object: jobject, hash_code_ptr: *mut jint) -> jvmtiError>,
+    pub GetObjectMonitorUsage: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, object: jobject, info_ptr: *mut jvmtiMonitorUsage) -> jvmtiError>,
+    pub GetFieldName: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, klass: jclass, field: jfieldID, name_ptr: *mut *mut c_char, signature_ptr: *mut *mut c_char, generic_ptr: *mut *mut c_char) -> jvmtiError>,
+    pub GetFieldDeclaringClass: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, klass: jclass, field: jfieldID, declaring_class_ptr: *mut jclass) -> jvmtiError>,
+    pub GetFieldModifiers: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, klass: jclass, field: jfieldID, modifiers_ptr: *mut jint) -> jvmtiError>,
+    pub IsFieldSynthetic: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, klass: jclass, field: jfieldID, is_synthetic_ptr: *mut jboolean) -> jvmtiError>,
+    pub GetMethodName: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, method: jmethodID, name_ptr: *mut *mut c_char, signature_ptr: *mut *mut c_char, generic_ptr: *mut *mut c_char) -> jvmtiError>,
+    pub GetMethodDeclaringClass: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, method: jmethodID, declaring_class_ptr: *mut jclass) -> jvmtiError>,
+    pub GetMethodModifiers: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, method: jmethodID, modifiers_ptr: *mut jint) -> jvmtiError>,
+    pub reserved67: *mut c_void,
+    pub GetMaxLocals: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, method: jmethodID, max_ptr: *mut jint) -> jvmtiError>,
+    pub GetArgumentsSize: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, method: jmethodID, size_ptr: *mut jint) -> jvmtiError>,
+    pub GetLineNumberTable: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, method: jmethodID, entry_count_ptr: *mut jint, table_ptr: *mut *mut jvmtiLineNumberEntry) -> jvmtiError>,
+    pub GetMethodLocation: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, method: jmethodID, start_location_ptr: *mut jlocation, end_location_ptr: *mut jlocation) -> jvmtiError>,
+    pub GetLocalVariableTable: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, method: jmethodID, entry_count_ptr: *mut jint, table_ptr: *mut *mut jvmtiLocalVariableEntry) -> jvmtiError>,
+    pub SetNativeMethodPrefix: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, prefix: *const c_char) -> jvmtiError>,
+    pub SetNativeMethodPrefixes: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, prefix_count: jint, prefixes: *mut *mut c_char) -> jvmtiError>,
+    pub GetBytecodes: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, method: jmethodID, bytecode_count_ptr: *mut jint, bytecodes_ptr: *mut *mut c_uchar) -> jvmtiError>,
+    pub IsMethodNative: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, method: jmethodID, is_native_ptr: *mut jboolean) -> jvmtiError>,
+    pub IsMethodSynthetic: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, method: jmethodID, is_synthetic_ptr: *mut jboolean) -> jvmtiError>,
+    pub GetLoadedClasses: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, class_count_ptr: *mut jint, classes_ptr: *mut *mut jclass) -> jvmtiError>,
+    pub GetClassLoaderClasses: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, initiating_loader: jobject, class_count_ptr: *mut jint, classes_ptr: *mut *mut jclass) -> jvmtiError>,
+    pub PopFrame: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, thread: jthread) -> jvmtiError>,
+    pub ForceEarlyReturnObject: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, thread: jthread, value: jobject) -> jvmtiError>,
+    pub ForceEarlyReturnInt: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, thread: jthread, value: jint) -> jvmtiError>,
+    pub ForceEarlyReturnLong: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, thread: jthread, value: jlong) -> jvmtiError>,
+    pub ForceEarlyReturnFloat: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, thread: jthread, value: jfloat) -> jvmtiError>,
+    pub ForceEarlyReturnDouble: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, thread: jthread, value: jdouble) -> jvmtiError>,
+    pub ForceEarlyReturnVoid: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, thread: jthread) -> jvmtiError>,
+    pub RedefineClasses: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, class_count: jint, class_definitions: *const jvmtiClassDefinition) -> jvmtiError>,
+    pub GetVersionNumber: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, version_ptr: *mut jint) -> jvmtiError>,
+    pub GetCapabilities: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, capabilities_ptr: *mut jvmtiCapabilities) -> jvmtiError>,
+    pub GetSourceDebugExtension: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, klass: jclass, source_debug_extension_ptr: *mut *mut c_char) -> jvmtiError>,
+    pub IsMethodObsolete: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, method: jmethodID, is_obsolete_ptr: *mut jboolean) -> jvmtiError>,
+    pub SuspendThreadList: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, request_count: jint, request_list: *const jthread, results: *mut jvmtiError) -> jvmtiError>,
+    pub ResumeThreadList: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, request_count: jint, request_list: *const jthread, results: *mut jvmtiError) -> jvmtiError>,
+    pub AddModuleReads: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, module: jobject, to_module: jobject) -> jvmtiError>,
+    pub AddModuleExports: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, module: jobject, pkg_name: *const c_char, to_module: jobject) -> jvmtiError>,
+    pub AddModuleOpens: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, module: jobject, pkg_name: *const c_char, to_module: jobject) -> jvmtiError>,
+    pub AddModuleUses: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, module: jobject, service: jclass) -> jvmtiError>,
+    pub AddModuleProvides: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, module: jobject, service: jclass, impl_class: jclass) -> jvmtiError>,
+    pub IsModifiableModule: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, module: jobject, is_modifiable_module_ptr: *mut jboolean) -> jvmtiError>,
+    pub GetAllStackTraces: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, max_frame_count: jint, stack_info_ptr: *mut *mut jvmtiStackInfo, thread_count_ptr: *mut jint) -> jvmtiError>,
+    pub GetThreadListStackTraces: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, thread_count: jint, thread_list: *const jthread, max_frame_count: jint, stack_info_ptr: *mut *mut jvmtiStackInfo) -> jvmtiError>,
+    pub GetThreadLocalStorage: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, thread: jthread, data_ptr: *mut *mut c_void) -> jvmtiError>,
+    pub SetThreadLocalStorage: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, thread: jthread, data: *const c_void) -> jvmtiError>,
+    pub GetStackTrace: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, thread: jthread, start_depth: jint, max_frame_count: jint, frame_buffer: *mut jvmtiFrameInfo, count_ptr: *mut jint) -> jvmtiError>,
+    pub reserved105: *mut c_void,
+    pub GetTag: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, object: jobject, tag_ptr: *mut jlong) -> jvmtiError>,
+    pub SetTag: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, object: jobject, tag: jlong) -> jvmtiError>,
+    pub ForceGarbageCollection: Option<unsafe extern "C" fn(env: *mut jvmtiEnv) -> jvmtiError>,
+    pub IterateOverObjectsReachableFromObject: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, object: jobject, object_reference_callback: jvmtiObjectReferenceCallback, user_data: *const c_void) -> jvmtiError>,
+    pub IterateOverReachableObjects: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, heap_root_callback: jvmtiHeapRootCallback, stack_ref_callback: jvmtiStackReferenceCallback, object_ref_callback: jvmtiObjectReferenceCallback, user_data: *const c_void) -> jvmtiError>,
+    pub IterateOverHeap: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, object_filter: jvmtiHeapObjectFilter, heap_object_callback: jvmtiHeapObjectCallback, user_data: *const c_void) -> jvmtiError>,
+    pub IterateOverInstancesOfClass: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, klass: jclass, object_filter: jvmtiHeapObjectFilter, heap_object_callback: jvmtiHeapObjectCallback, user_data: *const c_void) -> jvmtiError>,
+    pub reserved113: *mut c_void,
+    pub GetObjectsWithTags: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, tag_count: jint, tags: *const jlong, count_ptr: *mut jint, object_result_ptr: *mut *mut jobject, tag_result_ptr: *mut *mut jlong) -> jvmtiError>,
+    pub FollowReferences: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, heap_filter: jint, klass: jclass, initial_object: jobject, callbacks: *const jvmtiHeapCallbacks, user_data: *const c_void) -> jvmtiError>,
+    pub IterateThroughHeap: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, heap_filter: jint, klass: jclass, callbacks: *const jvmtiHeapCallbacks, user_data: *const c_void) -> jvmtiError>,
+    pub reserved117: *mut c_void,
+    pub reserved118: *mut c_void,
+    pub reserved119: *mut c_void,
+    pub SetJNIFunctionTable: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, function_table: *const jniNativeInterface) -> jvmtiError>,
+    pub GetJNIFunctionTable: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, function_table: *mut *mut jniNativeInterface) -> jvmtiError>,
+    pub SetEventCallbacks: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, callbacks: *const jvmtiEventCallbacks, size_of_callbacks: jint) -> jvmtiError>,
+    pub GenerateEvents: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, event_type: jvmtiEvent) -> jvmtiError>,
+    pub GetExtensionFunctions: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, extension_count_ptr: *mut jint, extensions: *mut *mut jvmtiExtensionFunctionInfo) -> jvmtiError>,
+    pub GetExtensionEvents: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, extension_count_ptr: *mut jint, extensions: *mut *mut jvmtiExtensionEventInfo) -> jvmtiError>,
+    pub SetExtensionEventCallback: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, extension_event_index: jint, callback: jvmtiExtensionEvent) -> jvmtiError>,
+    pub DisposeEnvironment: Option<unsafe extern "C" fn(env: *mut jvmtiEnv) -> jvmtiError>,
+    pub GetErrorName: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, error: jvmtiError, name_ptr: *mut *mut c_char) -> jvmtiError>,
+    pub GetJLocationFormat: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, format_ptr: *mut jvmtiJlocationFormat) -> jvmtiError>,
+    pub GetSystemProperties: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, count_ptr: *mut jint, property_ptr: *mut *mut *mut c_char) -> jvmtiError>,
+    pub GetSystemProperty: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, property: *const c_char, value_ptr: *mut *mut c_char) -> jvmtiError>,
+    pub SetSystemProperty: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, property: *const c_char, value: *const c_char) -> jvmtiError>,
+    pub GetPhase: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, phase_ptr: *mut jvmtiPhase) -> jvmtiError>,
+    pub GetCurrentThreadCpuTimerInfo: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, info_ptr: *mut jvmtiTimerInfo) -> jvmtiError>,
+    pub GetCurrentThreadCpuTime: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, nanos_ptr: *mut jlong) -> jvmtiError>,
+    pub GetThreadCpuTimerInfo: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, info_ptr: *mut jvmtiTimerInfo) -> jvmtiError>,
+    pub GetThreadCpuTime: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, thread: jthread, nanos_ptr: *mut jlong) -> jvmtiError>,
+    pub GetTimerInfo: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, info_ptr: *mut jvmtiTimerInfo) -> jvmtiError>,
+    pub GetTime: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, nanos_ptr: *mut jlong) -> jvmtiError>,
+    pub GetPotentialCapabilities: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, capabilities_ptr: *mut jvmtiCapabilities) -> jvmtiError>,
+    pub reserved141: *mut c_void,
+    pub AddCapabilities: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, capabilities_ptr: *const jvmtiCapabilities) -> jvmtiError>,
+    pub RelinquishCapabilities: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, capabilities_ptr: *const jvmtiCapabilities) -> jvmtiError>,
+    pub GetAvailableProcessors: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, processor_count_ptr: *mut jint) -> jvmtiError>,
+    pub GetClassVersionNumbers: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, klass: jclass, minor_version_ptr: *mut jint, major_version_ptr: *mut jint) -> jvmtiError>,
+    pub GetConstantPool: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, klass: jclass, constant_pool_count_ptr: *mut jint, constant_pool_byte_count_ptr: *mut jint, constant_pool_bytes_ptr: *mut *mut c_uchar) -> jvmtiError>,
+    pub GetEnvironmentLocalStorage: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, data_ptr: *mut *mut c_void) -> jvmtiError>,
+    pub SetEnvironmentLocalStorage: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, data: *const c_void) -> jvmtiError>,
+    pub AddToBootstrapClassLoaderSearch: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, segment: *const c_char) -> jvmtiError>,
+    pub SetVerboseFlag: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, flag: jvmtiVerboseFlag, value: jboolean) -> jvmtiError>,
+    pub AddToSystemClassLoaderSearch: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, segment: *const c_char) -> jvmtiError>,
+    pub RetransformClasses: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, class_count: jint, classes: *const jclass) -> jvmtiError>,
+    pub GetOwnedMonitorStackDepthInfo: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, thread: jthread, monitor_info_count_ptr: *mut jint, monitor_info_ptr: *mut *mut jvmtiMonitorStackDepthInfo) -> jvmtiError>,
+    pub GetObjectSize: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, object: jobject, size_ptr: *mut jlong) -> jvmtiError>,
+    pub GetLocalInstance: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, thread: jthread, depth: jint, value_ptr: *mut jobject) -> jvmtiError>,
+    pub SetHeapSamplingInterval: Option<unsafe extern "C" fn(env: *mut jvmtiEnv, sampling_interval: jint) -> jvmtiError>,
+}
+impl ::std::clone::Clone for Struct_jvmtiInterface_1_ {
+    fn clone(&self) -> Self { *self }
+}
+impl ::std::default::Default for Struct_jvmtiInterface_1_ {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+#[repr(C)]
+#[derive(Copy)]
+pub struct Struct__jvmtiEnv {
+    pub functions: *const Struct_jvmtiInterface_1_,
+}
+impl ::std::clone::Clone for Struct__jvmtiEnv {
+    fn clone(&self) -> Self { *self }
+}
+impl ::std::default::Default for Struct__jvmtiEnv {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+
+extern "C" {
+    pub fn Agent_OnAttach(vm: *mut JavaVM, options: *mut c_char, reserved: *mut c_void) -> jint;
+}
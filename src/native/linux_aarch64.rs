@@ -0,0 +1,24 @@
+//!
+//! Platform dependent declarations for Linux on aarch64 (AAPCS64).
+//!
+//! On this platform `va_list` is a plain structure that is passed around by value.
+//!
+use libc::{c_int, c_void};
+
+#[repr(C)]
+#[derive(Copy)]
+pub struct Struct___va_list {
+    pub __stack: *mut c_void,
+    pub __gr_top: *mut c_void,
+    pub __vr_top: *mut c_void,
+    pub __gr_offs: c_int,
+    pub __vr_offs: c_int,
+}
+impl ::std::clone::Clone for Struct___va_list {
+    fn clone(&self) -> Self { *self }
+}
+impl ::std::default::Default for Struct___va_list {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+pub type __builtin_va_list = Struct___va_list;
+pub type va_list = Struct___va_list;
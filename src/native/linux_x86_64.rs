@@ -0,0 +1,25 @@
+//!
+//! Platform dependent declarations for Linux on x86_64 (System V AMD64 ABI).
+//!
+//! On this platform `va_list` is a single element array of `__va_list_tag` structures, which
+//! decays into a pointer whenever it's passed to a function such as `CallObjectMethodV`.
+//!
+use libc::{c_uint, c_void};
+
+#[repr(C)]
+#[derive(Copy)]
+pub struct Struct___va_list_tag {
+    pub gp_offset: c_uint,
+    pub fp_offset: c_uint,
+    pub overflow_arg_area: *mut c_void,
+    pub reg_save_area: *mut c_void,
+}
+impl ::std::clone::Clone for Struct___va_list_tag {
+    fn clone(&self) -> Self { *self }
+}
+impl ::std::default::Default for Struct___va_list_tag {
+    fn default() -> Self { unsafe { ::std::mem::zeroed() } }
+}
+pub type __va_list_tag = Struct___va_list_tag;
+pub type __builtin_va_list = [__va_list_tag; 1usize];
+pub type va_list = *mut __va_list_tag;
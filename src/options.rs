@@ -1,4 +1,6 @@
+use super::logging::{logger, Level};
 use std::collections::HashMap;
+use std::str::FromStr;
 
 ///
 /// Represents the possible program options configured from command-line arguments.
@@ -6,7 +8,12 @@ use std::collections::HashMap;
 pub struct Options {
     pub agent_id: String,
     pub custom_args: HashMap<String, String>,
-    pub config_location: Option<String>
+    pub config_location: Option<String>,
+    /// Where log records are written to: `stderr`, `syslog`, `syslog:<socket path>` or a file path
+    pub log_output: Option<String>,
+    pub log_level: Option<Level>,
+    /// Levels of individual modules, given as `loglevel.<module>=<level>`
+    pub log_modules: Vec<(String, Level)>
 }
 
 impl Options {
@@ -31,14 +38,31 @@ impl Options {
     }
 
     fn parse_key_value(options: &mut Options, key: &str, value: &str) {
-        println!("Parsing key: {} -> {}", key, value);
+        debug!(logger(), "Parsing key: {} -> {}", key, value);
         match key {
             "agentid" => { options.agent_id = value.to_string(); },
             "config" => { options.config_location = Some(value.to_string()); },
+            "log" => { options.log_output = Some(value.to_string()); },
+            "loglevel" => { options.log_level = Options::parse_level(key, value); },
+            _ if key.starts_with("loglevel.") => {
+                if let Some(level) = Options::parse_level(key, value) {
+                    options.log_modules.push((key["loglevel.".len()..].to_string(), level));
+                }
+            },
             _ => { options.custom_args.insert(key.to_string(), value.to_string()); }
         }
     }
 
+    fn parse_level(key: &str, value: &str) -> Option<Level> {
+        match Level::from_str(value) {
+            Ok(level) => Some(level),
+            Err(_) => {
+                warn!(logger(), "Ignoring {}: unknown log level {}", key, value);
+                None
+            }
+        }
+    }
+
     fn parse_directive(options: &mut Options, directive: &str) {
         match directive {
             _ => options.custom_args.insert(directive.to_string(), "".to_string())
@@ -50,7 +74,10 @@ impl Options {
         Options {
             agent_id: "jvmti".to_string(),
             custom_args: HashMap::new(),
-            config_location: None
+            config_location: None,
+            log_output: None,
+            log_level: None,
+            log_modules: vec![]
         }
     }
 }
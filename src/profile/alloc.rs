@@ -0,0 +1,92 @@
+use super::super::runtime::ObjectAllocationEvent;
+use std::collections::HashMap;
+
+///
+/// Identifies the place where objects are allocated: the allocated class and the topmost frames of
+/// the allocating thread's stack.
+///
+#[derive(Eq, PartialEq, Hash, Clone, Debug)]
+pub struct AllocationSite {
+    pub class_name: String,
+    pub frames: Vec<String>
+}
+
+impl AllocationSite {
+
+    pub fn to_string(&self) -> String {
+        match self.frames.is_empty() {
+            true => format!("{} <unknown>", self.class_name),
+            false => format!("{} at {}", self.class_name, self.frames.join(" <- "))
+        }
+    }
+}
+
+/// Aggregated statistics of allocations made at a single allocation site.
+#[derive(Eq, PartialEq, Clone, Debug, Default)]
+pub struct AllocationStats {
+    pub count: u64,
+    pub total_bytes: u64,
+    pub max_bytes: u64
+}
+
+///
+/// Aggregates allocation events by allocation site. When fed with sampled allocation events, the
+/// counts reflect the number of samples rather than the number of allocations, which is still
+/// proportional to the allocation rate of each site.
+///
+pub struct AllocationProfile {
+    depth: usize,
+    sites: HashMap<AllocationSite, AllocationStats>
+}
+
+impl AllocationProfile {
+
+    /// Create a new, empty profile that distinguishes allocation sites by the topmost `depth`
+    /// stack frames.
+    pub fn new(depth: usize) -> AllocationProfile {
+        AllocationProfile { depth: depth, sites: HashMap::new() }
+    }
+
+    pub fn record(&mut self, event: &ObjectAllocationEvent) {
+        let site = AllocationSite {
+            class_name: event.class_sig.to_string(),
+            frames: event.stack_trace.iter().take(self.depth).map(|frame| frame.to_string()).collect()
+        };
+
+        let size = if event.size > 0 { event.size as u64 } else { 0 };
+        let stats = self.sites.entry(site).or_insert_with(AllocationStats::default);
+
+        stats.count += 1;
+        stats.total_bytes += size;
+
+        if size > stats.max_bytes {
+            stats.max_bytes = size;
+        }
+    }
+
+    pub fn get(&self, site: &AllocationSite) -> Option<&AllocationStats> {
+        self.sites.get(site)
+    }
+
+    /// Return the number of distinct allocation sites recorded
+    pub fn len(&self) -> usize {
+        self.sites.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sites.is_empty()
+    }
+
+    /// Return at most `limit` allocation sites, ordered by the number of allocated bytes
+    pub fn top_sites(&self, limit: usize) -> Vec<(AllocationSite, AllocationStats)> {
+        let mut sites: Vec<(AllocationSite, AllocationStats)> = self.sites.iter().map(|(site, stats)| (site.clone(), stats.clone())).collect();
+
+        sites.sort_by(|a, b| b.1.total_bytes.cmp(&a.1.total_bytes).then(b.1.count.cmp(&a.1.count)));
+        sites.truncate(limit);
+        sites
+    }
+
+    pub fn reset(&mut self) {
+        self.sites.clear();
+    }
+}
@@ -0,0 +1,240 @@
+use std::collections::{BTreeMap, HashMap};
+
+/// Name of the synthetic root node of every call tree
+pub const ROOT: &'static str = "<root>";
+
+///
+/// A node of a calling context tree: a method invoked through a particular chain of callers. All
+/// times are in nanoseconds; the total time includes the time spent in callees, while the self
+/// time doesn't.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct CallTreeNode {
+    pub method: String,
+    pub count: u64,
+    pub total_time: u64,
+    pub self_time: u64,
+    pub children: BTreeMap<String, CallTreeNode>
+}
+
+impl CallTreeNode {
+
+    pub fn new(method: &str) -> CallTreeNode {
+        CallTreeNode { method: method.to_string(), count: 0, total_time: 0, self_time: 0, children: BTreeMap::new() }
+    }
+
+    /// Return the child node reached by calling the given method from this node, if it was called
+    pub fn child(&self, method: &str) -> Option<&CallTreeNode> {
+        self.children.get(method)
+    }
+
+    /// Add the counts and times of another tree (of the same calling context) to this one
+    pub fn merge(&mut self, other: &CallTreeNode) {
+        self.count += other.count;
+        self.total_time += other.total_time;
+        self.self_time += other.self_time;
+
+        for (method, other_child) in other.children.iter() {
+            self.children.entry(method.clone()).or_insert_with(|| CallTreeNode::new(method)).merge(other_child);
+        }
+    }
+
+    ///
+    /// Render the tree as an indented list, one line per node, with the most expensive callees
+    /// listed first.
+    ///
+    pub fn render_lines(&self) -> Vec<String> {
+        let mut lines = vec![];
+
+        self.render_into(0, &mut lines);
+        lines
+    }
+
+    fn render_into(&self, depth: usize, lines: &mut Vec<String>) {
+        lines.push(format!("{}{} (calls: {}, total: {:.3} ms, self: {:.3} ms)", "  ".repeat(depth), self.method, self.count, self.total_time as f64 / 1e6, self.self_time as f64 / 1e6));
+
+        let mut children: Vec<&CallTreeNode> = self.children.values().collect();
+        children.sort_by(|a, b| b.total_time.cmp(&a.total_time));
+
+        for child in children {
+            child.render_into(depth + 1, lines);
+        }
+    }
+}
+
+/// A node of a thread's call tree, referring to its children by their index in the tree
+struct Node {
+    method: String,
+    count: u64,
+    total_time: u64,
+    self_time: u64,
+    children: HashMap<String, usize>
+}
+
+/// An invocation that hasn't returned yet
+struct Frame {
+    node: usize,
+    start: u64,
+    child_time: u64
+}
+
+///
+/// The calling context tree of a single thread. Nodes are kept in a flat list so that the active
+/// invocations can refer to them while the tree is being built.
+///
+struct ThreadCallTree {
+    nodes: Vec<Node>,
+    stack: Vec<Frame>
+}
+
+impl ThreadCallTree {
+
+    fn new() -> ThreadCallTree {
+        ThreadCallTree { nodes: vec![ Node { method: ROOT.to_string(), count: 0, total_time: 0, self_time: 0, children: HashMap::new() } ], stack: vec![] }
+    }
+
+    fn enter(&mut self, method: &str, time: u64) {
+        let parent = self.stack.last().map(|frame| frame.node).unwrap_or(0);
+        let next_idx = self.nodes.len();
+        let node = *self.nodes[parent].children.entry(method.to_string()).or_insert(next_idx);
+
+        if node == next_idx {
+            self.nodes.push(Node { method: method.to_string(), count: 0, total_time: 0, self_time: 0, children: HashMap::new() });
+        }
+
+        self.stack.push(Frame { node: node, start: time, child_time: 0 });
+    }
+
+    fn exit(&mut self, method: &str, time: u64) -> Option<u64> {
+        let depth = {
+            let nodes = &self.nodes;
+            match self.stack.iter().rposition(|frame| nodes[frame.node].method == method) {
+                Some(depth) => depth,
+                None => return None
+            }
+        };
+
+        let mut elapsed = 0;
+
+        // Frames above the exited one have been popped without their exit being reported
+        while self.stack.len() > depth {
+            let frame = match self.stack.pop() {
+                Some(frame) => frame,
+                None => break
+            };
+
+            elapsed = time.saturating_sub(frame.start);
+
+            if let Some(parent) = self.stack.last_mut() {
+                parent.child_time += elapsed;
+            }
+
+            let node = &mut self.nodes[frame.node];
+
+            node.count += 1;
+            node.total_time += elapsed;
+            node.self_time += elapsed.saturating_sub(frame.child_time);
+        }
+
+        Some(elapsed)
+    }
+
+    fn to_root(&self) -> CallTreeNode {
+        let mut root = self.to_tree(0);
+
+        root.total_time = root.children.values().map(|child| child.total_time).sum();
+        root
+    }
+
+    fn to_tree(&self, idx: usize) -> CallTreeNode {
+        let node = &self.nodes[idx];
+
+        CallTreeNode {
+            method: node.method.clone(),
+            count: node.count,
+            total_time: node.total_time,
+            self_time: node.self_time,
+            children: node.children.iter().map(|(method, child)| (method.clone(), self.to_tree(*child))).collect()
+        }
+    }
+
+    fn reset(&mut self) {
+        for node in self.nodes.iter_mut() {
+            node.count = 0;
+            node.total_time = 0;
+            node.self_time = 0;
+        }
+    }
+}
+
+///
+/// Builds a calling context tree for every thread from method entry and exit events. The trees are
+/// kept separately so that threads don't need to synchronise on a shared tree and are merged on
+/// demand. The trees of ended threads are merged right away, so that they aren't kept forever.
+///
+pub struct CallTreeProfile {
+    threads: HashMap<i64, ThreadCallTree>,
+    ended: CallTreeNode
+}
+
+impl CallTreeProfile {
+
+    pub fn new() -> CallTreeProfile {
+        CallTreeProfile { threads: HashMap::new(), ended: CallTreeNode::new(ROOT) }
+    }
+
+    /// Record that the given thread has entered a method at the given time (in nanoseconds)
+    pub fn enter(&mut self, thread_id: i64, method: &str, time: u64) {
+        self.threads.entry(thread_id).or_insert_with(ThreadCallTree::new).enter(method, time);
+    }
+
+    ///
+    /// Record that the given thread has left a method at the given time and return the time spent
+    /// in it. Exits from methods that have been entered before the recording started are ignored.
+    ///
+    pub fn exit(&mut self, thread_id: i64, method: &str, time: u64) -> Option<u64> {
+        match self.threads.get_mut(&thread_id) {
+            Some(tree) => tree.exit(method, time),
+            None => None
+        }
+    }
+
+    /// Drop the call tree of the given thread along with the invocations still in progress on it.
+    /// Its counts and times are kept in the merged tree.
+    pub fn thread_end(&mut self, thread_id: i64) {
+        if let Some(tree) = self.threads.remove(&thread_id) {
+            self.ended.merge(&tree.to_root());
+        }
+    }
+
+    /// Return the call tree of a single live thread
+    pub fn thread_tree(&self, thread_id: i64) -> Option<CallTreeNode> {
+        self.threads.get(&thread_id).map(|tree| tree.to_root())
+    }
+
+    /// Return the call trees of all threads merged into a single tree
+    pub fn merged(&self) -> CallTreeNode {
+        let mut root = self.ended.clone();
+
+        for tree in self.threads.values() {
+            root.merge(&tree.to_root());
+        }
+
+        root
+    }
+
+    /// Clear the collected counts and times, keeping the invocations that are still in progress
+    pub fn reset(&mut self) {
+        for tree in self.threads.values_mut() {
+            tree.reset();
+        }
+
+        self.ended = CallTreeNode::new(ROOT);
+    }
+}
+
+impl Default for CallTreeProfile {
+    fn default() -> CallTreeProfile {
+        CallTreeProfile::new()
+    }
+}
@@ -0,0 +1,247 @@
+use super::super::runtime::{ExceptionCatchEvent, ExceptionEvent};
+use std::collections::{BTreeMap, HashMap};
+
+/// Length of the window throw rates are measured over, in nanoseconds
+const RATE_WINDOW: u64 = 1_000_000_000;
+
+/// Identifies where exceptions are thrown: the class of the exception and the throwing location
+#[derive(Eq, PartialEq, Hash, Clone, Debug)]
+pub struct ThrowSite {
+    pub exception_class: String,
+    pub location: String
+}
+
+impl ThrowSite {
+
+    pub fn to_string(&self) -> String {
+        format!("{} at {}", self.exception_class, self.location)
+    }
+}
+
+///
+/// Aggregated statistics of thrown exceptions. The distance of a caught exception is the number
+/// of frames between the frame that has thrown it and the one that has caught it.
+///
+#[derive(Eq, PartialEq, Clone, Debug, Default)]
+pub struct ExceptionStats {
+    pub thrown: u64,
+    pub caught: u64,
+    pub uncaught: u64,
+    pub total_distance: u64,
+    pub max_distance: u64
+}
+
+impl ExceptionStats {
+
+    /// Return the average throw to catch distance of the caught exceptions
+    pub fn mean_distance(&self) -> f64 {
+        match self.caught {
+            0 => 0.0,
+            caught => self.total_distance as f64 / caught as f64
+        }
+    }
+
+    fn record_catch(&mut self, distance: Option<u64>) {
+        self.caught += 1;
+
+        if let Some(distance) = distance {
+            self.total_distance += distance;
+            self.max_distance = self.max_distance.max(distance);
+        }
+    }
+}
+
+/// Statistics of the exceptions thrown at a single site
+#[derive(Eq, PartialEq, Clone, Debug, Default)]
+pub struct SiteStats {
+    pub exceptions: ExceptionStats,
+    /// Number of exceptions caught by each method
+    pub catch_methods: BTreeMap<String, u64>,
+    /// Highest number of exceptions thrown within a second
+    pub peak_rate: u64,
+    window_start: u64,
+    window_count: u64
+}
+
+impl SiteStats {
+
+    fn record_throw(&mut self, time: u64) {
+        if self.exceptions.thrown == 0 || time.saturating_sub(self.window_start) >= RATE_WINDOW {
+            self.window_start = time;
+            self.window_count = 0;
+        }
+
+        self.exceptions.thrown += 1;
+        self.window_count += 1;
+        self.peak_rate = self.peak_rate.max(self.window_count);
+    }
+}
+
+/// An exception that has been thrown and is expected to be caught
+struct PendingThrow {
+    site: ThrowSite,
+    stack_depth: usize
+}
+
+///
+/// Aggregates exception and exception catch events by exception class and by throw site. An
+/// exception event reports whether and where the exception is going to be caught, the matching
+/// catch event is used to measure how far up the stack it has travelled.
+///
+pub struct ExceptionProfile {
+    types: HashMap<String, ExceptionStats>,
+    sites: HashMap<ThrowSite, SiteStats>,
+    pending: HashMap<i64, PendingThrow>
+}
+
+impl ExceptionProfile {
+
+    pub fn new() -> ExceptionProfile {
+        ExceptionProfile { types: HashMap::new(), sites: HashMap::new(), pending: HashMap::new() }
+    }
+
+    /// Record an exception thrown at the given time (in nanoseconds)
+    pub fn thrown(&mut self, event: &ExceptionEvent, time: u64) {
+        let site = ThrowSite { exception_class: event.exception_class.to_string(), location: event.throw_site.to_string() };
+        let type_stats = self.types.entry(site.exception_class.clone()).or_insert_with(ExceptionStats::default);
+        let site_stats = self.sites.entry(site.clone()).or_insert_with(SiteStats::default);
+
+        type_stats.thrown += 1;
+        site_stats.record_throw(time);
+
+        match event.catch_site {
+            Some(_) => {
+                self.pending.insert(event.thread.java_id, PendingThrow { site: site, stack_depth: event.stack_depth });
+            },
+            None => {
+                type_stats.uncaught += 1;
+                site_stats.exceptions.uncaught += 1;
+                self.pending.remove(&event.thread.java_id);
+            }
+        }
+    }
+
+    ///
+    /// Record that an exception has been caught and return its throw to catch distance. The
+    /// distance is unknown for exceptions whose throw hasn't been reported, eg. ones thrown by
+    /// native code.
+    ///
+    pub fn caught(&mut self, event: &ExceptionCatchEvent) -> Option<u64> {
+        let exception_class = event.exception_class.to_string();
+
+        match self.pending.remove(&event.thread.java_id) {
+            Some(ref pending) if pending.site.exception_class == exception_class => {
+                let distance = pending.stack_depth.saturating_sub(event.stack_depth) as u64;
+                let site_stats = self.sites.entry(pending.site.clone()).or_insert_with(SiteStats::default);
+
+                site_stats.exceptions.record_catch(Some(distance));
+                *site_stats.catch_methods.entry(event.catch_site.method_name()).or_insert(0) += 1;
+
+                self.types.entry(exception_class).or_insert_with(ExceptionStats::default).record_catch(Some(distance));
+                Some(distance)
+            },
+            _ => {
+                self.types.entry(exception_class).or_insert_with(ExceptionStats::default).record_catch(None);
+                None
+            }
+        }
+    }
+
+    pub fn get_type(&self, exception_class: &str) -> Option<&ExceptionStats> {
+        self.types.get(exception_class)
+    }
+
+    pub fn get_site(&self, site: &ThrowSite) -> Option<&SiteStats> {
+        self.sites.get(site)
+    }
+
+    /// Return the number of distinct throw sites recorded
+    pub fn len(&self) -> usize {
+        self.sites.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.types.is_empty()
+    }
+
+    /// Return at most `limit` exception classes, ordered by the number of exceptions thrown
+    pub fn top_types(&self, limit: usize) -> Vec<(String, ExceptionStats)> {
+        let mut types: Vec<(String, ExceptionStats)> = self.types.iter().map(|(class, stats)| (class.clone(), stats.clone())).collect();
+
+        types.sort_by(|a, b| b.1.thrown.cmp(&a.1.thrown).then(a.0.cmp(&b.0)));
+        types.truncate(limit);
+        types
+    }
+
+    /// Return at most `limit` throw sites, ordered by the number of exceptions thrown
+    pub fn top_sites(&self, limit: usize) -> Vec<(ThrowSite, SiteStats)> {
+        let mut sites: Vec<(ThrowSite, SiteStats)> = self.sites.iter().map(|(site, stats)| (site.clone(), stats.clone())).collect();
+
+        sites.sort_by(|a, b| b.1.exceptions.thrown.cmp(&a.1.exceptions.thrown).then(a.0.location.cmp(&b.0.location)));
+        sites.truncate(limit);
+        sites
+    }
+
+    ///
+    /// Return the throw sites that look like exceptions are used for control flow: at least
+    /// `min_rate` exceptions have been thrown there within a second and all of them have been
+    /// caught by the same method. The sites are ordered by their peak throw rate.
+    ///
+    pub fn hot_spots(&self, min_rate: u64) -> Vec<(ThrowSite, SiteStats)> {
+        let mut sites: Vec<(ThrowSite, SiteStats)> = self.sites.iter()
+            .filter(|&(_, stats)| stats.peak_rate >= min_rate && stats.exceptions.uncaught == 0 && stats.catch_methods.len() == 1)
+            .map(|(site, stats)| (site.clone(), stats.clone()))
+            .collect();
+
+        sites.sort_by(|a, b| b.1.peak_rate.cmp(&a.1.peak_rate).then(a.0.location.cmp(&b.0.location)));
+        sites
+    }
+
+    /// Render a report of the most frequent exception classes, throw sites and hot spots
+    pub fn report_lines(&self, limit: usize, min_rate: u64) -> Vec<String> {
+        let mut lines = vec![];
+
+        lines.push("Exceptions by type:".to_string());
+
+        for (class, stats) in self.top_types(limit) {
+            lines.push(format!("  {} (thrown: {}, caught: {}, uncaught: {}, mean distance: {:.1}, max distance: {})",
+                class, stats.thrown, stats.caught, stats.uncaught, stats.mean_distance(), stats.max_distance));
+        }
+
+        lines.push("Exceptions by throw site:".to_string());
+
+        for (site, stats) in self.top_sites(limit) {
+            lines.push(format!("  {} (thrown: {}, caught: {}, uncaught: {}, peak rate: {}/s)",
+                site.to_string(), stats.exceptions.thrown, stats.exceptions.caught, stats.exceptions.uncaught, stats.peak_rate));
+        }
+
+        let hot_spots = self.hot_spots(min_rate);
+
+        if !hot_spots.is_empty() {
+            lines.push("Exceptions used as control flow:".to_string());
+
+            for (site, stats) in hot_spots.iter().take(limit) {
+                let catch_method = stats.catch_methods.keys().next().cloned().unwrap_or_default();
+                lines.push(format!("  {} caught by {} (peak rate: {}/s)", site.to_string(), catch_method, stats.peak_rate));
+            }
+        }
+
+        lines
+    }
+
+    /// Drop the exception the given thread has thrown but not caught yet
+    pub fn thread_end(&mut self, thread_id: i64) {
+        self.pending.remove(&thread_id);
+    }
+
+    pub fn reset(&mut self) {
+        self.types.clear();
+        self.sites.clear();
+    }
+}
+
+impl Default for ExceptionProfile {
+    fn default() -> ExceptionProfile {
+        ExceptionProfile::new()
+    }
+}
@@ -0,0 +1,389 @@
+use super::alloc::AllocationProfile;
+use super::calltree::CallTreeNode;
+use super::locks::LockProfile;
+use super::sampling::SampleProfile;
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{Error, Write};
+use std::path::Path;
+
+///
+/// A profile in the folded stack format introduced by Brendan Gregg's FlameGraph tools: a set of
+/// stacks, each listing its frames from the outermost to the innermost one, with a weight (eg. the
+/// number of samples, nanoseconds or bytes) attributed to the innermost frame.
+///
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FoldedStacks {
+    stacks: BTreeMap<String, u64>
+}
+
+impl FoldedStacks {
+
+    pub fn new() -> FoldedStacks {
+        FoldedStacks { stacks: BTreeMap::new() }
+    }
+
+    /// Add weight to a stack, given as frames from the outermost to the innermost one
+    pub fn add(&mut self, frames: &[String], weight: u64) {
+        if frames.is_empty() || weight == 0 {
+            return;
+        }
+
+        let stack = frames.iter().map(|frame| frame.replace(';', ":")).collect::<Vec<String>>().join(";");
+
+        *self.stacks.entry(stack).or_insert(0) += weight;
+    }
+
+    ///
+    /// Parse folded stack text, one `frame;frame;frame weight` line per stack. Lines that don't end
+    /// with a weight are skipped.
+    ///
+    pub fn parse(text: &str) -> FoldedStacks {
+        let mut folded = FoldedStacks::new();
+
+        for line in text.lines() {
+            if let Some(idx) = line.trim_end().rfind(' ') {
+                if let Ok(weight) = line[idx + 1..].trim().parse::<u64>() {
+                    let frames: Vec<String> = line[..idx].split(';').map(|frame| frame.to_string()).collect();
+                    folded.add(&frames, weight);
+                }
+            }
+        }
+
+        folded
+    }
+
+    pub fn get(&self, stack: &str) -> u64 {
+        self.stacks.get(stack).cloned().unwrap_or(0)
+    }
+
+    /// Return the sum of the weights of all stacks
+    pub fn total(&self) -> u64 {
+        self.stacks.values().sum()
+    }
+
+    pub fn len(&self) -> usize {
+        self.stacks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stacks.is_empty()
+    }
+
+    pub fn lines(&self) -> Vec<String> {
+        self.stacks.iter().map(|(stack, weight)| format!("{} {}", stack, weight)).collect()
+    }
+
+    pub fn to_string(&self) -> String {
+        self.lines().iter().map(|line| format!("{}\n", line)).collect()
+    }
+}
+
+///
+/// Profiles that can be turned into folded stacks and thus rendered as flame graphs
+///
+pub trait ToFolded {
+    fn to_folded(&self) -> FoldedStacks;
+}
+
+impl ToFolded for SampleProfile {
+
+    /// Every sampled stack is weighted by the number of times it's been sampled
+    fn to_folded(&self) -> FoldedStacks {
+        let mut folded = FoldedStacks::new();
+
+        for (stack, count) in self.stacks() {
+            folded.stacks.insert(stack, count);
+        }
+
+        folded
+    }
+}
+
+impl ToFolded for CallTreeNode {
+
+    /// Every calling context is weighted by the self time (in nanoseconds) spent in it. The node
+    /// itself is treated as a synthetic root and left out of the stacks.
+    fn to_folded(&self) -> FoldedStacks {
+        fn fold(node: &CallTreeNode, path: &mut Vec<String>, folded: &mut FoldedStacks) {
+            path.push(node.method.clone());
+            folded.add(path, node.self_time);
+
+            for child in node.children.values() {
+                fold(child, path, folded);
+            }
+
+            path.pop();
+        }
+
+        let mut folded = FoldedStacks::new();
+
+        for child in self.children.values() {
+            fold(child, &mut vec![], &mut folded);
+        }
+
+        folded
+    }
+}
+
+impl ToFolded for AllocationProfile {
+
+    /// Every allocation site is weighted by the number of bytes allocated there, with the allocated
+    /// class as the innermost frame
+    fn to_folded(&self) -> FoldedStacks {
+        let mut folded = FoldedStacks::new();
+
+        for (site, stats) in self.top_sites(self.len()) {
+            let mut frames: Vec<String> = site.frames.iter().rev().cloned().collect();
+
+            frames.push(site.class_name.clone());
+            folded.add(&frames, stats.total_bytes);
+        }
+
+        folded
+    }
+}
+
+impl ToFolded for LockProfile {
+
+    /// Every contended monitor site is weighted by the time threads have been blocked there, with
+    /// the class of the monitor as the innermost frame. Waits are left out as they're intended.
+    fn to_folded(&self) -> FoldedStacks {
+        let mut folded = FoldedStacks::new();
+
+        for (site, stats) in self.top_contended(self.len()) {
+            let mut frames: Vec<String> = site.frames.iter().rev().cloned().collect();
+
+            frames.push(site.monitor_class.clone());
+            folded.add(&frames, stats.total_time);
+        }
+
+        folded
+    }
+}
+
+/// Width of the rendered flame graph in pixels
+const IMAGE_WIDTH: f64 = 1200.0;
+const FRAME_HEIGHT: f64 = 16.0;
+const PADDING: f64 = 10.0;
+const HEADER_HEIGHT: f64 = 40.0;
+/// Frames narrower than this (in pixels) aren't drawn at all
+const MIN_FRAME_WIDTH: f64 = 0.1;
+/// Approximate width of a character of the frame labels, used to decide how much text fits
+const CHAR_WIDTH: f64 = 7.0;
+
+/// Parameters shared by all frames of a flame graph
+struct Layout<'a> {
+    bottom: f64,
+    scale: f64,
+    total: u64,
+    unit: &'a str
+}
+
+/// A node of the merged stack tree the flame graph is drawn from
+struct FlameNode {
+    weight: u64,
+    children: BTreeMap<String, FlameNode>
+}
+
+impl FlameNode {
+
+    fn new() -> FlameNode {
+        FlameNode { weight: 0, children: BTreeMap::new() }
+    }
+
+    fn depth(&self) -> usize {
+        self.children.values().map(|child| child.depth() + 1).max().unwrap_or(0)
+    }
+}
+
+///
+/// Render folded stacks as a standalone SVG flame graph. The image embeds a small script which
+/// allows zooming into a frame by clicking on it and highlighting frames matching a search term,
+/// so it can be opened directly in a web browser. `unit` is used to describe the weights in the
+/// frame tooltips, eg. "samples" or "bytes".
+///
+pub fn render_svg(folded: &FoldedStacks, title: &str, unit: &str) -> String {
+    let mut root = FlameNode::new();
+
+    for (stack, weight) in folded.stacks.iter() {
+        let mut node = &mut root;
+        node.weight += *weight;
+
+        for frame in stack.split(';') {
+            node = { node }.children.entry(frame.to_string()).or_insert_with(FlameNode::new);
+            node.weight += *weight;
+        }
+    }
+
+    let depth = root.depth();
+    let height = HEADER_HEIGHT + (depth as f64 + 1.0) * FRAME_HEIGHT + 2.0 * PADDING;
+    let scale = if root.weight > 0 { (IMAGE_WIDTH - 2.0 * PADDING) / root.weight as f64 } else { 0.0 };
+
+    let mut svg = String::new();
+
+    svg.push_str(&format!("<?xml version=\"1.0\" standalone=\"no\"?>\n<svg version=\"1.1\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\" xmlns=\"http://www.w3.org/2000/svg\" onload=\"init(evt)\">\n", IMAGE_WIDTH, height, IMAGE_WIDTH, height));
+    svg.push_str("<style type=\"text/css\">text { font-family: monospace; font-size: 12px; fill: rgb(0,0,0); } .frame:hover rect { stroke: black; stroke-width: 0.5; cursor: pointer; } .hidden { display: none; } #title { font-size: 17px; } .control { cursor: pointer; }</style>\n");
+    svg.push_str(&format!("<script type=\"text/ecmascript\"><![CDATA[{}]]></script>\n", SCRIPT));
+    svg.push_str("<rect x=\"0\" y=\"0\" width=\"100%\" height=\"100%\" fill=\"rgb(250,250,238)\"/>\n");
+    svg.push_str(&format!("<text id=\"title\" x=\"{}\" y=\"24\" text-anchor=\"middle\">{}</text>\n", IMAGE_WIDTH / 2.0, escape(title)));
+    svg.push_str(&format!("<text id=\"unzoom\" class=\"control hidden\" x=\"{}\" y=\"24\">Reset Zoom</text>\n", PADDING));
+    svg.push_str(&format!("<text id=\"search\" class=\"control\" x=\"{}\" y=\"24\" text-anchor=\"end\">Search</text>\n", IMAGE_WIDTH - PADDING));
+    svg.push_str(&format!("<text id=\"details\" x=\"{}\" y=\"{}\"> </text>\n", PADDING, height - PADDING / 2.0));
+    svg.push_str("<g id=\"frames\">\n");
+
+    let layout = Layout { bottom: height - PADDING - HEADER_HEIGHT / 2.0, scale: scale, total: root.weight, unit: unit };
+
+    render_frame(&mut svg, "all", &root, PADDING, 0, &layout);
+
+    svg.push_str("</g>\n</svg>\n");
+    svg
+}
+
+fn render_frame(svg: &mut String, name: &str, node: &FlameNode, x: f64, depth: usize, layout: &Layout) {
+    let width = node.weight as f64 * layout.scale;
+
+    if width < MIN_FRAME_WIDTH {
+        return;
+    }
+
+    let y = layout.bottom - (depth as f64 + 1.0) * FRAME_HEIGHT;
+    let percent = if layout.total > 0 { node.weight as f64 * 100.0 / layout.total as f64 } else { 0.0 };
+    let info = escape(&format!("{} ({} {}, {:.2}%)", name, node.weight, layout.unit, percent));
+    let (r, g, b) = color(name);
+
+    svg.push_str(&format!("<g class=\"frame\"><title>{}</title><rect x=\"{:.1}\" y=\"{:.1}\" width=\"{:.1}\" height=\"{:.1}\" fill=\"rgb({},{},{})\" rx=\"2\" ry=\"2\"/><text x=\"{:.1}\" y=\"{:.1}\">{}</text></g>\n",
+        info, x, y, width, FRAME_HEIGHT - 1.0, r, g, b, x + 3.0, y + FRAME_HEIGHT - 4.5, escape(&label(name, width))));
+
+    let mut child_x = x;
+
+    for (child_name, child) in node.children.iter() {
+        render_frame(svg, child_name, child, child_x, depth + 1, layout);
+        child_x += child.weight as f64 * layout.scale;
+    }
+}
+
+/// Return as much of the frame name as fits into the given width
+fn label(name: &str, width: f64) -> String {
+    let fits = ((width - 6.0) / CHAR_WIDTH).floor();
+
+    if fits < 3.0 {
+        String::new()
+    } else if name.chars().count() as f64 <= fits {
+        name.to_string()
+    } else {
+        format!("{}..", name.chars().take(fits as usize - 2).collect::<String>())
+    }
+}
+
+/// Pick a warm color for the frame, derived from its name so that the same method always gets
+/// the same color
+fn color(name: &str) -> (u8, u8, u8) {
+    let hash = name.bytes().fold(2166136261u32, |hash, byte| (hash ^ byte as u32).wrapping_mul(16777619));
+
+    (205 + (hash % 50) as u8, ((hash >> 8) % 230) as u8, ((hash >> 16) % 55) as u8)
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;").replace('\'', "&apos;")
+}
+
+///
+/// Write the given profile both as folded stacks (`<name>.folded`) and as a flame graph
+/// (`<name>.svg`) into the given directory, which is created if necessary.
+///
+pub fn write_flame_graph<P: AsRef<Path>>(directory: P, name: &str, folded: &FoldedStacks, title: &str, unit: &str) -> Result<(), Error> {
+    fs::create_dir_all(directory.as_ref())?;
+
+    File::create(directory.as_ref().join(format!("{}.folded", name)))?.write_all(folded.to_string().as_bytes())?;
+    File::create(directory.as_ref().join(format!("{}.svg", name)))?.write_all(render_svg(folded, title, unit).as_bytes())
+}
+
+/// Zooming and searching support embedded into the rendered flame graphs
+const SCRIPT: &'static str = r#"
+var frames, details, unzoom, searchButton;
+function init(evt) {
+    frames = document.getElementById("frames");
+    details = document.getElementById("details").firstChild;
+    unzoom = document.getElementById("unzoom");
+    searchButton = document.getElementById("search");
+    var groups = frames.getElementsByTagName("g");
+    for (var i = 0; i < groups.length; i++) {
+        var rect = groups[i].getElementsByTagName("rect")[0];
+        rect.setAttribute("data-x", rect.getAttribute("x"));
+        rect.setAttribute("data-width", rect.getAttribute("width"));
+        rect.setAttribute("data-fill", rect.getAttribute("fill"));
+        groups[i].onclick = function() { zoom(this); };
+        groups[i].onmouseover = function() { details.nodeValue = this.getElementsByTagName("title")[0].textContent; };
+        groups[i].onmouseout = function() { details.nodeValue = " "; };
+    }
+    unzoom.onclick = reset;
+    searchButton.onclick = search;
+}
+function name(group) {
+    var title = group.getElementsByTagName("title")[0].textContent;
+    return title.substring(0, title.lastIndexOf(" ("));
+}
+function fit(group, x, width) {
+    var rect = group.getElementsByTagName("rect")[0];
+    var text = group.getElementsByTagName("text")[0];
+    var label = name(group);
+    var fits = Math.floor((width - 6) / 7);
+    rect.setAttribute("x", x);
+    rect.setAttribute("width", width);
+    text.setAttribute("x", x + 3);
+    text.textContent = fits < 3 ? "" : (label.length <= fits ? label : label.substring(0, fits - 2) + "..");
+}
+function zoom(target) {
+    var rect = target.getElementsByTagName("rect")[0];
+    var x = parseFloat(rect.getAttribute("data-x")), width = parseFloat(rect.getAttribute("data-width"));
+    var y = parseFloat(rect.getAttribute("y"));
+    var left = parseFloat(frames.getElementsByTagName("rect")[0].getAttribute("data-x"));
+    var full = parseFloat(frames.getElementsByTagName("rect")[0].getAttribute("data-width"));
+    var ratio = full / width;
+    var groups = frames.getElementsByTagName("g");
+    for (var i = 0; i < groups.length; i++) {
+        var r = groups[i].getElementsByTagName("rect")[0];
+        var gx = parseFloat(r.getAttribute("data-x")), gw = parseFloat(r.getAttribute("data-width"));
+        var gy = parseFloat(r.getAttribute("y"));
+        var inside = gx >= x - 0.1 && gx + gw <= x + width + 0.1;
+        var contains = gx <= x + 0.1 && gx + gw >= x + width - 0.1;
+        if (gy <= y && inside) {
+            groups[i].classList.remove("hidden");
+            fit(groups[i], left + (gx - x) * ratio, gw * ratio);
+        } else if (gy > y && contains) {
+            groups[i].classList.remove("hidden");
+            fit(groups[i], left, full);
+        } else {
+            groups[i].classList.add("hidden");
+        }
+    }
+    unzoom.classList.remove("hidden");
+}
+function reset() {
+    var groups = frames.getElementsByTagName("g");
+    for (var i = 0; i < groups.length; i++) {
+        var r = groups[i].getElementsByTagName("rect")[0];
+        groups[i].classList.remove("hidden");
+        fit(groups[i], parseFloat(r.getAttribute("data-x")), parseFloat(r.getAttribute("data-width")));
+    }
+    unzoom.classList.add("hidden");
+}
+function search() {
+    var term = prompt("Search for frames matching (regular expression):", "");
+    var pattern = null;
+    if (term) {
+        try {
+            pattern = new RegExp(term);
+        } catch (e) {
+            // Terms that aren't valid regular expressions are matched literally
+            pattern = new RegExp(term.replace(/[.*+?^${}()|[\]\\]/g, "\\$&"));
+        }
+    }
+    var groups = frames.getElementsByTagName("g");
+    for (var i = 0; i < groups.length; i++) {
+        var r = groups[i].getElementsByTagName("rect")[0];
+        r.setAttribute("fill", pattern && pattern.test(name(groups[i])) ? "rgb(230,0,230)" : r.getAttribute("data-fill"));
+    }
+}
+"#;
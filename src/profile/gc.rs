@@ -0,0 +1,238 @@
+use super::histogram::Histogram;
+use std::collections::VecDeque;
+
+/// Maximum number of finished pauses waiting to be reported, older ones are dropped
+const MAX_UNREPORTED: usize = 1024;
+
+///
+/// A single garbage collection pause. Times are in nanoseconds. The number of freed objects only
+/// covers tagged objects, ie. the sampled allocations the agent has been notified of, so it's a
+/// sample of the freed objects rather than their total.
+///
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct GcPause {
+    pub start: u64,
+    pub end: u64,
+    pub freed: u64,
+    /// Set if the pause took longer than the configured threshold
+    pub over_threshold: bool,
+    /// Set if the pauses within the budget window, including this one, took longer than the budget
+    pub over_budget: bool
+}
+
+impl GcPause {
+
+    pub fn duration(&self) -> u64 {
+        self.end.saturating_sub(self.start)
+    }
+}
+
+///
+/// Aggregates garbage collection pauses into overall statistics and keeps a timeline of the most
+/// recent ones. Pauses are checked against two limits: a threshold that no single pause should
+/// exceed and a budget for the total pause time within a sliding window. A limit of 0 disables it.
+///
+pub struct GcPauses {
+    threshold: u64,
+    budget: u64,
+    budget_window: u64,
+    timeline_size: usize,
+    current: Option<u64>,
+    freed_in_current: u64,
+    timeline: VecDeque<GcPause>,
+    /// Finished pauses that haven't been taken by `take_unreported` yet
+    unreported: VecDeque<GcPause>,
+    /// End and duration of the pauses that are still within the budget window
+    recent: VecDeque<(u64, u64)>,
+    histogram: Histogram,
+    total_time: u64,
+    max_time: u64,
+    freed: u64,
+    over_threshold: u64,
+    over_budget: u64
+}
+
+impl GcPauses {
+
+    /// Create a new, empty set of pause statistics keeping at most `timeline_size` pauses. Times
+    /// are in nanoseconds.
+    pub fn new(threshold: u64, budget: u64, budget_window: u64, timeline_size: usize) -> GcPauses {
+        GcPauses {
+            threshold: threshold,
+            budget: budget,
+            budget_window: budget_window,
+            timeline_size: timeline_size,
+            current: None,
+            freed_in_current: 0,
+            timeline: VecDeque::new(),
+            unreported: VecDeque::new(),
+            recent: VecDeque::new(),
+            histogram: Histogram::new(),
+            total_time: 0,
+            max_time: 0,
+            freed: 0,
+            over_threshold: 0,
+            over_budget: 0
+        }
+    }
+
+    /// Record that a garbage collection has started at the given time
+    pub fn start(&mut self, time: u64) {
+        self.current = Some(time);
+        self.freed_in_current = 0;
+    }
+
+    /// Record that the garbage collection in progress has finished at the given time and return
+    /// the pause. Returns `None` if no collection has been started.
+    pub fn finish(&mut self, time: u64) -> Option<GcPause> {
+        let start = match self.current.take() {
+            Some(start) => start,
+            None => return None
+        };
+
+        let duration = time.saturating_sub(start);
+
+        self.recent.push_back((time, duration));
+
+        while self.recent.front().map(|&(end, _)| end.saturating_add(self.budget_window) <= time).unwrap_or(false) {
+            self.recent.pop_front();
+        }
+
+        let pause = GcPause {
+            start: start,
+            end: time,
+            freed: self.freed_in_current,
+            over_threshold: self.threshold > 0 && duration > self.threshold,
+            over_budget: self.budget > 0 && self.recent.iter().map(|&(_, duration)| duration).sum::<u64>() > self.budget
+        };
+
+        self.histogram.record(duration);
+        self.total_time += duration;
+        self.max_time = self.max_time.max(duration);
+
+        if pause.over_threshold {
+            self.over_threshold += 1;
+        }
+
+        if pause.over_budget {
+            self.over_budget += 1;
+        }
+
+        if self.timeline_size > 0 {
+            if self.timeline.len() == self.timeline_size {
+                self.timeline.pop_front();
+            }
+
+            self.timeline.push_back(pause.clone());
+        }
+
+        if self.unreported.len() == MAX_UNREPORTED {
+            self.unreported.pop_front();
+        }
+
+        self.unreported.push_back(pause.clone());
+        Some(pause)
+    }
+
+    ///
+    /// Record that a tagged object has been freed. Object free events may be reported after the
+    /// collection that freed them has finished, so these are attributed to the last pause unless a
+    /// collection is in progress.
+    ///
+    pub fn object_freed(&mut self) {
+        self.freed += 1;
+
+        match self.current {
+            Some(_) => self.freed_in_current += 1,
+            None => {
+                if let Some(pause) = self.timeline.back_mut() {
+                    pause.freed += 1;
+                }
+
+                if let Some(pause) = self.unreported.back_mut() {
+                    pause.freed += 1;
+                }
+            }
+        }
+    }
+
+    /// Remove and return the finished pauses that haven't been taken yet, starting with the oldest
+    pub fn take_unreported(&mut self) -> Vec<GcPause> {
+        self.unreported.drain(..).collect()
+    }
+
+    /// Return the number of pauses recorded
+    pub fn count(&self) -> u64 {
+        self.histogram.count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count() == 0
+    }
+
+    pub fn total_time(&self) -> u64 {
+        self.total_time
+    }
+
+    pub fn max_time(&self) -> u64 {
+        self.max_time
+    }
+
+    /// Return the distribution of the pause times
+    pub fn histogram(&self) -> &Histogram {
+        &self.histogram
+    }
+
+    /// Return an estimate of the pause time below which the given fraction of pauses fall
+    pub fn percentile(&self, fraction: f64) -> u64 {
+        self.histogram.percentile(fraction)
+    }
+
+    pub fn p50(&self) -> u64 {
+        self.percentile(0.5)
+    }
+
+    pub fn p99(&self) -> u64 {
+        self.percentile(0.99)
+    }
+
+    /// Return the total number of freed sampled objects
+    pub fn freed(&self) -> u64 {
+        self.freed
+    }
+
+    /// Return the number of pauses that have exceeded the pause threshold
+    pub fn over_threshold(&self) -> u64 {
+        self.over_threshold
+    }
+
+    /// Return the number of pauses that have exceeded the pause time budget
+    pub fn over_budget(&self) -> u64 {
+        self.over_budget
+    }
+
+    /// Render the statistics of the pauses as a single line, times in milliseconds
+    pub fn render_summary(&self) -> String {
+        format!("GC pauses: {} (total: {:.3} ms, p50: {:.3} ms, p99: {:.3} ms, max: {:.3} ms, over threshold: {}, over budget: {}, sampled objects freed: {})",
+            self.count(), self.total_time() as f64 / 1e6, self.p50() as f64 / 1e6, self.p99() as f64 / 1e6, self.max_time() as f64 / 1e6,
+            self.over_threshold(), self.over_budget(), self.freed())
+    }
+
+    /// Return the most recent pauses, starting with the oldest one
+    pub fn timeline(&self) -> Vec<GcPause> {
+        self.timeline.iter().cloned().collect()
+    }
+
+    /// Clear the collected statistics, keeping the collection that's still in progress and the
+    /// pauses that haven't been taken yet
+    pub fn reset(&mut self) {
+        self.timeline.clear();
+        self.recent.clear();
+        self.histogram.reset();
+        self.total_time = 0;
+        self.max_time = 0;
+        self.freed = 0;
+        self.over_threshold = 0;
+        self.over_budget = 0;
+    }
+}
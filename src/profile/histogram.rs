@@ -0,0 +1,120 @@
+/// Number of sub-buckets each power of two range is split into. Values are recorded with a relative
+/// error of at most 1 / SUB_BUCKETS.
+const SUB_BUCKETS: u64 = 8;
+const SUB_BUCKET_BITS: u32 = 3;
+
+///
+/// A fixed size, log-linear histogram of non-negative values (typically durations in nanoseconds).
+/// Every power of two range is split into a handful of linear buckets so that percentiles can be
+/// estimated with a bounded relative error using constant memory, regardless of the value range.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct Histogram {
+    counts: Vec<u64>,
+    total: u64,
+    max: u64
+}
+
+impl Histogram {
+
+    pub fn new() -> Histogram {
+        Histogram { counts: vec![0; Histogram::bucket_of(::std::u64::MAX) + 1], total: 0, max: 0 }
+    }
+
+    pub fn record(&mut self, value: u64) {
+        self.counts[Histogram::bucket_of(value)] += 1;
+        self.total += 1;
+
+        if value > self.max {
+            self.max = value;
+        }
+    }
+
+    /// Return the number of recorded values
+    pub fn count(&self) -> u64 {
+        self.total
+    }
+
+    ///
+    /// Return an estimate of the value below which the given fraction (between 0.0 and 1.0) of the
+    /// recorded values fall. The estimate never exceeds the largest recorded value.
+    ///
+    pub fn percentile(&self, fraction: f64) -> u64 {
+        if self.total == 0 {
+            return 0;
+        }
+
+        let rank = ((fraction * self.total as f64).ceil() as u64).max(1).min(self.total);
+        let mut seen = 0;
+
+        for (bucket, count) in self.counts.iter().enumerate() {
+            seen += *count;
+
+            if seen >= rank {
+                return Histogram::upper_bound_of(bucket).min(self.max);
+            }
+        }
+
+        self.max
+    }
+
+    ///
+    /// Return the number of recorded values that are certainly not greater than the given value,
+    /// ie. the ones in buckets whose range ends at or below it.
+    ///
+    pub fn count_at_most(&self, value: u64) -> u64 {
+        self.counts.iter().enumerate()
+            .take_while(|&(bucket, _)| Histogram::upper_bound_of(bucket) <= value)
+            .map(|(_, count)| *count)
+            .sum()
+    }
+
+    /// Merge the values recorded by another histogram into this one
+    pub fn merge(&mut self, other: &Histogram) {
+        for (count, other_count) in self.counts.iter_mut().zip(other.counts.iter()) {
+            *count += *other_count;
+        }
+
+        self.total += other.total;
+        self.max = self.max.max(other.max);
+    }
+
+    pub fn reset(&mut self) {
+        for count in self.counts.iter_mut() {
+            *count = 0;
+        }
+
+        self.total = 0;
+        self.max = 0;
+    }
+
+    fn bucket_of(value: u64) -> usize {
+        if value < SUB_BUCKETS {
+            value as usize
+        } else {
+            let exponent = 63 - value.leading_zeros();
+            let mantissa = (value >> (exponent - SUB_BUCKET_BITS)) & (SUB_BUCKETS - 1);
+
+            ((exponent - SUB_BUCKET_BITS + 1) as u64 * SUB_BUCKETS + mantissa) as usize
+        }
+    }
+
+    fn upper_bound_of(bucket: usize) -> u64 {
+        let bucket = bucket as u64;
+
+        if bucket < SUB_BUCKETS {
+            bucket
+        } else {
+            let shift = bucket / SUB_BUCKETS - 1;
+            let mantissa = bucket % SUB_BUCKETS;
+
+            ((SUB_BUCKETS + mantissa + 1) << shift).wrapping_sub(1)
+        }
+    }
+}
+
+impl Default for Histogram {
+    fn default() -> Histogram {
+        Histogram::new()
+    }
+}
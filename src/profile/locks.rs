@@ -0,0 +1,180 @@
+use super::super::runtime::{MonitorEvent, MonitorWaitEvent, MonitorWaitedEvent};
+use super::super::stack::StackTraceElement;
+use std::collections::HashMap;
+
+///
+/// Identifies the place where threads block or wait on monitors: the class of the monitor object
+/// and the topmost frames of the blocked thread's stack.
+///
+#[derive(Eq, PartialEq, Hash, Clone, Debug)]
+pub struct ContentionSite {
+    pub monitor_class: String,
+    pub frames: Vec<String>
+}
+
+impl ContentionSite {
+
+    pub fn to_string(&self) -> String {
+        match self.frames.is_empty() {
+            true => format!("{} <unknown>", self.monitor_class),
+            false => format!("{} at {}", self.monitor_class, self.frames.join(" <- "))
+        }
+    }
+}
+
+/// Aggregated statistics of the time threads have been blocked on entering contended monitors.
+/// All times are in nanoseconds.
+#[derive(Eq, PartialEq, Clone, Debug, Default)]
+pub struct ContentionStats {
+    pub count: u64,
+    pub total_time: u64,
+    pub max_time: u64
+}
+
+/// Aggregated statistics of the time threads have spent in `Object.wait`. All times are in
+/// nanoseconds.
+#[derive(Eq, PartialEq, Clone, Debug, Default)]
+pub struct WaitStats {
+    pub count: u64,
+    pub total_time: u64,
+    pub max_time: u64,
+    /// Number of waits with a timeout
+    pub timed_waits: u64,
+    /// Number of waits that have been ended by their timeout
+    pub timed_out: u64
+}
+
+/// A wait that hasn't finished yet
+struct PendingWait {
+    site: ContentionSite,
+    start: u64,
+    timed: bool
+}
+
+///
+/// Aggregates monitor contention and `Object.wait` calls by contention site. Blocking on a
+/// contended monitor and waiting on an object are kept apart, as the latter is usually intended.
+///
+pub struct LockProfile {
+    depth: usize,
+    contended: HashMap<ContentionSite, ContentionStats>,
+    waits: HashMap<ContentionSite, WaitStats>,
+    pending_enters: HashMap<i64, (ContentionSite, u64)>,
+    pending_waits: HashMap<i64, PendingWait>
+}
+
+impl LockProfile {
+
+    /// Create a new, empty profile that distinguishes contention sites by the topmost `depth`
+    /// stack frames.
+    pub fn new(depth: usize) -> LockProfile {
+        LockProfile { depth: depth, contended: HashMap::new(), waits: HashMap::new(), pending_enters: HashMap::new(), pending_waits: HashMap::new() }
+    }
+
+    /// Record that a thread has started blocking on a contended monitor at the given time (in
+    /// nanoseconds)
+    pub fn contended_enter(&mut self, event: &MonitorEvent, time: u64) {
+        let site = self.site(event.class_sig.to_string(), &event.stack_trace);
+
+        self.pending_enters.insert(event.thread.java_id, (site, time));
+    }
+
+    /// Record that a thread has entered the monitor it's been blocked on and return the time it's
+    /// been blocked for
+    pub fn contended_entered(&mut self, thread_id: i64, time: u64) -> Option<u64> {
+        self.pending_enters.remove(&thread_id).map(|(site, start)| {
+            let elapsed = time.saturating_sub(start);
+            let stats = self.contended.entry(site).or_insert_with(ContentionStats::default);
+
+            stats.count += 1;
+            stats.total_time += elapsed;
+            stats.max_time = stats.max_time.max(elapsed);
+            elapsed
+        })
+    }
+
+    /// Record that a thread has started waiting on an object at the given time (in nanoseconds)
+    pub fn wait_start(&mut self, event: &MonitorWaitEvent, time: u64) {
+        let site = self.site(event.class_sig.to_string(), &event.stack_trace);
+
+        self.pending_waits.insert(event.thread.java_id, PendingWait { site: site, start: time, timed: event.timeout > 0 });
+    }
+
+    /// Record that a thread has finished waiting on an object and return the time it's been
+    /// waiting for
+    pub fn wait_end(&mut self, event: &MonitorWaitedEvent, time: u64) -> Option<u64> {
+        self.pending_waits.remove(&event.thread.java_id).map(|wait| {
+            let elapsed = time.saturating_sub(wait.start);
+            let stats = self.waits.entry(wait.site).or_insert_with(WaitStats::default);
+
+            stats.count += 1;
+            stats.total_time += elapsed;
+            stats.max_time = stats.max_time.max(elapsed);
+
+            if wait.timed {
+                stats.timed_waits += 1;
+            }
+
+            if event.timed_out {
+                stats.timed_out += 1;
+            }
+
+            elapsed
+        })
+    }
+
+    pub fn contention(&self, site: &ContentionSite) -> Option<&ContentionStats> {
+        self.contended.get(site)
+    }
+
+    pub fn wait(&self, site: &ContentionSite) -> Option<&WaitStats> {
+        self.waits.get(site)
+    }
+
+    /// Return the number of distinct contention and wait sites recorded
+    pub fn len(&self) -> usize {
+        self.contended.len() + self.waits.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.contended.is_empty() && self.waits.is_empty()
+    }
+
+    /// Return at most `limit` contended monitor sites, ordered by the total time threads have been
+    /// blocked there
+    pub fn top_contended(&self, limit: usize) -> Vec<(ContentionSite, ContentionStats)> {
+        let mut sites: Vec<(ContentionSite, ContentionStats)> = self.contended.iter().map(|(site, stats)| (site.clone(), stats.clone())).collect();
+
+        sites.sort_by(|a, b| b.1.total_time.cmp(&a.1.total_time).then(b.1.count.cmp(&a.1.count)));
+        sites.truncate(limit);
+        sites
+    }
+
+    /// Return at most `limit` wait sites, ordered by the total time threads have been waiting there
+    pub fn top_waits(&self, limit: usize) -> Vec<(ContentionSite, WaitStats)> {
+        let mut sites: Vec<(ContentionSite, WaitStats)> = self.waits.iter().map(|(site, stats)| (site.clone(), stats.clone())).collect();
+
+        sites.sort_by(|a, b| b.1.total_time.cmp(&a.1.total_time).then(b.1.count.cmp(&a.1.count)));
+        sites.truncate(limit);
+        sites
+    }
+
+    /// Drop the blocking and waiting still in progress on the given thread
+    pub fn thread_end(&mut self, thread_id: i64) {
+        self.pending_enters.remove(&thread_id);
+        self.pending_waits.remove(&thread_id);
+    }
+
+    /// Clear the collected statistics, keeping the blocking and waiting that's still in progress
+    pub fn reset(&mut self) {
+        self.contended.clear();
+        self.waits.clear();
+    }
+
+    fn site(&self, monitor_class: String, stack_trace: &[StackTraceElement]) -> ContentionSite {
+        ContentionSite {
+            monitor_class: monitor_class,
+            frames: stack_trace.iter().take(self.depth).map(|frame| frame.to_string()).collect()
+        }
+    }
+}
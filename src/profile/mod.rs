@@ -0,0 +1,17 @@
+///
+/// Collection of aggregators that turn the raw runtime events reported by the JVM into profiles
+/// that can be queried or exported later on.
+///
+pub mod alloc;
+pub mod calltree;
+pub mod exceptions;
+pub mod flamegraph;
+pub mod gc;
+pub mod histogram;
+pub mod locks;
+pub mod sampling;
+pub mod slow;
+pub mod threads;
+pub mod timeline;
+pub mod timing;
+pub mod trace;
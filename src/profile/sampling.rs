@@ -0,0 +1,73 @@
+use std::collections::HashMap;
+
+///
+/// Aggregates sampled stack traces into a folded stack profile: every distinct stack is stored as
+/// a single line of semicolon separated frames, from the outermost frame to the innermost one,
+/// along with the number of times it has been sampled.
+///
+pub struct SampleProfile {
+    stacks: HashMap<String, u64>,
+    samples: u64
+}
+
+impl SampleProfile {
+
+    pub fn new() -> SampleProfile {
+        SampleProfile { stacks: HashMap::new(), samples: 0 }
+    }
+
+    /// Record a sampled stack. Frames are expected in the order the JVM reports them, ie. starting
+    /// with the currently executing method.
+    pub fn record(&mut self, frames: &[String]) {
+        if frames.is_empty() {
+            return;
+        }
+
+        let folded = frames.iter().rev().map(|frame| frame.replace(';', ":")).collect::<Vec<String>>().join(";");
+
+        *self.stacks.entry(folded).or_insert(0) += 1;
+        self.samples += 1;
+    }
+
+    /// Return the number of times the given folded stack has been sampled
+    pub fn get(&self, folded_stack: &str) -> u64 {
+        self.stacks.get(folded_stack).cloned().unwrap_or(0)
+    }
+
+    /// Return the total number of samples recorded
+    pub fn samples(&self) -> u64 {
+        self.samples
+    }
+
+    pub fn len(&self) -> usize {
+        self.stacks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stacks.is_empty()
+    }
+
+    /// Return the distinct stacks with their sample counts, ordered by the stacks
+    pub fn stacks(&self) -> Vec<(String, u64)> {
+        let mut stacks: Vec<(String, u64)> = self.stacks.iter().map(|(stack, count)| (stack.clone(), *count)).collect();
+
+        stacks.sort();
+        stacks
+    }
+
+    /// Return the profile in the folded stack format, one `frame;frame;frame count` line per stack
+    pub fn folded_lines(&self) -> Vec<String> {
+        self.stacks().iter().map(|&(ref stack, count)| format!("{} {}", stack, count)).collect()
+    }
+
+    pub fn reset(&mut self) {
+        self.stacks.clear();
+        self.samples = 0;
+    }
+}
+
+impl Default for SampleProfile {
+    fn default() -> SampleProfile {
+        SampleProfile::new()
+    }
+}
@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+
+///
+/// An invocation of a watched method that has taken longer than the threshold. Times are in
+/// nanoseconds.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct SlowInvocation {
+    pub method: String,
+    pub thread_name: String,
+    /// The values of the arguments the method has been called with, in their declared order
+    pub arguments: Vec<String>,
+    pub start: u64,
+    pub duration: u64,
+    /// Set if the method has been left by throwing an exception
+    pub exception: bool,
+    /// The stack of the thread when the method has exited, starting with the method itself
+    pub stack_trace: Vec<String>
+}
+
+impl SlowInvocation {
+
+    /// Render the invocation as a line describing the call followed by its stack trace
+    pub fn render_lines(&self) -> Vec<String> {
+        let mut lines = vec![ format!("Slow invocation of {}({}) on {} took {:.3} ms{}",
+            self.method, self.arguments.join(", "), self.thread_name, self.duration as f64 / 1e6, if self.exception { ", exception thrown" } else { "" }) ];
+
+        for frame in self.stack_trace.iter() {
+            lines.push(format!("  at {}", frame));
+        }
+
+        lines
+    }
+}
+
+/// A watched method that has been entered but not exited yet
+struct PendingInvocation {
+    method: String,
+    arguments: Vec<String>,
+    start: u64
+}
+
+///
+/// Times the invocations of watched methods along with the arguments they've been called with,
+/// and keeps the slowest of those that have exceeded the threshold.
+///
+pub struct SlowInvocationDetector {
+    threshold: u64,
+    max_reports: usize,
+    pending: HashMap<i64, Vec<PendingInvocation>>,
+    slowest: Vec<SlowInvocation>,
+    count: u64
+}
+
+impl SlowInvocationDetector {
+
+    /// Create a detector reporting invocations that take at least `threshold` nanoseconds and
+    /// keeping the `max_reports` slowest ones
+    pub fn new(threshold: u64, max_reports: usize) -> SlowInvocationDetector {
+        SlowInvocationDetector { threshold: threshold, max_reports: max_reports, pending: HashMap::new(), slowest: vec![], count: 0 }
+    }
+
+    ///
+    /// Record that a watched method has been entered with the given arguments. Returns true if the
+    /// thread hasn't been inside any watched method before, ie. the thread has to be watched from
+    /// now on.
+    ///
+    pub fn enter(&mut self, thread_id: i64, method: &str, arguments: Vec<String>, time: u64) -> bool {
+        let invocations = self.pending.entry(thread_id).or_insert_with(Vec::new);
+
+        invocations.push(PendingInvocation { method: method.to_string(), arguments: arguments, start: time });
+        invocations.len() == 1
+    }
+
+    ///
+    /// Record that a watched method has exited and return the invocation if it's been slow. The
+    /// stack trace of the returned invocation is left empty to be filled in by the caller.
+    ///
+    pub fn exit(&mut self, thread_id: i64, thread_name: &str, method: &str, time: u64, exception: bool) -> Option<SlowInvocation> {
+        let invocation = match self.pending.get_mut(&thread_id) {
+            Some(ref mut invocations) if invocations.last().map(|invocation| invocation.method == method).unwrap_or(false) => invocations.pop(),
+            _ => None
+        };
+
+        if self.pending.get(&thread_id).map(|invocations| invocations.is_empty()).unwrap_or(false) {
+            self.pending.remove(&thread_id);
+        }
+
+        match invocation {
+            Some(invocation) if time.saturating_sub(invocation.start) >= self.threshold => Some(SlowInvocation {
+                method: invocation.method,
+                thread_name: thread_name.to_string(),
+                arguments: invocation.arguments,
+                start: invocation.start,
+                duration: time.saturating_sub(invocation.start),
+                exception: exception,
+                stack_trace: vec![]
+            }),
+            _ => None
+        }
+    }
+
+    /// Return true if the given thread is inside a watched method
+    pub fn is_watching(&self, thread_id: i64) -> bool {
+        self.pending.contains_key(&thread_id)
+    }
+
+    /// Keep a slow invocation if it's among the slowest ones seen so far
+    pub fn record(&mut self, invocation: SlowInvocation) {
+        self.count += 1;
+
+        if self.max_reports > 0 {
+            let idx = self.slowest.iter().position(|other| other.duration < invocation.duration).unwrap_or(self.slowest.len());
+
+            self.slowest.insert(idx, invocation);
+            self.slowest.truncate(self.max_reports);
+        }
+    }
+
+    /// Return the number of slow invocations recorded
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Return at most `limit` of the slowest invocations kept, starting with the slowest one
+    pub fn slowest(&self, limit: usize) -> Vec<SlowInvocation> {
+        self.slowest.iter().take(limit).cloned().collect()
+    }
+
+    /// Drop the watched methods the given thread is inside of
+    pub fn thread_end(&mut self, thread_id: i64) {
+        self.pending.remove(&thread_id);
+    }
+
+    pub fn reset(&mut self) {
+        self.slowest.clear();
+        self.count = 0;
+    }
+}
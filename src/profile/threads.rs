@@ -0,0 +1,167 @@
+use super::super::thread::Thread;
+use std::collections::{HashMap, VecDeque};
+
+///
+/// The lifetime and CPU usage of a single thread. Timestamps are wall clock times in nanoseconds
+/// since the Unix epoch, CPU times are in nanoseconds.
+///
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct ThreadRecord {
+    pub java_id: i64,
+    pub name: String,
+    pub is_daemon: bool,
+    pub priority: u32,
+    /// When the thread has started or, for threads started before the agent, first been seen
+    pub start_time: u64,
+    pub end_time: Option<u64>,
+    pub cpu_time: u64,
+    /// CPU time used since the previous sample
+    pub recent_cpu_time: u64
+}
+
+impl ThreadRecord {
+
+    pub fn new(java_id: i64, thread: &Thread, start_time: u64) -> ThreadRecord {
+        ThreadRecord {
+            java_id: java_id,
+            name: thread.name.clone(),
+            is_daemon: thread.is_daemon,
+            priority: thread.priority,
+            start_time: start_time,
+            end_time: None,
+            cpu_time: 0,
+            recent_cpu_time: 0
+        }
+    }
+
+    pub fn is_alive(&self) -> bool {
+        self.end_time.is_none()
+    }
+
+    /// Return how long the thread has lived until it ended or, if it's still alive, until `now`
+    pub fn lifetime(&self, now: u64) -> u64 {
+        self.end_time.unwrap_or(now).saturating_sub(self.start_time)
+    }
+
+    fn update_cpu_time(&mut self, cpu_time: u64) {
+        self.recent_cpu_time = cpu_time.saturating_sub(self.cpu_time);
+        self.cpu_time = self.cpu_time.max(cpu_time);
+    }
+}
+
+///
+/// Keeps a record of every live thread and the most recently ended ones, identified by their Java
+/// thread ids. Besides the records, the number of started and ended threads is counted so that
+/// thread pools that keep replacing their threads stand out.
+///
+pub struct ThreadTable {
+    max_finished: usize,
+    live: HashMap<i64, ThreadRecord>,
+    finished: VecDeque<ThreadRecord>,
+    started: u64,
+    ended: u64
+}
+
+impl ThreadTable {
+
+    /// Create a new, empty table keeping at most `max_finished` ended threads
+    pub fn new(max_finished: usize) -> ThreadTable {
+        ThreadTable { max_finished: max_finished, live: HashMap::new(), finished: VecDeque::new(), started: 0, ended: 0 }
+    }
+
+    /// Record that a thread has started at the given time
+    pub fn start(&mut self, java_id: i64, thread: &Thread, time: u64) {
+        self.live.insert(java_id, ThreadRecord::new(java_id, thread, time));
+        self.started += 1;
+    }
+
+    ///
+    /// Record that a thread has ended at the given time, having used `cpu_time` nanoseconds of CPU
+    /// time if known, and return its final record. Threads that have never been seen before are
+    /// recorded as if they had started when they ended.
+    ///
+    pub fn end(&mut self, java_id: i64, thread: &Thread, time: u64, cpu_time: Option<u64>) -> ThreadRecord {
+        let mut record = self.live.remove(&java_id).unwrap_or_else(|| ThreadRecord::new(java_id, thread, time));
+
+        record.end_time = Some(time);
+        record.recent_cpu_time = 0;
+
+        if let Some(cpu_time) = cpu_time {
+            record.cpu_time = record.cpu_time.max(cpu_time);
+        }
+
+        self.ended += 1;
+
+        if self.max_finished > 0 {
+            if self.finished.len() == self.max_finished {
+                self.finished.pop_front();
+            }
+
+            self.finished.push_back(record.clone());
+        }
+
+        record
+    }
+
+    ///
+    /// Record a sample of the CPU time used by a live thread so far. Threads that have been
+    /// started before the agent are added when they're sampled for the first time.
+    ///
+    pub fn cpu_sample(&mut self, java_id: i64, thread: &Thread, time: u64, cpu_time: u64) {
+        self.live.entry(java_id).or_insert_with(|| ThreadRecord::new(java_id, thread, time)).update_cpu_time(cpu_time);
+    }
+
+    /// Return the record of a live thread
+    pub fn get(&self, java_id: i64) -> Option<&ThreadRecord> {
+        self.live.get(&java_id)
+    }
+
+    /// Return the records of the live threads, ordered by their ids
+    pub fn live(&self) -> Vec<ThreadRecord> {
+        let mut records: Vec<ThreadRecord> = self.live.values().cloned().collect();
+
+        records.sort_by_key(|record| record.java_id);
+        records
+    }
+
+    /// Return the records of the most recently ended threads, starting with the oldest one
+    pub fn finished(&self) -> Vec<ThreadRecord> {
+        self.finished.iter().cloned().collect()
+    }
+
+    /// Return the number of threads started since the table has been created or reset
+    pub fn started(&self) -> u64 {
+        self.started
+    }
+
+    /// Return the number of threads ended since the table has been created or reset
+    pub fn ended(&self) -> u64 {
+        self.ended
+    }
+
+    /// Return at most `limit` threads, live or ended, ordered by the CPU time they've used
+    pub fn top_by_cpu(&self, limit: usize) -> Vec<ThreadRecord> {
+        let mut records: Vec<ThreadRecord> = self.live.values().chain(self.finished.iter()).cloned().collect();
+
+        records.sort_by(|a, b| b.cpu_time.cmp(&a.cpu_time).then(a.java_id.cmp(&b.java_id)));
+        records.truncate(limit);
+        records
+    }
+
+    /// Return at most `limit` live threads, ordered by the CPU time they've used since the previous
+    /// sample. Threads that stay on top of this list are likely to be spinning.
+    pub fn top_by_recent_cpu(&self, limit: usize) -> Vec<ThreadRecord> {
+        let mut records: Vec<ThreadRecord> = self.live.values().cloned().collect();
+
+        records.sort_by(|a, b| b.recent_cpu_time.cmp(&a.recent_cpu_time).then(a.java_id.cmp(&b.java_id)));
+        records.truncate(limit);
+        records
+    }
+
+    /// Forget the ended threads and reset the counters. Live threads are kept.
+    pub fn reset(&mut self) {
+        self.finished.clear();
+        self.started = 0;
+        self.ended = 0;
+    }
+}
@@ -0,0 +1,266 @@
+use super::gc::GcPause;
+use super::trace::{Span, Trace};
+use serde_json;
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::Path;
+
+/// Track the garbage collection pauses are shown on
+pub const GC_TRACK: u64 = 0;
+
+/// A single event of the Chrome Trace Event Format. Times are in microseconds.
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct TraceEvent {
+    pub name: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub cat: String,
+    /// The type of the event: `B` and `E` begin and end a span, `X` is a complete span and `M`
+    /// names a process or thread
+    pub ph: &'static str,
+    pub ts: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dur: Option<f64>,
+    pub pid: u32,
+    pub tid: u64,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub args: BTreeMap<String, String>
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ChromeTrace<'a> {
+    trace_events: Vec<&'a TraceEvent>,
+    display_time_unit: &'static str,
+    other_data: BTreeMap<&'static str, String>
+}
+
+///
+/// Records what each thread has been doing over time, to be exported in the Chrome Trace Event
+/// Format and loaded into chrome://tracing or the Perfetto UI. Every thread gets its own track and
+/// garbage collection pauses are shown on a separate one. Threads are told apart by their Java id,
+/// as names may be shared or changed, and the latest name of each thread is used as its label.
+///
+/// All times are in nanoseconds of the same monotonic clock and are exported relative to the time
+/// the timeline has been created. At most `max_events` events are kept, the rest are dropped.
+///
+pub struct Timeline {
+    origin: u64,
+    max_events: usize,
+    pid: u32,
+    /// The track and name of each thread, by Java thread id
+    tracks: HashMap<i64, (u64, String)>,
+    /// The start of the threads that are still alive
+    started: HashMap<i64, u64>,
+    /// The monitor class and wait start of the threads that are waiting
+    waits: HashMap<i64, (String, u64)>,
+    events: Vec<TraceEvent>,
+    dropped: u64
+}
+
+impl Timeline {
+
+    pub fn new(origin: u64, max_events: usize, pid: u32) -> Timeline {
+        Timeline {
+            origin: origin,
+            max_events: max_events,
+            pid: pid,
+            tracks: HashMap::new(),
+            started: HashMap::new(),
+            waits: HashMap::new(),
+            events: vec![],
+            dropped: 0
+        }
+    }
+
+    /// Return the number of events recorded
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Return the number of events dropped because the timeline was full
+    pub fn dropped(&self) -> u64 {
+        self.dropped
+    }
+
+    pub fn events(&self) -> &[TraceEvent] {
+        &self.events
+    }
+
+    /// Return the track of a thread, assigning it a new one if it has none yet. The name of the
+    /// track is updated if the thread has been renamed.
+    pub fn track(&mut self, thread_id: i64, thread_name: &str) -> u64 {
+        let next = self.tracks.len() as u64 + GC_TRACK + 1;
+        let track = self.tracks.entry(thread_id).or_insert_with(|| (next, String::new()));
+
+        if track.1 != thread_name {
+            track.1 = thread_name.to_string();
+        }
+
+        track.0
+    }
+
+    pub fn thread_start(&mut self, thread_id: i64, thread_name: &str, time: u64) {
+        self.track(thread_id, thread_name);
+        self.started.insert(thread_id, time);
+    }
+
+    /// Record the end of a thread, adding its lifetime if its start has been recorded
+    pub fn thread_end(&mut self, thread_id: i64, thread_name: &str, time: u64) {
+        if let Some(start) = self.started.remove(&thread_id) {
+            let tid = self.track(thread_id, thread_name);
+
+            self.complete(format!("Thread {}", thread_name), "thread", tid, start, time, BTreeMap::new());
+        }
+    }
+
+    pub fn wait_start(&mut self, thread_id: i64, monitor_class: &str, time: u64) {
+        self.waits.insert(thread_id, (monitor_class.to_string(), time));
+    }
+
+    pub fn wait_end(&mut self, thread_id: i64, thread_name: &str, timed_out: bool, time: u64) {
+        if let Some((monitor_class, start)) = self.waits.remove(&thread_id) {
+            let tid = self.track(thread_id, thread_name);
+            let mut args = BTreeMap::new();
+
+            args.insert("timed_out".to_string(), timed_out.to_string());
+            self.complete(format!("Wait on {}", monitor_class), "monitor", tid, start, time, args);
+        }
+    }
+
+    pub fn gc_pause(&mut self, pause: &GcPause) {
+        let mut args = BTreeMap::new();
+
+        args.insert("sampled_objects_freed".to_string(), pause.freed.to_string());
+        self.complete("GC pause".to_string(), "gc", GC_TRACK, pause.start, pause.end, args);
+    }
+
+    /// Add the spans of a completed trace as nested begin and end events. Traces that don't fit
+    /// are dropped as a whole.
+    pub fn trace(&mut self, trace: &Trace) {
+        let count = trace.root.span_count() * 2;
+
+        if self.events.len() + count > self.max_events {
+            self.dropped += count as u64;
+            return;
+        }
+
+        let tid = self.track(trace.thread_id, &trace.thread_name);
+
+        self.span(&trace.root, tid);
+    }
+
+    /// Record that a thread has entered a method, eg. while replaying a recording
+    pub fn begin(&mut self, thread_id: i64, thread_name: &str, method: &str, time: u64) {
+        let tid = self.track(thread_id, thread_name);
+        let event = self.event(method.to_string(), "trace", "B", tid, time, BTreeMap::new());
+
+        self.push(event);
+    }
+
+    /// Record that a thread has left the method it's entered last
+    pub fn end(&mut self, thread_id: i64, thread_name: &str, method: &str, time: u64, exception: bool) {
+        let tid = self.track(thread_id, thread_name);
+        let mut args = BTreeMap::new();
+
+        if exception {
+            args.insert("exception".to_string(), "true".to_string());
+        }
+
+        let event = self.event(method.to_string(), "trace", "E", tid, time, args);
+
+        self.push(event);
+    }
+
+    fn span(&mut self, span: &Span, tid: u64) {
+        let mut args = BTreeMap::new();
+
+        if span.exception {
+            args.insert("exception".to_string(), "true".to_string());
+        }
+
+        let begin = self.event(span.method.clone(), "trace", "B", tid, span.start, BTreeMap::new());
+        let end = self.event(span.method.clone(), "trace", "E", tid, span.end, args);
+
+        self.events.push(begin);
+
+        for child in span.children.iter() {
+            self.span(child, tid);
+        }
+
+        self.events.push(end);
+    }
+
+    fn complete(&mut self, name: String, category: &str, tid: u64, start: u64, end: u64, args: BTreeMap<String, String>) {
+        let mut event = self.event(name, category, "X", tid, start, args);
+
+        event.dur = Some(end.saturating_sub(start) as f64 / 1000.0);
+        self.push(event);
+    }
+
+    fn push(&mut self, event: TraceEvent) {
+        match self.events.len() < self.max_events {
+            true => self.events.push(event),
+            false => self.dropped += 1
+        }
+    }
+
+    fn event(&self, name: String, category: &str, phase: &'static str, tid: u64, time: u64, args: BTreeMap<String, String>) -> TraceEvent {
+        TraceEvent { name: name, cat: category.to_string(), ph: phase, ts: time.saturating_sub(self.origin) as f64 / 1000.0, dur: None, pid: self.pid, tid: tid, args: args }
+    }
+
+    fn metadata(&self, name: &str, tid: u64, value: &str) -> TraceEvent {
+        let mut args = BTreeMap::new();
+
+        args.insert("name".to_string(), value.to_string());
+        TraceEvent { name: name.to_string(), cat: String::new(), ph: "M", ts: 0.0, dur: None, pid: self.pid, tid: tid, args: args }
+    }
+
+    ///
+    /// Render the timeline as a Chrome trace JSON document. The lifetime of the threads still
+    /// alive is shown as ending at the given time.
+    ///
+    pub fn to_json(&self, now: u64) -> io::Result<String> {
+        let mut extra = vec![self.metadata("process_name", GC_TRACK, "JVM"), self.metadata("thread_name", GC_TRACK, "GC")];
+
+        extra.extend(self.tracks.values().map(|&(tid, ref name)| self.metadata("thread_name", tid, name)));
+
+        for (thread_id, &start) in self.started.iter() {
+            let (tid, name) = self.tracks.get(thread_id).cloned().unwrap_or((GC_TRACK, String::new()));
+            let mut event = self.event(format!("Thread {}", name), "thread", "X", tid, start, BTreeMap::new());
+
+            event.dur = Some(now.saturating_sub(start) as f64 / 1000.0);
+            event.args.insert("alive".to_string(), "true".to_string());
+            extra.push(event);
+        }
+
+        let mut other_data = BTreeMap::new();
+
+        other_data.insert("dropped_events", self.dropped.to_string());
+
+        let trace = ChromeTrace { trace_events: extra.iter().chain(self.events.iter()).collect(), display_time_unit: "ms", other_data: other_data };
+
+        serde_json::to_string(&trace).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    /// Write the timeline to the given file, creating its directory if needed
+    pub fn write<P: AsRef<Path>>(&self, path: P, now: u64) -> io::Result<()> {
+        if let Some(dir) = path.as_ref().parent() {
+            if !dir.as_os_str().is_empty() {
+                fs::create_dir_all(dir)?;
+            }
+        }
+
+        File::create(path)?.write_all(self.to_json(now)?.as_bytes())
+    }
+
+    pub fn reset(&mut self) {
+        self.events.clear();
+        self.waits.clear();
+        self.dropped = 0;
+    }
+}
@@ -0,0 +1,171 @@
+use super::histogram::Histogram;
+use std::collections::HashMap;
+
+///
+/// Aggregated timing statistics of a single method. All times are in nanoseconds. The total time
+/// includes the time spent in callees, while the self time doesn't.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct MethodStats {
+    pub count: u64,
+    pub exceptions: u64,
+    pub total_time: u64,
+    pub self_time: u64,
+    pub min_time: u64,
+    pub max_time: u64,
+    histogram: Histogram
+}
+
+impl MethodStats {
+
+    pub fn new() -> MethodStats {
+        MethodStats { count: 0, exceptions: 0, total_time: 0, self_time: 0, min_time: 0, max_time: 0, histogram: Histogram::new() }
+    }
+
+    /// Return the median of the invocation times
+    pub fn p50(&self) -> u64 {
+        self.histogram.percentile(0.5)
+    }
+
+    /// Return the 99th percentile of the invocation times
+    pub fn p99(&self) -> u64 {
+        self.histogram.percentile(0.99)
+    }
+
+    /// Return the distribution of the invocation times
+    pub fn histogram(&self) -> &Histogram {
+        &self.histogram
+    }
+
+    pub fn mean(&self) -> u64 {
+        match self.count {
+            0 => 0,
+            count => self.total_time / count
+        }
+    }
+
+    fn record(&mut self, elapsed: u64, self_time: u64, popped_by_exception: bool) {
+        self.min_time = if self.count == 0 { elapsed } else { self.min_time.min(elapsed) };
+        self.max_time = self.max_time.max(elapsed);
+        self.count += 1;
+        self.total_time += elapsed;
+        self.self_time += self_time;
+        self.histogram.record(elapsed);
+
+        if popped_by_exception {
+            self.exceptions += 1;
+        }
+    }
+}
+
+impl Default for MethodStats {
+    fn default() -> MethodStats {
+        MethodStats::new()
+    }
+}
+
+/// An invocation that hasn't returned yet
+struct Frame {
+    method: String,
+    start: u64,
+    child_time: u64
+}
+
+///
+/// Aggregates method entry and exit events into per-method timing statistics. Each thread has its
+/// own stack of active invocations, which is used to tell apart the time spent in a method itself
+/// from the time spent in the methods it called.
+///
+pub struct MethodTimings {
+    stacks: HashMap<i64, Vec<Frame>>,
+    methods: HashMap<String, MethodStats>
+}
+
+impl MethodTimings {
+
+    pub fn new() -> MethodTimings {
+        MethodTimings { stacks: HashMap::new(), methods: HashMap::new() }
+    }
+
+    /// Record that the given thread has entered a method at the given time
+    pub fn enter(&mut self, thread_id: i64, method: &str, time: u64) {
+        self.stacks.entry(thread_id).or_insert_with(Vec::new).push(Frame { method: method.to_string(), start: time, child_time: 0 });
+    }
+
+    ///
+    /// Record that the given thread has left a method at the given time and return the time spent
+    /// in the method. Frames above the exited method have been popped without their exit being
+    /// reported (eg. because events were disabled in the meantime), so they're closed at the same time.
+    /// Exits from methods that have been entered before the recording started are ignored.
+    ///
+    pub fn exit(&mut self, thread_id: i64, method: &str, time: u64, popped_by_exception: bool) -> Option<u64> {
+        let stack = match self.stacks.get_mut(&thread_id) {
+            Some(stack) => stack,
+            None => return None
+        };
+
+        let depth = match stack.iter().rposition(|frame| frame.method == method) {
+            Some(depth) => depth,
+            None => return None
+        };
+
+        let mut elapsed = 0;
+
+        while stack.len() > depth {
+            let frame = match stack.pop() {
+                Some(frame) => frame,
+                None => break
+            };
+
+            elapsed = time.saturating_sub(frame.start);
+
+            if let Some(parent) = stack.last_mut() {
+                parent.child_time += elapsed;
+            }
+
+            let stats = self.methods.entry(frame.method).or_insert_with(MethodStats::new);
+
+            stats.record(elapsed, elapsed.saturating_sub(frame.child_time), popped_by_exception);
+        }
+
+        Some(elapsed)
+    }
+
+    /// Drop the invocations still in progress on the given thread
+    pub fn thread_end(&mut self, thread_id: i64) {
+        self.stacks.remove(&thread_id);
+    }
+
+    pub fn get(&self, method: &str) -> Option<&MethodStats> {
+        self.methods.get(method)
+    }
+
+    pub fn len(&self) -> usize {
+        self.methods.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.methods.is_empty()
+    }
+
+    /// Return the methods with the most total time spent in them, in descending order
+    pub fn top_methods(&self, limit: usize) -> Vec<(String, MethodStats)> {
+        let mut methods: Vec<(String, MethodStats)> = self.methods.iter().map(|(method, stats)| (method.clone(), stats.clone())).collect();
+
+        methods.sort_by(|a, b| b.1.total_time.cmp(&a.1.total_time).then(b.1.count.cmp(&a.1.count)));
+        methods.truncate(limit);
+        methods
+    }
+
+    /// Clear the collected statistics. Invocations in progress are kept so that they're recorded
+    /// correctly once they return.
+    pub fn reset(&mut self) {
+        self.methods.clear();
+    }
+}
+
+impl Default for MethodTimings {
+    fn default() -> MethodTimings {
+        MethodTimings::new()
+    }
+}
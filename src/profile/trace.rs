@@ -0,0 +1,230 @@
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+
+///
+/// A single method invocation within a trace, along with the invocations made from it. Times are
+/// in nanoseconds.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct Span {
+    pub method: String,
+    pub start: u64,
+    pub end: u64,
+    /// Set if the method has been left by throwing an exception
+    pub exception: bool,
+    pub children: Vec<Span>
+}
+
+impl Span {
+
+    pub fn new(method: &str, start: u64) -> Span {
+        Span { method: method.to_string(), start: start, end: start, exception: false, children: vec![] }
+    }
+
+    pub fn duration(&self) -> u64 {
+        self.end.saturating_sub(self.start)
+    }
+
+    /// Return the number of spans in this tree, including this one
+    pub fn span_count(&self) -> usize {
+        1 + self.children.iter().map(|child| child.span_count()).sum::<usize>()
+    }
+
+    fn render_into(&self, depth: usize, lines: &mut Vec<String>) {
+        lines.push(format!("{}{} ({:.3} ms{})", "  ".repeat(depth), self.method, self.duration() as f64 / 1e6, if self.exception { ", exception" } else { "" }));
+
+        for child in self.children.iter() {
+            child.render_into(depth + 1, lines);
+        }
+    }
+}
+
+/// A completed trace of an entry point invocation
+#[derive(Clone, Debug, PartialEq)]
+pub struct Trace {
+    pub thread_id: i64,
+    pub thread_name: String,
+    pub root: Span,
+    /// Number of nested invocations left out because the depth or duration budget was exceeded
+    pub dropped: u64
+}
+
+impl Trace {
+
+    /// Render the trace as an indented list of spans, in the order they've been entered
+    pub fn render_lines(&self) -> Vec<String> {
+        let mut lines = vec![ format!("Trace of {} on {} ({} spans, {} dropped)", self.root.method, self.thread_name, self.root.span_count(), self.dropped) ];
+
+        self.root.render_into(1, &mut lines);
+        lines
+    }
+}
+
+/// A trace that's still being recorded
+struct ActiveTrace {
+    thread_name: String,
+    start: u64,
+    /// The spans that haven't been exited yet, starting with the entry point
+    stack: Vec<Span>,
+    /// Number of nested invocations in progress that aren't being recorded
+    skipped: usize,
+    dropped: u64
+}
+
+impl ActiveTrace {
+
+    /// Close the innermost open span and attach it to its parent. Returns the span if it was the
+    /// root of the trace.
+    fn close(&mut self, time: u64, exception: bool) -> Option<Span> {
+        match self.stack.pop() {
+            Some(mut span) => {
+                span.end = time;
+                span.exception = exception;
+
+                match self.stack.last_mut() {
+                    Some(parent) => {
+                        parent.children.push(span);
+                        None
+                    },
+                    None => Some(span)
+                }
+            },
+            None => None
+        }
+    }
+}
+
+///
+/// Records a span tree for every invocation of an entry point. Once an entry point is entered on
+/// a thread, all the invocations made from it are recorded until it exits, as long as they're at
+/// most `max_depth` levels deep and start within `max_duration` nanoseconds of the entry point.
+///
+pub struct TraceRecorder {
+    max_depth: usize,
+    max_duration: u64,
+    active: HashMap<i64, ActiveTrace>
+}
+
+impl TraceRecorder {
+
+    pub fn new(max_depth: usize, max_duration: u64) -> TraceRecorder {
+        TraceRecorder { max_depth: max_depth, max_duration: max_duration, active: HashMap::new() }
+    }
+
+    /// Return true if a trace is being recorded on the given thread
+    pub fn is_tracing(&self, thread_id: i64) -> bool {
+        self.active.contains_key(&thread_id)
+    }
+
+    ///
+    /// Record that a method has been entered. A new trace is started if the method is an entry
+    /// point and the thread isn't being traced yet, entry points called from within a trace are
+    /// recorded as regular spans.
+    ///
+    pub fn enter(&mut self, thread_id: i64, thread_name: &str, method: &str, time: u64, is_entry_point: bool) {
+        match self.active.get_mut(&thread_id) {
+            Some(trace) => {
+                if trace.skipped > 0 || trace.stack.len() >= self.max_depth || time.saturating_sub(trace.start) > self.max_duration {
+                    trace.skipped += 1;
+                    trace.dropped += 1;
+                } else {
+                    trace.stack.push(Span::new(method, time));
+                }
+
+                return;
+            },
+            None => if !is_entry_point {
+                return;
+            }
+        }
+
+        self.active.insert(thread_id, ActiveTrace { thread_name: thread_name.to_string(), start: time, stack: vec![ Span::new(method, time) ], skipped: 0, dropped: 0 });
+    }
+
+    ///
+    /// Record that a method has been left and return the completed trace if it was the entry point
+    /// of the trace being recorded on the thread.
+    ///
+    pub fn exit(&mut self, thread_id: i64, method: &str, time: u64, exception: bool) -> Option<Trace> {
+        let completed = match self.active.get_mut(&thread_id) {
+            Some(trace) => {
+                if trace.skipped > 0 {
+                    trace.skipped -= 1;
+                    return None;
+                }
+
+                let depth = match trace.stack.iter().rposition(|span| span.method == method) {
+                    Some(depth) => depth,
+                    None => return None
+                };
+
+                let mut root = None;
+
+                // Spans above the exited one have been popped without their exit being reported
+                while trace.stack.len() > depth {
+                    let popped_by_exception = exception || trace.stack.len() > depth + 1;
+                    root = trace.close(time, popped_by_exception);
+                }
+
+                root
+            },
+            None => return None
+        };
+
+        completed.and_then(|root| self.active.remove(&thread_id).map(|trace| Trace { thread_id: thread_id, thread_name: trace.thread_name, root: root, dropped: trace.dropped }))
+    }
+
+    /// Drop the trace being recorded on the given thread
+    pub fn thread_end(&mut self, thread_id: i64) {
+        self.active.remove(&thread_id);
+    }
+}
+
+/// Destination of completed traces
+pub trait TraceSink {
+    fn write_trace(&mut self, trace: &Trace) -> io::Result<()>;
+}
+
+/// Prints completed traces to the standard output
+pub struct ConsoleTraceSink;
+
+impl TraceSink for ConsoleTraceSink {
+    fn write_trace(&mut self, trace: &Trace) -> io::Result<()> {
+        let stdout = io::stdout();
+        let mut out = stdout.lock();
+
+        for line in trace.render_lines() {
+            writeln!(out, "{}", line)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Appends completed traces to a file
+pub struct FileTraceSink {
+    file: File
+}
+
+impl FileTraceSink {
+
+    /// Open the given file for appending, creating it along with its directory if necessary
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<FileTraceSink> {
+        if let Some(dir) = path.as_ref().parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        OpenOptions::new().create(true).append(true).open(path).map(|file| FileTraceSink { file: file })
+    }
+}
+
+impl TraceSink for FileTraceSink {
+    fn write_trace(&mut self, trace: &Trace) -> io::Result<()> {
+        let mut contents = trace.render_lines().join("\n");
+
+        contents.push('\n');
+        self.file.write_all(contents.as_bytes())
+    }
+}
@@ -0,0 +1,85 @@
+use super::Event;
+use super::super::profile::gc::GcPause;
+use super::super::profile::timeline::Timeline;
+use serde_json;
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+/// Names of the formats a recording can be converted to
+pub const FORMATS: [&'static str; 4] = ["text", "jsonl", "csv", "chrome"];
+
+/// Quote a CSV field if necessary
+fn csv_field(value: &str) -> String {
+    match value.contains(',') || value.contains('"') || value.contains('\n') {
+        true => format!("\"{}\"", value.replace("\"", "\"\"")),
+        false => value.to_string()
+    }
+}
+
+/// Write the events one per line, as rendered by `Event::render_line`
+pub fn write_text<W: Write, I: IntoIterator<Item=Event>>(events: I, out: &mut W) -> io::Result<()> {
+    for event in events {
+        writeln!(out, "{}", event.render_line())?;
+    }
+
+    Ok(())
+}
+
+/// Write the events as JSON objects, one per line, tagged with their type
+pub fn write_json_lines<W: Write, I: IntoIterator<Item=Event>>(events: I, out: &mut W) -> io::Result<()> {
+    for event in events {
+        serde_json::to_writer(&mut *out, &event).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        out.write_all(b"\n")?;
+    }
+
+    Ok(())
+}
+
+/// Write the events as CSV rows with the `time,type,thread,subject,value` columns
+pub fn write_csv<W: Write, I: IntoIterator<Item=Event>>(events: I, out: &mut W) -> io::Result<()> {
+    out.write_all(b"time,type,thread,subject,value\n")?;
+
+    for event in events {
+        writeln!(out, "{},{},{},{},{}", event.time(), event.kind(), csv_field(event.thread().unwrap_or("")), csv_field(&event.subject()),
+            event.value().map(|value| value.to_string()).unwrap_or_default())?;
+    }
+
+    Ok(())
+}
+
+///
+/// Replay the events into a timeline, starting at the time of the first event. Method entries and
+/// exits become spans, monitor contention isn't shown.
+///
+pub fn to_timeline<I: IntoIterator<Item=Event>>(events: I, max_events: usize, pid: u32) -> Timeline {
+    let mut timeline: Option<Timeline> = None;
+    // Recordings tell threads apart by name only, so every name stands for a thread of its own
+    let mut thread_ids: HashMap<String, i64> = HashMap::new();
+
+    for event in events {
+        let timeline = timeline.get_or_insert_with(|| Timeline::new(event.time(), max_events, pid));
+        let next = thread_ids.len() as i64 + 1;
+        let thread_id = event.thread().map(|thread| *thread_ids.entry(thread.to_string()).or_insert(next)).unwrap_or(0);
+
+        match event {
+            Event::ThreadStart { time, ref thread } => timeline.thread_start(thread_id, thread, time),
+            Event::ThreadEnd { time, ref thread, .. } => timeline.thread_end(thread_id, thread, time),
+            Event::MethodEntry { time, ref thread, .. } => timeline.begin(thread_id, thread, &event.subject(), time),
+            Event::MethodExit { time, ref thread, exception, .. } => timeline.end(thread_id, thread, &event.subject(), time, exception),
+            Event::GcPause { time, duration, freed } => timeline.gc_pause(&GcPause { start: time, end: time + duration, freed: freed, over_threshold: false, over_budget: false }),
+            Event::MonitorWait { time, ref class, .. } => timeline.wait_start(thread_id, class, time),
+            Event::MonitorWaited { time, ref thread, timed_out } => timeline.wait_end(thread_id, thread, timed_out, time),
+            _ => ()
+        }
+    }
+
+    timeline.unwrap_or_else(|| Timeline::new(0, max_events, pid))
+}
+
+/// Write the events in the Chrome Trace Event Format. Threads still alive end at the last event.
+pub fn write_chrome<W: Write>(events: Vec<Event>, out: &mut W, pid: u32) -> io::Result<()> {
+    let end = events.iter().map(|event| event.time()).max().unwrap_or(0);
+    let len = events.len() * 2;
+
+    out.write_all(to_timeline(events, len, pid).to_json(end)?.as_bytes())
+}
@@ -0,0 +1,348 @@
+//! Compact binary recording of the events reported by the JVM, meant for long running recordings.
+//!
+//! A recording starts with the `JVMTIREC` magic and a little endian `u16` format version, followed
+//! by chunks. Each chunk starts with the little endian `u32` length and CRC-32 of its payload, so
+//! corrupt chunks can be detected and skipped. The payload is the varint start time of the chunk in
+//! nanoseconds followed by records: string and method definitions, each introduced right before
+//! the first event of the chunk referring to it, and events. Events store the difference of their
+//! time from the previous event's as a zigzag varint, and refer to strings and methods by their
+//! index in the tables of the chunk.
+
+pub mod convert;
+pub mod reader;
+pub mod writer;
+
+use self::writer::RecordingWriter;
+use super::logging::logger;
+use std::cell::RefCell;
+use std::fs::{self, File};
+use std::io::{self, BufWriter};
+use std::path::Path;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread::{self, JoinHandle};
+
+/// Magic bytes every recording starts with
+pub const MAGIC: &'static [u8; 8] = b"JVMTIREC";
+/// Version of the format written
+pub const VERSION: u16 = 1;
+
+/// Tags of the records of a chunk
+pub mod tag {
+    pub const STRING: u8 = 0x01;
+    pub const METHOD: u8 = 0x02;
+    pub const THREAD_START: u8 = 0x10;
+    pub const THREAD_END: u8 = 0x11;
+    pub const METHOD_ENTRY: u8 = 0x12;
+    pub const METHOD_EXIT: u8 = 0x13;
+    pub const GC_PAUSE: u8 = 0x14;
+    pub const MONITOR_CONTENDED: u8 = 0x15;
+    pub const MONITOR_ENTERED: u8 = 0x16;
+    pub const MONITOR_WAIT: u8 = 0x17;
+    pub const MONITOR_WAITED: u8 = 0x18;
+    pub const EXCEPTION_THROWN: u8 = 0x19;
+    pub const EXCEPTION_CAUGHT: u8 = 0x1a;
+}
+
+/// Name of the thread writing the recorded events
+const WRITER_THREAD_NAME: &'static str = "jvmti-recording";
+
+/// Set while events are being recorded
+static RECORDING: AtomicBool = AtomicBool::new(false);
+/// Incremented whenever a recording is started, so that threads drop the senders of earlier ones
+static GENERATION: AtomicUsize = AtomicUsize::new(0);
+
+lazy_static! {
+    /// Sender of the current recording, cloned by every thread recording events. `None` is sent
+    /// to make the writer thread finish.
+    static ref CHANNEL: Mutex<Option<Sender<Option<Event>>>> = Mutex::new(None);
+    static ref HANDLE: Mutex<Option<JoinHandle<u64>>> = Mutex::new(None);
+}
+
+thread_local! {
+    /// The sender of the current thread, along with the generation of the recording it belongs to
+    static SENDER: RefCell<Option<(usize, Sender<Option<Event>>)>> = RefCell::new(None);
+}
+
+///
+/// An event of a recording. Times are in nanoseconds of a monotonic clock, threads are given by
+/// name and methods by their class and method names.
+///
+#[derive(Serialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Event {
+    ThreadStart { time: u64, thread: String },
+    /// `cpu_time` is the CPU time used by the thread, 0 if unknown
+    ThreadEnd { time: u64, thread: String, cpu_time: u64 },
+    MethodEntry { time: u64, thread: String, class: String, method: String },
+    MethodExit { time: u64, thread: String, class: String, method: String, exception: bool },
+    /// `time` is the start of the pause
+    GcPause { time: u64, duration: u64, freed: u64 },
+    MonitorContended { time: u64, thread: String, class: String },
+    MonitorEntered { time: u64, thread: String },
+    /// `timeout` is in milliseconds, 0 if the thread waits indefinitely
+    MonitorWait { time: u64, thread: String, class: String, timeout: u64 },
+    MonitorWaited { time: u64, thread: String, timed_out: bool },
+    /// `caught` is set if the exception is going to be caught
+    ExceptionThrown { time: u64, thread: String, class: String, caught: bool },
+    ExceptionCaught { time: u64, thread: String, class: String }
+}
+
+impl Event {
+
+    pub fn time(&self) -> u64 {
+        match *self {
+            Event::ThreadStart { time, .. } | Event::ThreadEnd { time, .. } | Event::MethodEntry { time, .. } | Event::MethodExit { time, .. } |
+            Event::GcPause { time, .. } | Event::MonitorContended { time, .. } | Event::MonitorEntered { time, .. } | Event::MonitorWait { time, .. } |
+            Event::MonitorWaited { time, .. } | Event::ExceptionThrown { time, .. } | Event::ExceptionCaught { time, .. } => time
+        }
+    }
+
+    /// Return the name of the event type, eg. `thread_start`
+    pub fn kind(&self) -> &'static str {
+        match *self {
+            Event::ThreadStart { .. } => "thread_start",
+            Event::ThreadEnd { .. } => "thread_end",
+            Event::MethodEntry { .. } => "method_entry",
+            Event::MethodExit { .. } => "method_exit",
+            Event::GcPause { .. } => "gc_pause",
+            Event::MonitorContended { .. } => "monitor_contended",
+            Event::MonitorEntered { .. } => "monitor_entered",
+            Event::MonitorWait { .. } => "monitor_wait",
+            Event::MonitorWaited { .. } => "monitor_waited",
+            Event::ExceptionThrown { .. } => "exception_thrown",
+            Event::ExceptionCaught { .. } => "exception_caught"
+        }
+    }
+
+    /// Return the thread the event has been reported on, `None` for garbage collection pauses
+    pub fn thread(&self) -> Option<&str> {
+        match *self {
+            Event::ThreadStart { ref thread, .. } | Event::ThreadEnd { ref thread, .. } | Event::MethodEntry { ref thread, .. } |
+            Event::MethodExit { ref thread, .. } | Event::MonitorContended { ref thread, .. } | Event::MonitorEntered { ref thread, .. } |
+            Event::MonitorWait { ref thread, .. } | Event::MonitorWaited { ref thread, .. } | Event::ExceptionThrown { ref thread, .. } |
+            Event::ExceptionCaught { ref thread, .. } => Some(thread),
+            Event::GcPause { .. } => None
+        }
+    }
+
+    /// Return the subject of the event: a method, a class or nothing
+    pub fn subject(&self) -> String {
+        match *self {
+            Event::MethodEntry { ref class, ref method, .. } | Event::MethodExit { ref class, ref method, .. } => format!("{}.{}", class, method),
+            Event::MonitorContended { ref class, .. } | Event::MonitorWait { ref class, .. } | Event::ExceptionThrown { ref class, .. } |
+            Event::ExceptionCaught { ref class, .. } => class.clone(),
+            _ => String::new()
+        }
+    }
+
+    /// Return the numeric detail of the event, eg. the duration of a garbage collection pause
+    pub fn value(&self) -> Option<u64> {
+        match *self {
+            Event::ThreadEnd { cpu_time, .. } => Some(cpu_time),
+            Event::MethodExit { exception, .. } => Some(exception as u64),
+            Event::GcPause { duration, .. } => Some(duration),
+            Event::MonitorWait { timeout, .. } => Some(timeout),
+            Event::MonitorWaited { timed_out, .. } => Some(timed_out as u64),
+            Event::ExceptionThrown { caught, .. } => Some(caught as u64),
+            _ => None
+        }
+    }
+
+    /// Render the event on a single line, eg. `1000 method_entry main Hello.main`
+    pub fn render_line(&self) -> String {
+        let mut line = format!("{} {}", self.time(), self.kind());
+
+        for field in self.thread().map(|thread| thread.to_string()).into_iter().chain(Some(self.subject())).chain(self.value().map(|value| value.to_string())) {
+            if !field.is_empty() {
+                line.push(' ');
+                line.push_str(&field);
+            }
+        }
+
+        line
+    }
+}
+
+/// Append an unsigned LEB128 varint
+pub fn write_varint(buffer: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buffer.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+
+    buffer.push(value as u8);
+}
+
+/// Read an unsigned LEB128 varint at the given position, advancing it
+pub fn read_varint(data: &[u8], pos: &mut usize) -> io::Result<u64> {
+    let mut value = 0u64;
+    let mut shift = 0;
+
+    loop {
+        let byte = match data.get(*pos) {
+            Some(byte) => *byte,
+            None => return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "truncated varint"))
+        };
+
+        *pos += 1;
+
+        if shift > 63 {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "varint too long"));
+        }
+
+        value |= ((byte & 0x7f) as u64) << shift;
+        shift += 7;
+
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+}
+
+/// Map a signed value to an unsigned one so that values close to zero have short varints
+pub fn zigzag(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+pub fn unzigzag(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+/// Return the CRC-32 (IEEE 802.3) checksum of the given data
+pub fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+
+    for byte in data {
+        crc ^= *byte as u32;
+
+        for _ in 0..8 {
+            crc = match crc & 1 {
+                1 => (crc >> 1) ^ 0xedb8_8320,
+                _ => crc >> 1
+            };
+        }
+    }
+
+    !crc
+}
+
+///
+/// Start recording events to the given file, which is replaced if it exists. Events are written in
+/// chunks of about `chunk_size` bytes by a thread of their own, so that the threads reporting them
+/// don't wait for the file. Returns an error if a recording is in progress already.
+///
+pub fn start<P: AsRef<Path>>(path: P, chunk_size: usize) -> io::Result<()> {
+    if let Some(dir) = path.as_ref().parent() {
+        if !dir.as_os_str().is_empty() {
+            fs::create_dir_all(dir)?;
+        }
+    }
+
+    let writer = RecordingWriter::new(BufWriter::new(File::create(path)?), chunk_size)?;
+    let (sender, receiver) = mpsc::channel();
+
+    let mut channel = match CHANNEL.lock() {
+        Ok(channel) => channel,
+        Err(_) => return Err(io::Error::new(io::ErrorKind::Other, "the recorder is unavailable"))
+    };
+
+    if channel.is_some() {
+        return Err(io::Error::new(io::ErrorKind::AlreadyExists, "events are being recorded already"));
+    }
+
+    let handle = thread::Builder::new().name(WRITER_THREAD_NAME.to_string()).spawn(move || write_events(writer, receiver))?;
+
+    if let Ok(mut current) = HANDLE.lock() {
+        *current = Some(handle);
+    }
+
+    *channel = Some(sender);
+    GENERATION.fetch_add(1, Ordering::SeqCst);
+    RECORDING.store(true, Ordering::SeqCst);
+    Ok(())
+}
+
+pub fn is_recording() -> bool {
+    RECORDING.load(Ordering::SeqCst)
+}
+
+///
+/// Record the event returned by the given function, which is only called while recording. The
+/// event is passed to the writer thread through a sender of the calling thread, which is cloned
+/// from the shared one the first time the thread records an event.
+///
+pub fn record<F: FnOnce() -> Event>(event: F) {
+    if !is_recording() {
+        return;
+    }
+
+    let generation = GENERATION.load(Ordering::SeqCst);
+
+    SENDER.with(|sender| {
+        let mut sender = sender.borrow_mut();
+
+        if sender.as_ref().map(|&(current, _)| current != generation).unwrap_or(true) {
+            *sender = match CHANNEL.lock() {
+                Ok(channel) => channel.as_ref().map(|channel| (generation, channel.clone())),
+                Err(_) => None
+            };
+        }
+
+        if let Some((_, ref sender)) = *sender {
+            // The writer thread is gone if the recording has been stopped in the meantime
+            let _ = sender.send(Some(event()));
+        }
+    });
+}
+
+///
+/// Stop recording and wait until the writer thread has written the events recorded so far.
+/// Returns the number of events recorded.
+///
+pub fn stop() -> io::Result<u64> {
+    RECORDING.store(false, Ordering::SeqCst);
+
+    let sender = match CHANNEL.lock() {
+        Ok(mut channel) => channel.take(),
+        Err(_) => None
+    };
+
+    let handle = match HANDLE.lock() {
+        Ok(mut handle) => handle.take(),
+        Err(_) => None
+    };
+
+    if let Some(sender) = sender {
+        let _ = sender.send(None);
+    }
+
+    match handle {
+        Some(handle) => handle.join().map_err(|_| io::Error::new(io::ErrorKind::Other, "the recording writer has panicked")),
+        None => Ok(0)
+    }
+}
+
+/// Write the received events until the recording is stopped. The recording is stopped if an
+/// event couldn't be written. Returns the number of events written.
+fn write_events(mut writer: RecordingWriter<BufWriter<File>>, receiver: Receiver<Option<Event>>) -> u64 {
+    for event in receiver.iter() {
+        let result = match event {
+            Some(event) => writer.write(&event),
+            None => break
+        };
+
+        if let Err(err) = result {
+            error!(logger(), "Couldn't record event, stopping the recording: {}", err);
+            RECORDING.store(false, Ordering::SeqCst);
+            return writer.events();
+        }
+    }
+
+    if let Err(err) = writer.flush() {
+        error!(logger(), "Couldn't write recording: {}", err);
+    }
+
+    writer.events()
+}
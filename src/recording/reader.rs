@@ -0,0 +1,203 @@
+use super::{crc32, read_varint, tag, unzigzag, Event, MAGIC, VERSION};
+use std::collections::{HashMap, VecDeque};
+use std::fs::File;
+use std::io::{self, BufReader, ErrorKind, Read};
+use std::path::Path;
+
+/// Longest chunk accepted, longer ones are considered corrupt
+const MAX_CHUNK_SIZE: usize = 64 * 1024 * 1024;
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, message.to_string())
+}
+
+///
+/// Reads the events of a recording back, chunk by chunk. A chunk that fails its checksum or can't
+/// be decoded is reported as an error and skipped. A recording cut short, eg. by a crash of the VM,
+/// ends with an `UnexpectedEof` error.
+///
+pub struct RecordingReader<R: Read> {
+    input: R,
+    events: VecDeque<Event>,
+    /// Set at the end of the input, or once the start of the next chunk can't be found
+    done: bool
+}
+
+impl RecordingReader<BufReader<File>> {
+
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<RecordingReader<BufReader<File>>> {
+        RecordingReader::new(BufReader::new(File::open(path)?))
+    }
+}
+
+impl<R: Read> RecordingReader<R> {
+
+    /// Create a reader, checking the header of the recording
+    pub fn new(mut input: R) -> io::Result<RecordingReader<R>> {
+        let mut header = [0; 10];
+
+        input.read_exact(&mut header)?;
+
+        if &header[..8] != &MAGIC[..] {
+            return Err(invalid("not a recording"));
+        }
+
+        match (header[8] as u16) | ((header[9] as u16) << 8) {
+            VERSION => Ok(RecordingReader { input: input, events: VecDeque::new(), done: false }),
+            version => Err(invalid(&format!("unsupported recording version: {}", version)))
+        }
+    }
+
+    /// Read the next chunk and return its events, or `None` at the end of the recording
+    pub fn read_chunk(&mut self) -> io::Result<Option<Vec<Event>>> {
+        if self.done {
+            return Ok(None);
+        }
+
+        let payload = match self.read_payload() {
+            Ok(payload) => payload,
+            Err(err) => {
+                self.done = true;
+                return Err(err);
+            }
+        };
+
+        match payload {
+            Some((payload, checksum)) => match crc32(&payload) == checksum {
+                true => decode_chunk(&payload).map(Some),
+                false => Err(invalid("chunk checksum mismatch"))
+            },
+            None => {
+                self.done = true;
+                Ok(None)
+            }
+        }
+    }
+
+    /// Read the payload of the next chunk along with its checksum
+    fn read_payload(&mut self) -> io::Result<Option<(Vec<u8>, u32)>> {
+        let mut header = [0; 8];
+        let mut read = 0;
+
+        while read < header.len() {
+            match self.input.read(&mut header[read..]) {
+                Ok(0) if read == 0 => return Ok(None),
+                Ok(0) => return Err(io::Error::new(ErrorKind::UnexpectedEof, "truncated chunk header")),
+                Ok(len) => read += len,
+                Err(ref err) if err.kind() == ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err)
+            }
+        }
+
+        let length = (header[0] as usize) | (header[1] as usize) << 8 | (header[2] as usize) << 16 | (header[3] as usize) << 24;
+        let checksum = (header[4] as u32) | (header[5] as u32) << 8 | (header[6] as u32) << 16 | (header[7] as u32) << 24;
+
+        if length > MAX_CHUNK_SIZE {
+            return Err(invalid("chunk too large"));
+        }
+
+        let mut payload = vec![0; length];
+
+        self.input.read_exact(&mut payload)?;
+        Ok(Some((payload, checksum)))
+    }
+}
+
+impl<R: Read> Iterator for RecordingReader<R> {
+    type Item = io::Result<Event>;
+
+    fn next(&mut self) -> Option<io::Result<Event>> {
+        while self.events.is_empty() {
+            if self.done {
+                return None;
+            }
+
+            match self.read_chunk() {
+                Ok(Some(events)) => self.events.extend(events),
+                Ok(None) => return None,
+                Err(err) => return Some(Err(err))
+            }
+        }
+
+        self.events.pop_front().map(Ok)
+    }
+}
+
+/// Decode the payload of a chunk
+pub fn decode_chunk(payload: &[u8]) -> io::Result<Vec<Event>> {
+    let mut pos = 0;
+    let mut time = read_varint(payload, &mut pos)?;
+    let mut strings: HashMap<u64, String> = HashMap::new();
+    let mut methods: HashMap<u64, (String, String)> = HashMap::new();
+    let mut events = vec![];
+
+    while pos < payload.len() {
+        let record_tag = payload[pos];
+
+        pos += 1;
+
+        match record_tag {
+            tag::STRING => {
+                let idx = read_varint(payload, &mut pos)?;
+                let len = read_varint(payload, &mut pos)? as usize;
+
+                if pos.checked_add(len).map_or(true, |end| end > payload.len()) {
+                    return Err(invalid("truncated string"));
+                }
+
+                strings.insert(idx, String::from_utf8_lossy(&payload[pos..pos + len]).into_owned());
+                pos += len;
+                continue;
+            },
+            tag::METHOD => {
+                let idx = read_varint(payload, &mut pos)?;
+                let class = string(&strings, read_varint(payload, &mut pos)?)?;
+                let method = string(&strings, read_varint(payload, &mut pos)?)?;
+
+                methods.insert(idx, (class, method));
+                continue;
+            },
+            _ => ()
+        }
+
+        time = time.wrapping_add(unzigzag(read_varint(payload, &mut pos)?) as u64);
+
+        let mut next = || read_varint(payload, &mut pos);
+        let event = match record_tag {
+            tag::THREAD_START => Event::ThreadStart { time: time, thread: string(&strings, next()?)? },
+            tag::THREAD_END => Event::ThreadEnd { time: time, thread: string(&strings, next()?)?, cpu_time: next()? },
+            tag::METHOD_ENTRY => {
+                let thread = string(&strings, next()?)?;
+                let (class, method) = method(&methods, next()?)?;
+
+                Event::MethodEntry { time: time, thread: thread, class: class, method: method }
+            },
+            tag::METHOD_EXIT => {
+                let thread = string(&strings, next()?)?;
+                let (class, method) = method(&methods, next()?)?;
+
+                Event::MethodExit { time: time, thread: thread, class: class, method: method, exception: next()? != 0 }
+            },
+            tag::GC_PAUSE => Event::GcPause { time: time, duration: next()?, freed: next()? },
+            tag::MONITOR_CONTENDED => Event::MonitorContended { time: time, thread: string(&strings, next()?)?, class: string(&strings, next()?)? },
+            tag::MONITOR_ENTERED => Event::MonitorEntered { time: time, thread: string(&strings, next()?)? },
+            tag::MONITOR_WAIT => Event::MonitorWait { time: time, thread: string(&strings, next()?)?, class: string(&strings, next()?)?, timeout: next()? },
+            tag::MONITOR_WAITED => Event::MonitorWaited { time: time, thread: string(&strings, next()?)?, timed_out: next()? != 0 },
+            tag::EXCEPTION_THROWN => Event::ExceptionThrown { time: time, thread: string(&strings, next()?)?, class: string(&strings, next()?)?, caught: next()? != 0 },
+            tag::EXCEPTION_CAUGHT => Event::ExceptionCaught { time: time, thread: string(&strings, next()?)?, class: string(&strings, next()?)? },
+            other => return Err(invalid(&format!("unknown record tag: {:#x}", other)))
+        };
+
+        events.push(event);
+    }
+
+    Ok(events)
+}
+
+fn string(strings: &HashMap<u64, String>, idx: u64) -> io::Result<String> {
+    strings.get(&idx).cloned().ok_or_else(|| invalid(&format!("undefined string: {}", idx)))
+}
+
+fn method(methods: &HashMap<u64, (String, String)>, idx: u64) -> io::Result<(String, String)> {
+    methods.get(&idx).cloned().ok_or_else(|| invalid(&format!("undefined method: {}", idx)))
+}
@@ -0,0 +1,209 @@
+use super::{crc32, tag, write_varint, zigzag, Event, MAGIC, VERSION};
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+///
+/// Writes events in the recording format. Events are buffered until the current chunk reaches
+/// `chunk_size` bytes, at which point the chunk is written along with its checksum and a new one,
+/// with its own string and method tables, is started.
+///
+pub struct RecordingWriter<W: Write> {
+    out: W,
+    chunk_size: usize,
+    /// The payload of the current chunk
+    chunk: Vec<u8>,
+    strings: HashMap<String, u64>,
+    methods: HashMap<(u64, u64), u64>,
+    /// Time of the previous event of the chunk
+    last_time: u64,
+    events: u64
+}
+
+impl<W: Write> RecordingWriter<W> {
+
+    /// Create a writer, writing the header of the recording right away
+    pub fn new(mut out: W, chunk_size: usize) -> io::Result<RecordingWriter<W>> {
+        out.write_all(MAGIC)?;
+        out.write_all(&[VERSION as u8, (VERSION >> 8) as u8])?;
+
+        Ok(RecordingWriter { out: out, chunk_size: chunk_size, chunk: vec![], strings: HashMap::new(), methods: HashMap::new(), last_time: 0, events: 0 })
+    }
+
+    /// Return the number of events written so far
+    pub fn events(&self) -> u64 {
+        self.events
+    }
+
+    pub fn write(&mut self, event: &Event) -> io::Result<()> {
+        let time = event.time();
+
+        if self.chunk.is_empty() {
+            write_varint(&mut self.chunk, time);
+            self.last_time = time;
+        }
+
+        // Definitions are written before the event referring to them
+        let mut record = vec![];
+
+        match *event {
+            Event::ThreadStart { ref thread, .. } => {
+                let thread = self.string(thread);
+
+                self.header(&mut record, tag::THREAD_START, time);
+                write_varint(&mut record, thread);
+            },
+            Event::ThreadEnd { ref thread, cpu_time, .. } => {
+                let thread = self.string(thread);
+
+                self.header(&mut record, tag::THREAD_END, time);
+                write_varint(&mut record, thread);
+                write_varint(&mut record, cpu_time);
+            },
+            Event::MethodEntry { ref thread, ref class, ref method, .. } => {
+                let (thread, method) = (self.string(thread), self.method(class, method));
+
+                self.header(&mut record, tag::METHOD_ENTRY, time);
+                write_varint(&mut record, thread);
+                write_varint(&mut record, method);
+            },
+            Event::MethodExit { ref thread, ref class, ref method, exception, .. } => {
+                let (thread, method) = (self.string(thread), self.method(class, method));
+
+                self.header(&mut record, tag::METHOD_EXIT, time);
+                write_varint(&mut record, thread);
+                write_varint(&mut record, method);
+                write_varint(&mut record, exception as u64);
+            },
+            Event::GcPause { duration, freed, .. } => {
+                self.header(&mut record, tag::GC_PAUSE, time);
+                write_varint(&mut record, duration);
+                write_varint(&mut record, freed);
+            },
+            Event::MonitorContended { ref thread, ref class, .. } => {
+                let (thread, class) = (self.string(thread), self.string(class));
+
+                self.header(&mut record, tag::MONITOR_CONTENDED, time);
+                write_varint(&mut record, thread);
+                write_varint(&mut record, class);
+            },
+            Event::MonitorEntered { ref thread, .. } => {
+                let thread = self.string(thread);
+
+                self.header(&mut record, tag::MONITOR_ENTERED, time);
+                write_varint(&mut record, thread);
+            },
+            Event::MonitorWait { ref thread, ref class, timeout, .. } => {
+                let (thread, class) = (self.string(thread), self.string(class));
+
+                self.header(&mut record, tag::MONITOR_WAIT, time);
+                write_varint(&mut record, thread);
+                write_varint(&mut record, class);
+                write_varint(&mut record, timeout);
+            },
+            Event::MonitorWaited { ref thread, timed_out, .. } => {
+                let thread = self.string(thread);
+
+                self.header(&mut record, tag::MONITOR_WAITED, time);
+                write_varint(&mut record, thread);
+                write_varint(&mut record, timed_out as u64);
+            },
+            Event::ExceptionThrown { ref thread, ref class, caught, .. } => {
+                let (thread, class) = (self.string(thread), self.string(class));
+
+                self.header(&mut record, tag::EXCEPTION_THROWN, time);
+                write_varint(&mut record, thread);
+                write_varint(&mut record, class);
+                write_varint(&mut record, caught as u64);
+            },
+            Event::ExceptionCaught { ref thread, ref class, .. } => {
+                let (thread, class) = (self.string(thread), self.string(class));
+
+                self.header(&mut record, tag::EXCEPTION_CAUGHT, time);
+                write_varint(&mut record, thread);
+                write_varint(&mut record, class);
+            }
+        }
+
+        self.chunk.extend_from_slice(&record);
+        self.events += 1;
+
+        if self.chunk.len() >= self.chunk_size {
+            self.write_chunk()?;
+        }
+
+        Ok(())
+    }
+
+    /// Write the tag and the time difference of an event
+    fn header(&mut self, record: &mut Vec<u8>, tag: u8, time: u64) {
+        record.push(tag);
+        write_varint(record, zigzag(time.wrapping_sub(self.last_time) as i64));
+        self.last_time = time;
+    }
+
+    /// Return the index of a string in the table of the chunk, defining it if it's not there yet
+    fn string(&mut self, value: &str) -> u64 {
+        if let Some(idx) = self.strings.get(value) {
+            return *idx;
+        }
+
+        let idx = self.strings.len() as u64;
+
+        self.chunk.push(tag::STRING);
+        write_varint(&mut self.chunk, idx);
+        write_varint(&mut self.chunk, value.len() as u64);
+        self.chunk.extend_from_slice(value.as_bytes());
+        self.strings.insert(value.to_string(), idx);
+        idx
+    }
+
+    /// Return the index of a method in the table of the chunk, defining it if it's not there yet
+    fn method(&mut self, class: &str, method: &str) -> u64 {
+        let key = (self.string(class), self.string(method));
+
+        if let Some(idx) = self.methods.get(&key) {
+            return *idx;
+        }
+
+        let idx = self.methods.len() as u64;
+
+        self.chunk.push(tag::METHOD);
+        write_varint(&mut self.chunk, idx);
+        write_varint(&mut self.chunk, key.0);
+        write_varint(&mut self.chunk, key.1);
+        self.methods.insert(key, idx);
+        idx
+    }
+
+    fn write_chunk(&mut self) -> io::Result<()> {
+        let length = self.chunk.len() as u32;
+        let checksum = crc32(&self.chunk);
+        let mut header = vec![];
+
+        for value in [length, checksum].iter() {
+            header.extend_from_slice(&[*value as u8, (*value >> 8) as u8, (*value >> 16) as u8, (*value >> 24) as u8]);
+        }
+
+        self.out.write_all(&header)?;
+        self.out.write_all(&self.chunk)?;
+        self.chunk.clear();
+        self.strings.clear();
+        self.methods.clear();
+        Ok(())
+    }
+
+    /// Write the current chunk, even if it's not full yet, and flush the output
+    pub fn flush(&mut self) -> io::Result<()> {
+        if !self.chunk.is_empty() {
+            self.write_chunk()?;
+        }
+
+        self.out.flush()
+    }
+
+    /// Flush the writer and return its output
+    pub fn into_inner(mut self) -> io::Result<W> {
+        self.flush()?;
+        Ok(self.out)
+    }
+}
@@ -0,0 +1,424 @@
+//! Hot reload of the agent configuration. The modification time of the configuration file is
+//! polled and a changed file is applied to the running agent: profilers and exporters whose
+//! settings have changed are restarted and method events are enabled or disabled. Settings that
+//! are only read when the agent is loaded are reported as requiring a restart. A file that can't be
+//! read or is invalid is rejected, the previous configuration stays in effect.
+
+use super::config::{Config, ConfigError, InstrumentationConfig};
+use super::context::static_context;
+use super::control;
+use super::environment::jvm::{java_vm, JVMF, JVMAgent};
+use super::error::{translate_error, NativeError};
+use super::event::VMEvent;
+use super::export;
+use super::invocations;
+use super::logging::logger;
+use super::sampler;
+use serde_json::{self, Value};
+use std::fmt;
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant, SystemTime};
+use time::precise_time_ns;
+
+/// Name of the agent thread watching the configuration file, as seen by the JVM
+pub const RELOAD_THREAD_NAME: &'static str = "jvmti-reload";
+/// Longest time the reload thread sleeps before checking whether it's been stopped
+const POLL_INTERVAL_MS: u64 = 100;
+
+/// Set while the reload thread is alive
+static RUNNING: AtomicBool = AtomicBool::new(false);
+
+lazy_static! {
+    static ref HANDLE: Mutex<Option<JoinHandle<()>>> = Mutex::new(None);
+}
+
+///
+/// The outcome of applying a changed section of the configuration. Some settings are only read
+/// when the agent is loaded, changing them is reported as `RestartRequired`.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub enum Change {
+    Applied(String, String),
+    RestartRequired(String),
+    Failed(String, String)
+}
+
+impl Change {
+
+    fn applied(section: &str, description: &str) -> Change {
+        Change::Applied(section.to_string(), description.to_string())
+    }
+
+    fn failed(section: &str, reason: &str) -> Change {
+        Change::Failed(section.to_string(), reason.to_string())
+    }
+}
+
+impl fmt::Display for Change {
+
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Change::Applied(ref section, ref description) => write!(f, "[{}] {}", section, description),
+            Change::RestartRequired(ref section) => write!(f, "[{}] changes take effect after the VM is restarted", section),
+            Change::Failed(ref section, ref reason) => write!(f, "[{}] {}", section, reason)
+        }
+    }
+}
+
+///
+/// Start watching the given configuration file, checking whether it's been modified every
+/// `reload.interval_ms` of the current configuration. Returns an error if the reload thread is
+/// running already.
+///
+pub fn start<P: AsRef<Path>>(path: P) -> io::Result<()> {
+    if RUNNING.swap(true, Ordering::SeqCst) {
+        return Err(io::Error::new(ErrorKind::AlreadyExists, "the configuration is being watched already"));
+    }
+
+    let watcher = Watcher::new(path);
+
+    match thread::Builder::new().name(RELOAD_THREAD_NAME.to_string()).spawn(move || run(watcher)) {
+        Ok(handle) => {
+            if let Ok(mut current) = HANDLE.lock() {
+                *current = Some(handle);
+            }
+
+            Ok(())
+        },
+        Err(err) => {
+            RUNNING.store(false, Ordering::SeqCst);
+            Err(err)
+        }
+    }
+}
+
+/// Stop watching the configuration file and wait until the reload thread has finished
+pub fn stop() {
+    RUNNING.store(false, Ordering::SeqCst);
+
+    let handle = match HANDLE.lock() {
+        Ok(mut handle) => handle.take(),
+        Err(_) => None
+    };
+
+    if let Some(handle) = handle {
+        let _ = handle.join();
+    }
+}
+
+pub fn is_running() -> bool {
+    RUNNING.load(Ordering::SeqCst)
+}
+
+/// The modification time and size of a file. Files rewritten within the resolution of their
+/// modification time are still noticed if their size has changed.
+fn modification(path: &Path) -> Option<(SystemTime, u64)> {
+    fs::metadata(path).and_then(|metadata| metadata.modified().map(|modified| (modified, metadata.len()))).ok()
+}
+
+///
+/// Checks a configuration file for modifications and reloads it once it's been changed. The
+/// reload thread polls it every `reload.interval_ms`.
+///
+pub struct Watcher {
+    path: PathBuf,
+    modified: Option<(SystemTime, u64)>
+}
+
+impl Watcher {
+
+    /// Start watching the given file, its current state is taken as unchanged
+    pub fn new<P: AsRef<Path>>(path: P) -> Watcher {
+        let path = path.as_ref().to_path_buf();
+        let modified = modification(&path);
+
+        Watcher { path: path, modified: modified }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    ///
+    /// Reload the file if it's been modified since it was last polled, returning the outcome.
+    /// Returns `None` if it hasn't been modified or it's gone, files are often replaced rather than
+    /// rewritten, a missing file is reloaded once it's back.
+    ///
+    pub fn poll(&mut self) -> Option<Result<Vec<Change>, ConfigError>> {
+        let current = modification(&self.path);
+
+        if current == self.modified {
+            return None;
+        }
+
+        self.modified = current;
+
+        if self.modified.is_none() {
+            warn!(logger(), "{} is gone, keeping the current configuration", self.path.display());
+            return None;
+        }
+
+        Some(reload(&self.path))
+    }
+}
+
+fn reload_interval() -> Duration {
+    match static_context().config.read() {
+        Ok(cfg) => Duration::from_millis((*cfg).reload.interval_ms),
+        Err(_) => Duration::from_millis(POLL_INTERVAL_MS)
+    }
+}
+
+fn run(mut watcher: Watcher) {
+    // Restarted profilers and exporters may call into the JVM from this thread
+    let jvm = java_vm().map(JVMAgent::new);
+    let attached = match jvm {
+        Some(ref jvm) => match jvm.attach_current_thread(RELOAD_THREAD_NAME) {
+            Ok(_) => true,
+            Err(err) => {
+                warn!(logger(), "Couldn't attach the reload thread to the JVM: {}", translate_error(&err));
+                false
+            }
+        },
+        None => false
+    };
+
+    let mut checked = Instant::now();
+
+    while RUNNING.load(Ordering::SeqCst) {
+        thread::sleep(Duration::from_millis(POLL_INTERVAL_MS));
+
+        if checked.elapsed() < reload_interval() {
+            continue;
+        }
+
+        checked = Instant::now();
+
+        match watcher.poll() {
+            Some(Ok(changes)) => if !changes.is_empty() {
+                info!(logger(), "Reloaded the configuration from {}", watcher.path().display());
+            },
+            Some(Err(err)) => error!(logger(), "Keeping the previous configuration, {} has been rejected: {}", watcher.path().display(), err),
+            None => continue
+        }
+
+        let enabled = match static_context().config.read() {
+            Ok(cfg) => (*cfg).reload.enabled,
+            Err(_) => true
+        };
+
+        if !enabled {
+            info!(logger(), "Stopped watching {}", watcher.path().display());
+            RUNNING.store(false, Ordering::SeqCst);
+        }
+    }
+
+    if let (true, Some(jvm)) = (attached, jvm) {
+        let _ = jvm.detach_current_thread();
+    }
+}
+
+///
+/// Read the configuration from the given file and apply it, returning the changes made. Warnings
+/// about the file are logged. An invalid configuration is rejected, the current one is kept.
+///
+pub fn reload<P: AsRef<Path>>(path: P) -> Result<Vec<Change>, ConfigError> {
+    let (config, warnings) = Config::read_from_file(&path)?;
+
+    for warning in warnings {
+        warn!(logger(), "{}: {}", path.as_ref().display(), warning);
+    }
+
+    Ok(apply(config))
+}
+
+///
+/// Replace the configuration of the running agent and act on the sections that have changed,
+/// returning what has been done for each of them. Every change is logged.
+///
+pub fn apply(config: Config) -> Vec<Change> {
+    // The changes are found against the configuration that's actually been replaced, a concurrent
+    // update in between would be missed otherwise
+    let previous = match static_context().update_config(config.clone()) {
+        Some(previous) => previous,
+        None => return vec![]
+    };
+
+    let sections = changed_sections(&previous, &config);
+
+    let changes: Vec<Change> = sections.iter().map(|section| apply_section(section, &previous, &config)).collect();
+
+    for change in &changes {
+        match *change {
+            Change::Applied(_, _) => info!(logger(), "{}", change),
+            _ => warn!(logger(), "{}", change)
+        }
+    }
+
+    changes
+}
+
+/// Return the names of the top level settings and sections that differ between two configurations
+pub fn changed_sections(previous: &Config, current: &Config) -> Vec<String> {
+    match (serde_json::to_value(previous), serde_json::to_value(current)) {
+        (Ok(Value::Object(previous)), Ok(Value::Object(current))) => current.iter()
+            .filter(|&(key, value)| previous.get(key) != Some(value))
+            .map(|(key, _)| key.clone())
+            .collect(),
+        _ => vec![]
+    }
+}
+
+fn apply_section(section: &str, previous: &Config, config: &Config) -> Change {
+    match section {
+        "sampling" => apply_sampling(config),
+        "exceptions" => match invocations::set_exception_events(config.exceptions.enabled) {
+            Ok(_) => Change::applied(section, if config.exceptions.enabled { "exception profiling enabled" } else { "exception profiling disabled" }),
+            Err(err) => Change::failed(section, &format!("couldn't set exception events: {}", translate_error(&err)))
+        },
+        "timeline" => apply_timeline(config),
+        "instrumentation" => apply_instrumentation(&previous.instrumentation, config),
+        "slow_invocations" if previous.slow_invocations.max_argument_length != config.slow_invocations.max_argument_length
+            || previous.slow_invocations.stack_depth != config.slow_invocations.stack_depth => Change::RestartRequired(section.to_string()),
+        "slow_invocations" => match set_method_events(config) {
+            Ok(_) => Change::applied(section, &format!("watching {} methods", config.slow_invocations.methods.len())),
+            Err(err) => Change::failed(section, &format!("couldn't update method events: {}", translate_error(&err)))
+        },
+        "timing" => match set_method_events(config) {
+            Ok(_) => Change::applied(section, if config.timing.enabled { "method timing enabled" } else { "method timing disabled" }),
+            Err(err) => Change::failed(section, &format!("couldn't update method events: {}", translate_error(&err)))
+        },
+        "trace" if previous.trace.output != config.trace.output => Change::RestartRequired(section.to_string()),
+        "export" => {
+            export::stop();
+
+            match config.export.is_enabled() {
+                true => match export::start(config.export.clone(), export::exporters(&config.export, &config.export_dir())) {
+                    Ok(_) => Change::applied(section, "metrics exporter restarted"),
+                    Err(err) => Change::failed(section, &format!("couldn't start the metrics exporter: {}", translate_error(&err)))
+                },
+                false => Change::applied(section, "metrics exporter stopped")
+            }
+        },
+        "prometheus" => {
+            export::prometheus::stop();
+
+            match config.prometheus.is_enabled() {
+                true => match export::prometheus::start(config.prometheus.clone()) {
+                    Ok(address) => Change::applied(section, &format!("metrics server restarted on {}", address)),
+                    Err(err) => Change::failed(section, &format!("couldn't start the metrics server: {}", err))
+                },
+                false => Change::applied(section, "metrics server stopped")
+            }
+        },
+        "statsd" => {
+            export::statsd::stop();
+
+            match config.statsd.enabled {
+                true => match export::statsd::start(config.statsd.clone(), &static_context().agent_id()) {
+                    Ok(_) => Change::applied(section, "StatsD emitter restarted"),
+                    Err(err) => Change::failed(section, &format!("couldn't start the StatsD emitter: {}", err))
+                },
+                false => Change::applied(section, "StatsD emitter stopped")
+            }
+        },
+        "control" => {
+            control::stop();
+
+            match config.control.enabled {
+                true => match control::start(&config.control) {
+                    Ok(path) => Change::applied(section, &format!("listening for commands on {}", path.display())),
+                    Err(err) => Change::failed(section, &format!("couldn't start the control socket: {}", err))
+                },
+                false => Change::applied(section, "control socket closed")
+            }
+        },
+        "reload" => match config.reload.enabled {
+            true => Change::applied(section, &format!("checking for changes every {} ms", config.reload.interval_ms)),
+            false => Change::applied(section, "configuration reload disabled")
+        },
+        // Callbacks, capabilities and output files of these are set up when the agent is loaded
+        "allocations" | "gc" | "locks" | "recording" => Change::RestartRequired(section.to_string()),
+        other => Change::applied(other, "updated")
+    }
+}
+
+fn apply_sampling(config: &Config) -> Change {
+    // The sampler is given its settings when it's started, a new one replaces the current one
+    let restart = sampler::is_running();
+
+    if restart {
+        sampler::stop();
+    }
+
+    match config.sampling.enabled || config.sampling.cpu_interval_ms > 0 {
+        true => match sampler::start(config.sampling.clone()) {
+            Ok(_) => Change::applied("sampling", if restart { "sampler restarted" } else { "sampler started" }),
+            Err(err) => Change::failed("sampling", &format!("couldn't start the sampler: {}", err))
+        },
+        false => Change::applied("sampling", if restart { "sampler stopped" } else { "updated" })
+    }
+}
+
+fn apply_timeline(config: &Config) -> Change {
+    match config.timeline.enabled {
+        true => match static_context().enable_timeline(config.timeline.max_events) {
+            true => Change::applied("timeline", "timeline enabled"),
+            false => Change::applied("timeline", "timeline is being recorded already, new settings apply to the next one")
+        },
+        false => match static_context().disable_timeline() {
+            Some(timeline) => {
+                let path = Path::new(&config.output_dir).join(&config.timeline.file);
+
+                match timeline.write(&path, precise_time_ns()) {
+                    Ok(_) => Change::applied("timeline", &format!("timeline disabled, wrote {} events to {}", timeline.len(), path.display())),
+                    Err(err) => Change::failed("timeline", &format!("couldn't write the timeline: {}", err))
+                }
+            },
+            None => Change::applied("timeline", "updated")
+        }
+    }
+}
+
+fn apply_instrumentation(previous: &InstrumentationConfig, config: &Config) -> Change {
+    if previous.entry_points != config.instrumentation.entry_points {
+        if let Err(err) = set_method_events(config) {
+            return Change::failed("instrumentation", &format!("couldn't update method events: {}", translate_error(&err)));
+        }
+    }
+
+    // Entry points are matched by the method events, active classes would have to be transformed
+    // while they're loaded, which loaded classes aren't run through again
+    match previous.active_classes == config.instrumentation.active_classes {
+        true => Change::applied("instrumentation", "entry points updated"),
+        false => Change::RestartRequired("instrumentation".to_string())
+    }
+}
+
+///
+/// Enable the method events the given configuration needs, through the environment the callbacks
+/// have been registered with. Method entry events are needed for tracing, timing and detecting slow
+/// invocations, exit events only for tracing and timing, they're enabled per thread inside watched
+/// methods.
+///
+pub fn set_method_events(config: &Config) -> Result<(), NativeError> {
+    let tracing = !config.instrumentation.entry_points.is_empty() || config.timing.enabled;
+    let watching = config.slow_invocations.is_enabled();
+
+    let result = invocations::with_environment(|env| {
+        match env.set_event_notification_mode(VMEvent::MethodEntry, tracing || watching) {
+            Some(err) => Err(err),
+            None => match env.set_event_notification_mode(VMEvent::MethodExit, tracing) {
+                Some(err) => Err(err),
+                None => Ok(())
+            }
+        }
+    });
+
+    result.unwrap_or(Err(NativeError::NotAvailable))
+}
@@ -1,6 +1,7 @@
 use super::bytecode::Classfile;
 use super::class::{ClassId, ClassSignature};
 use super::method::{MethodId, MethodSignature};
+use super::stack::StackTraceElement;
 use super::thread::Thread;
 
 pub trait RuntimeEvent {
@@ -8,8 +9,70 @@ pub trait RuntimeEvent {
 
 pub struct ObjectAllocationEvent {
     pub class_id: ClassId,
+    pub class_sig: ClassSignature,
+    pub thread: Thread,
+    pub size: i64,
+    pub stack_trace: Vec<StackTraceElement>
+}
+
+///
+/// Reported when a thread starts blocking on a monitor (ie. on entering a `synchronized` block or
+/// method) that's owned by another thread.
+///
+pub struct MonitorEvent {
+    pub thread: Thread,
+    /// Signature of the class of the monitor object
+    pub class_sig: ClassSignature,
+    /// Stack trace of the blocked thread
+    pub stack_trace: Vec<StackTraceElement>
+}
+
+/// Reported when a thread is about to wait on an object by calling `Object.wait`
+pub struct MonitorWaitEvent {
     pub thread: Thread,
-    pub size: i64
+    pub class_sig: ClassSignature,
+    pub stack_trace: Vec<StackTraceElement>,
+    /// The time the thread is going to wait for in milliseconds, or 0 if it waits indefinitely
+    pub timeout: i64
+}
+
+/// Reported when a thread has finished waiting on an object
+pub struct MonitorWaitedEvent {
+    pub thread: Thread,
+    pub class_sig: ClassSignature,
+    /// Set if the wait has been ended by its timeout rather than a notification
+    pub timed_out: bool
+}
+
+///
+/// Reported when an exception is thrown, before any handler has been run. Stack depths are the
+/// number of frames on the thread's stack at the time of the event.
+///
+pub struct ExceptionEvent {
+    pub thread: Thread,
+    pub exception_class: ClassSignature,
+    pub throw_site: StackTraceElement,
+    /// The location of the handler the exception is going to be caught by, or `None` if it's not
+    /// going to be caught at all
+    pub catch_site: Option<StackTraceElement>,
+    pub stack_depth: usize
+}
+
+/// Reported when a thrown exception is caught, right before its handler is run
+pub struct ExceptionCatchEvent {
+    pub thread: Thread,
+    pub exception_class: ClassSignature,
+    pub catch_site: StackTraceElement,
+    pub stack_depth: usize
+}
+
+///
+/// Reported when a thread starts or ends. Both events are reported on the thread itself.
+///
+pub struct ThreadEvent {
+    pub thread: Thread,
+    /// CPU time used by the thread so far in nanoseconds, if the VM supports measuring it
+    pub cpu_time: Option<u64>
 }
 
 pub struct ObjectFreeEvent {
@@ -20,11 +83,33 @@ pub struct MethodInvocationEvent {
     pub method_id: MethodId,
     pub method_sig: MethodSignature,
     pub class_sig: ClassSignature,
-    pub thread: Thread
+    pub thread: Thread,
+    /// Set on method exit if the method was left by throwing an exception
+    pub popped_by_exception: bool
+}
+
+impl MethodInvocationEvent {
+
+    /// Return the fully qualified name of the invoked method, eg. `java.lang.Thread.run`
+    pub fn method_name(&self) -> String {
+        format!("{}.{}", self.class_sig.to_string(), self.method_sig.name)
+    }
+
+    /// Return the fully qualified name of the invoked method followed by its descriptor, eg.
+    /// `java.lang.String.valueOf(I)Ljava/lang/String;`, which tells overloaded methods apart
+    pub fn method_key(&self) -> String {
+        format!("{}{}", self.method_name(), self.method_sig.descriptor)
+    }
 }
 
 impl RuntimeEvent for ObjectAllocationEvent {}
 impl RuntimeEvent for MethodInvocationEvent {}
+impl RuntimeEvent for MonitorEvent {}
+impl RuntimeEvent for MonitorWaitEvent {}
+impl RuntimeEvent for MonitorWaitedEvent {}
+impl RuntimeEvent for ThreadEvent {}
+impl RuntimeEvent for ExceptionEvent {}
+impl RuntimeEvent for ExceptionCatchEvent {}
 
 pub struct ClassFileLoadEvent {
     pub class_name: String,
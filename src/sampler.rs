@@ -0,0 +1,177 @@
+use super::config::SamplingConfig;
+use super::context::static_context;
+use super::environment::jni::{JNIEnvironment, LocalFrame};
+use super::environment::jvm::{java_vm, JVMF, JVMAgent};
+use super::environment::jvmti::JVMTI;
+use super::error::{translate_error, NativeError};
+use super::logging::logger;
+use super::stack::{resolve_stack_trace, StackFrame};
+use super::thread::java_thread_id;
+use std::collections::HashMap;
+use std::io::{self, ErrorKind};
+use std::slice;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
+use time::precise_time_ns;
+
+/// Name of the agent thread taking the samples, as seen by the JVM
+pub const SAMPLER_THREAD_NAME: &'static str = "jvmti-sampler";
+
+/// Set while the sampler thread is alive
+static RUNNING: AtomicBool = AtomicBool::new(false);
+/// Set while samples are being taken. The sampler thread keeps running while this is cleared.
+static ENABLED: AtomicBool = AtomicBool::new(false);
+/// Incremented every time a sampler thread is started, so that a stopped thread that's still
+/// finishing its last sample doesn't carry on running next to its replacement
+static GENERATION: AtomicUsize = AtomicUsize::new(0);
+
+///
+/// Start the sampler thread, which periodically captures the stacks of all (matching) threads and
+/// records them in the agent context. If configured, it also samples the CPU time of every thread.
+/// The thread attaches itself to the JVM, so this can only be called once the VM has been
+/// initialised. Returns an error if the sampler is running already.
+///
+pub fn start(config: SamplingConfig) -> io::Result<()> {
+    if RUNNING.swap(true, Ordering::SeqCst) {
+        return Err(io::Error::new(ErrorKind::AlreadyExists, "the sampler is running already"));
+    }
+
+    ENABLED.store(config.enabled, Ordering::SeqCst);
+
+    let generation = GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+
+    match thread::Builder::new().name(SAMPLER_THREAD_NAME.to_string()).spawn(move || run(config, generation)) {
+        Ok(_) => Ok(()),
+        Err(err) => {
+            RUNNING.store(false, Ordering::SeqCst);
+            Err(err)
+        }
+    }
+}
+
+/// Stop the sampler thread after the sample currently being taken
+pub fn stop() {
+    RUNNING.store(false, Ordering::SeqCst);
+}
+
+pub fn is_running() -> bool {
+    RUNNING.load(Ordering::SeqCst)
+}
+
+/// Pause or resume taking samples without stopping the sampler thread
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::SeqCst);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::SeqCst)
+}
+
+fn run(config: SamplingConfig, generation: usize) {
+    let is_current = || RUNNING.load(Ordering::SeqCst) && GENERATION.load(Ordering::SeqCst) == generation;
+
+    let jvm = match java_vm() {
+        Some(vm) => JVMAgent::new(vm),
+        None => {
+            stop_generation(generation);
+            return;
+        }
+    };
+
+    let environments = jvm.attach_current_thread(SAMPLER_THREAD_NAME)
+        .and_then(|_| jvm.get_environment())
+        .and_then(|jvmti| JNIEnvironment::current().map(|jni| (jvmti, jni)));
+
+    match environments {
+        Ok((jvmti, jni)) => {
+            let mut method_names: HashMap<usize, String> = HashMap::new();
+            let mut last_cpu_sample = precise_time_ns();
+
+            while is_current() {
+                thread::sleep(Duration::from_millis(config.interval_ms));
+
+                if ENABLED.load(Ordering::SeqCst) {
+                    if let Err(err) = sample(&*jvmti, &jni, &config, &mut method_names) {
+                        warn!(logger(), "Couldn't take sample: {}", translate_error(&err));
+                    }
+                }
+
+                if config.cpu_interval_ms > 0 && precise_time_ns() - last_cpu_sample >= config.cpu_interval_ms * 1_000_000 {
+                    last_cpu_sample = precise_time_ns();
+
+                    if let Err(err) = sample_cpu_times(&*jvmti, &jni) {
+                        warn!(logger(), "Couldn't sample CPU times: {}", translate_error(&err));
+                    }
+                }
+            }
+
+            let _ = jvm.detach_current_thread();
+        },
+        Err(err) => error!(logger(), "Couldn't start sampler: {}", translate_error(&err))
+    }
+
+    stop_generation(generation);
+}
+
+/// Mark the sampler as stopped, unless it has been restarted in the meantime
+fn stop_generation(generation: usize) {
+    if GENERATION.load(Ordering::SeqCst) == generation {
+        RUNNING.store(false, Ordering::SeqCst);
+    }
+}
+
+/// Take a single sample of all matching threads
+fn sample(env: &JVMTI, jni: &JNIEnvironment, config: &SamplingConfig, method_names: &mut HashMap<usize, String>) -> Result<(), NativeError> {
+    // The thread references returned by the JVM are released along with this frame
+    let _frame = LocalFrame::new(jni, 16)?;
+
+    for trace in env.get_all_stack_traces(config.max_depth)? {
+        if trace.frames.is_empty() || (config.runnable_only && !trace.is_runnable()) {
+            continue;
+        }
+
+        if !config.threads.is_empty() {
+            let thread = env.get_thread_info(&trace.thread_id.native_id)?;
+
+            if !config.threads.iter().any(|prefix| thread.name.starts_with(prefix.as_str())) {
+                continue;
+            }
+        }
+
+        let frames: Vec<String> = trace.frames.iter().map(|frame| method_name(env, frame, method_names)).collect();
+
+        static_context().record_sample(&frames);
+    }
+
+    Ok(())
+}
+
+/// Record the CPU time used by every live thread so far
+fn sample_cpu_times(env: &JVMTI, jni: &JNIEnvironment) -> Result<(), NativeError> {
+    let _frame = LocalFrame::new(jni, 16)?;
+
+    // Asking for no frames at all still returns every live thread
+    for trace in env.get_all_stack_traces(0)? {
+        let thread_id = &trace.thread_id;
+
+        // Threads may end while they're being sampled
+        let sample = env.get_thread_info(&thread_id.native_id).ok()
+            .and_then(|thread| java_thread_id(jni, thread_id).ok().map(|java_id| (thread, java_id)))
+            .and_then(|(thread, java_id)| env.get_thread_cpu_time(&thread_id.native_id).ok().map(|cpu_time| (thread, java_id, cpu_time)));
+
+        if let Some((thread, java_id, cpu_time)) = sample {
+            static_context().thread_cpu_sample(java_id, &thread, cpu_time);
+        }
+    }
+
+    Ok(())
+}
+
+/// Resolve the name of the method of the given frame. Names are cached, as the same methods
+/// show up in nearly every sample.
+fn method_name(env: &JVMTI, frame: &StackFrame, method_names: &mut HashMap<usize, String>) -> String {
+    method_names.entry(frame.method_id.native_id as usize).or_insert_with(|| {
+        resolve_stack_trace(env, slice::from_ref(frame)).iter().map(|element| element.method_name()).next().unwrap_or_default()
+    }).clone()
+}
@@ -0,0 +1,90 @@
+use super::class::ClassSignature;
+use super::environment::jvmti::JVMTI;
+use super::method::{MethodId, MethodSignature};
+use super::native::JavaLong;
+use super::native::jvmti_native::{JVMTI_THREAD_STATE_ALIVE, JVMTI_THREAD_STATE_BLOCKED_ON_MONITOR_ENTER, JVMTI_THREAD_STATE_RUNNABLE,
+    JVMTI_THREAD_STATE_TERMINATED, JVMTI_THREAD_STATE_WAITING_INDEFINITELY, JVMTI_THREAD_STATE_WAITING_WITH_TIMEOUT};
+use super::thread::ThreadId;
+
+///
+/// A single raw stack frame as returned by the JVM. The method id is only valid as long as the
+/// declaring class stays loaded, so frames should be resolved into `StackTraceElement`s before
+/// they're stored anywhere.
+///
+pub struct StackFrame {
+    pub method_id: MethodId,
+    pub location: JavaLong
+}
+
+///
+/// The stack of a single thread along with the thread's state (a combination of the
+/// `JVMTI_THREAD_STATE_*` flags) at the time the stack was captured.
+///
+pub struct ThreadStackTrace {
+    pub thread_id: ThreadId,
+    pub state: u32,
+    pub frames: Vec<StackFrame>
+}
+
+impl ThreadStackTrace {
+
+    /// Returns true if the thread was executing (or ready to execute) when the stack was captured
+    pub fn is_runnable(&self) -> bool {
+        self.state & JVMTI_THREAD_STATE_RUNNABLE > 0
+    }
+
+    /// Returns the name of the state as reported by `Thread.getState()`, eg. `TIMED_WAITING`
+    pub fn state_name(&self) -> &'static str {
+        match self.state {
+            state if state & JVMTI_THREAD_STATE_TERMINATED > 0 => "TERMINATED",
+            state if state & JVMTI_THREAD_STATE_ALIVE == 0 => "NEW",
+            state if state & JVMTI_THREAD_STATE_BLOCKED_ON_MONITOR_ENTER > 0 => "BLOCKED",
+            state if state & JVMTI_THREAD_STATE_WAITING_INDEFINITELY > 0 => "WAITING",
+            state if state & JVMTI_THREAD_STATE_WAITING_WITH_TIMEOUT > 0 => "TIMED_WAITING",
+            _ => "RUNNABLE"
+        }
+    }
+}
+
+///
+/// A resolved stack frame, holding the signatures of the method being executed along with the
+/// bytecode index (location) within the method.
+///
+pub struct StackTraceElement {
+    pub class_sig: ClassSignature,
+    pub method_sig: MethodSignature,
+    pub location: i64
+}
+
+impl StackTraceElement {
+
+    pub fn new(class_sig: ClassSignature, method_sig: MethodSignature, location: i64) -> StackTraceElement {
+        StackTraceElement { class_sig: class_sig, method_sig: method_sig, location: location }
+    }
+
+    /// Returns the fully qualified name of the method of this frame, without the location
+    pub fn method_name(&self) -> String {
+        format!("{}.{}", self.class_sig.to_string(), self.method_sig.name)
+    }
+
+    /// Returns the readable representation of this frame, eg. `java.lang.String.indexOf@12`
+    pub fn to_string(&self) -> String {
+        format!("{}@{}", self.method_name(), self.location)
+    }
+}
+
+///
+/// Resolves the signatures of the given raw stack frames. Frames that cannot be resolved (eg.
+/// because their class has been unloaded in the meantime) are kept with unknown signatures so
+/// that the depth of the stack trace is preserved.
+///
+pub fn resolve_stack_trace(env: &JVMTI, frames: &[StackFrame]) -> Vec<StackTraceElement> {
+    frames.iter().map(|frame| {
+        let class_sig = env.get_method_declaring_class(&frame.method_id)
+            .and_then(|class_id| env.get_class_signature(&class_id))
+            .unwrap_or(ClassSignature::unknown());
+        let method_sig = env.get_method_name(&frame.method_id).unwrap_or(MethodSignature::unknown());
+
+        StackTraceElement::new(class_sig, method_sig, frame.location as i64)
+    }).collect()
+}
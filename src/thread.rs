@@ -1,4 +1,6 @@
+use super::environment::jni::{JNI, JNIError, JObject};
 use super::native::JavaThread;
+use std::cell::Cell;
 
 ///
 /// Represents a link between a JVM thread and the Rust code calling the JVMTI API.
@@ -16,7 +18,56 @@ unsafe impl Sync for ThreadId { }
 
 pub struct Thread {
     pub id: ThreadId,
+    /// The id reported by `Thread.getId()`. It's only resolved for the threads events are reported
+    /// on, and 0 otherwise.
+    pub java_id: i64,
     pub name: String,
     pub priority: u32,
     pub is_daemon: bool
 }
+
+///
+/// Return the id of the given thread as reported by `Thread.getId()`. Unlike the thread references
+/// passed to event callbacks, it identifies the thread for its whole lifetime.
+///
+pub fn java_thread_id(env: &JNI, thread_id: &ThreadId) -> Result<i64, JNIError> {
+    env.call_method_by_name(&JObject::from(thread_id), "getId", "()J", &[]).and_then(|value| value.j().map_err(JNIError::from))
+}
+
+/// The state of the Java id of the current thread
+#[derive(Clone, Copy)]
+enum CurrentId {
+    Unknown,
+    Resolving,
+    Resolved(i64)
+}
+
+thread_local! {
+    static CURRENT_ID: Cell<CurrentId> = Cell::new(CurrentId::Unknown);
+}
+
+///
+/// Return the Java id of the current thread, which `thread_id` must refer to. The id is cached, as
+/// JVMTI callbacks run on the thread the event is about and calling Java for every event would be
+/// expensive. `None` is returned for the events raised by the `Thread.getId()` call itself.
+///
+pub fn current_java_thread_id(env: &JNI, thread_id: &ThreadId) -> Option<i64> {
+    match CURRENT_ID.with(|id| id.get()) {
+        CurrentId::Resolved(java_id) => Some(java_id),
+        CurrentId::Resolving => None,
+        CurrentId::Unknown => {
+            CURRENT_ID.with(|id| id.set(CurrentId::Resolving));
+
+            let java_id = java_thread_id(env, thread_id).ok();
+
+            CURRENT_ID.with(|id| id.set(java_id.map(CurrentId::Resolved).unwrap_or(CurrentId::Unknown)));
+            java_id
+        }
+    }
+}
+
+/// Forget the cached Java id of the current thread once it has ended, as the native thread may be
+/// attached again as another Java thread
+pub fn forget_current_java_thread_id() {
+    CURRENT_ID.with(|id| id.set(CurrentId::Unknown));
+}
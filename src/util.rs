@@ -1,17 +1,19 @@
+use super::mutf8;
 use super::native::RawString;
 use std::ffi::CStr;
 use std::ptr;
 
 ///
-/// Turns a C-style string pointer into a String instance. If the string pointer points to NULL,
+/// Turns a C-style string pointer into a String instance. The string is expected to be encoded in
+/// modified UTF-8, like all strings returned by the JVM. If the string pointer points to NULL,
 /// then a "(NULL)" string will be returned.
 ///
 pub fn stringify(input: RawString) -> String {
     unsafe {
         if input != ptr::null_mut() {
-            match CStr::from_ptr(input).to_str() {
-                Ok(string) => string.to_string(),
-                Err(_) => "(UTF8-ERROR)".to_string()
+            match mutf8::decode(CStr::from_ptr(input).to_bytes()) {
+                Some(string) => string,
+                None => "(UTF8-ERROR)".to_string()
             }
         } else {
             "(NULL)".to_string()
@@ -5,14 +5,14 @@ mod tests {
 
     use jvmti::agent::Agent;
     use jvmti::emulator::JVMEmulator;
-    use jvmti::runtime::MethodInvocationEvent;
+    use jvmti::runtime::{MethodInvocationEvent, MonitorEvent, MonitorWaitEvent, MonitorWaitedEvent, ObjectAllocationEvent};
     use jvmti::thread::Thread;
     use jvmti::version::VersionNumber;
 
     #[test]
     fn agents_are_fucking_even_working() {
         let emulator = JVMEmulator::new();
-        let agent = Agent::new_from(Box::new(emulator));
+        let agent = Agent::new_from(Box::new(emulator)).ok().unwrap();
         let version = agent.get_version();
 
         assert_eq!(0x7FFF, version.major_version);
@@ -21,7 +21,7 @@ mod tests {
     #[test]
     fn agents_are_initialized_with_empty_capabilities() {
         let emulator = JVMEmulator::new();
-        let agent = Agent::new_from(Box::new(emulator));
+        let agent = Agent::new_from(Box::new(emulator)).ok().unwrap();
 
         assert_eq!(false, agent.capabilities.can_suspend);
         assert_eq!(false, agent.capabilities.can_pop_frame);
@@ -36,14 +36,14 @@ mod tests {
     #[test]
     fn agents_respond_to_shutdown() {
         let emulator = JVMEmulator::new();
-        let agent = Agent::new_from(Box::new(emulator));
+        let agent = Agent::new_from(Box::new(emulator)).ok().unwrap();
         agent.shutdown();
     }
 
     #[test]
     fn agents_provide_with_version_numbers() {
         let emulator = JVMEmulator::new();
-        let agent = Agent::new_from(Box::new(emulator));
+        let agent = Agent::new_from(Box::new(emulator)).ok().unwrap();
         let version = agent.get_version();
         let unknown_version = VersionNumber::unknown();
         assert_eq!(unknown_version.major_version, version.major_version);
@@ -54,7 +54,7 @@ mod tests {
     #[test]
     fn callbacks_trigger_capabilities() {
         let emulator = JVMEmulator::new();
-        let mut agent = Agent::new_from(Box::new(emulator));
+        let mut agent = Agent::new_from(Box::new(emulator)).ok().unwrap();
 
         agent.on_method_entry(Some(test_on_method_entry));
         assert_eq!(true, agent.capabilities.can_generate_method_entry_events);
@@ -62,11 +62,11 @@ mod tests {
         assert_eq!(false, agent.capabilities.can_generate_method_entry_events);
 
         assert_eq!(false, agent.capabilities.can_generate_monitor_events);
-        agent.on_monitor_wait(Some(test_on_monitor_events));
+        agent.on_monitor_wait(Some(test_on_monitor_wait));
         assert_eq!(true, agent.capabilities.can_generate_monitor_events);
-        agent.on_monitor_waited(Some(test_on_monitor_events));
+        agent.on_monitor_waited(Some(test_on_monitor_waited));
         assert_eq!(true, agent.capabilities.can_generate_monitor_events);
-        agent.on_monitor_contended_enter(Some(test_on_monitor_events));
+        agent.on_monitor_contended_enter(Some(test_on_monitor_contended_enter));
         assert_eq!(true, agent.capabilities.can_generate_monitor_events);
         agent.on_monitor_wait(None);
         assert_eq!(true, agent.capabilities.can_generate_monitor_events);
@@ -75,13 +75,49 @@ mod tests {
         agent.on_monitor_contended_enter(None);
     }
 
+    #[test]
+    fn sampled_allocation_callbacks_trigger_capabilities() {
+        let emulator = JVMEmulator::new();
+        let mut agent = Agent::new_from(Box::new(emulator)).ok().unwrap();
+
+        assert_eq!(false, agent.capabilities.can_generate_sampled_object_alloc_events);
+        agent.on_sampled_object_alloc(Some(test_on_object_alloc));
+        assert_eq!(true, agent.capabilities.can_generate_sampled_object_alloc_events);
+        assert_eq!(false, agent.capabilities.can_generate_vm_object_alloc_events);
+        agent.on_sampled_object_alloc(None);
+        assert_eq!(false, agent.capabilities.can_generate_sampled_object_alloc_events);
+    }
+
+    #[test]
+    fn heap_sampling_interval_can_be_set() {
+        let emulator = JVMEmulator::new();
+        let mut agent = Agent::new_from(Box::new(emulator)).ok().unwrap();
+
+        assert!(agent.set_heap_sampling_interval(64 * 1024).is_none());
+    }
+
+    #[allow(unused_variables)]
+    fn test_on_object_alloc(event: ObjectAllocationEvent) {
+        // this is a callback method for testing purposes
+    }
+
     #[allow(unused_variables)]
     fn test_on_method_entry(event: MethodInvocationEvent) {
         // this is a callback method for testing purposes
     }
 
     #[allow(unused_variables)]
-    fn test_on_monitor_events(thread: Thread) {
+    fn test_on_monitor_wait(event: MonitorWaitEvent) {
+        // this is a callback method for testing purposes
+    }
+
+    #[allow(unused_variables)]
+    fn test_on_monitor_waited(event: MonitorWaitedEvent) {
+        // this is a callback method for testing purposes
+    }
+
+    #[allow(unused_variables)]
+    fn test_on_monitor_contended_enter(event: MonitorEvent) {
         // this is a callback method for testing purposes
     }
 }
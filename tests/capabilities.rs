@@ -40,4 +40,33 @@ mod tests {
         assert_eq!(true, caps_result.can_pop_frame);
         assert_eq!(true, caps_result.can_generate_monitor_events);
     }
+
+    #[test]
+    fn merge_keeps_flags_stored_outside_the_first_native_word() {
+        let mut caps1 = Capabilities::new();
+        let mut caps2 = Capabilities::new();
+
+        caps1.can_generate_object_free_events = true;
+        caps2.can_generate_sampled_object_alloc_events = true;
+
+        let caps_result = caps1.merge(&caps2);
+
+        assert_eq!(true, caps_result.can_generate_object_free_events);
+        assert_eq!(true, caps_result.can_generate_sampled_object_alloc_events);
+        assert_eq!(false, caps_result.can_pop_frame);
+    }
+
+    #[test]
+    fn java_11_capabilities_are_mapped_to_their_native_bits() {
+        let mut caps = Capabilities::new();
+        caps.can_generate_early_vmstart = true;
+        caps.can_generate_early_class_hook_events = true;
+        caps.can_generate_sampled_object_alloc_events = true;
+
+        let native_caps = caps.to_native();
+
+        assert_eq!(0, native_caps._bindgen_bitfield_1_);
+        assert_eq!(0x00000E00, native_caps._bindgen_bitfield_2_);
+        assert_eq!(true, Capabilities::from_native(&native_caps).can_generate_sampled_object_alloc_events);
+    }
 }
@@ -48,4 +48,16 @@ mod tests {
         assert_eq!("so.blacklight.Test", Class::new(ClassId { native_id: ptr::null_mut() }, JavaType::Class("Lso/blacklight/Test;")).to_string());
         assert_eq!("so.blacklight.Test$1", Class::new(ClassId { native_id: ptr::null_mut() }, JavaType::Class("Lso/blacklight/Test$1;")).to_string());
     }
+
+    #[test]
+    fn method_arguments_are_parsed() {
+        assert_eq!(Some(vec![]), JavaType::parse_arguments("()V"));
+        assert_eq!(Some(vec![JavaType::Int, JavaType::Array(Box::new(JavaType::Long)), JavaType::Class("Ljava/lang/String;"), JavaType::Double]),
+            JavaType::parse_arguments("(I[JLjava/lang/String;D)Ljava/lang/Object;"));
+        assert_eq!(None, JavaType::parse_arguments("I)V"));
+        assert_eq!(None, JavaType::parse_arguments("(Ljava/lang/String)V"));
+        assert_eq!(None, JavaType::parse_arguments("([)V"));
+        assert_eq!(2, JavaType::Long.slot_size());
+        assert_eq!(1, JavaType::Class("LTest;").slot_size());
+    }
 }
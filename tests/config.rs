@@ -0,0 +1,86 @@
+extern crate jvmti;
+
+#[cfg(test)]
+mod tests {
+
+    use jvmti::config::{AllocationsConfig, Config, ConfigError, SamplingConfig, SCHEMA_VERSION};
+    use std::env;
+    use std::fs::File;
+    use std::io::Write;
+
+    #[test]
+    fn every_setting_is_optional() {
+        let (config, warnings) = Config::parse("").unwrap();
+
+        // Files without a version are read as version 1
+        assert_eq!(1, config.version);
+        assert_eq!("default", config.agent_name);
+        assert!(config.instrumentation.entry_points.is_empty());
+        assert_eq!(SamplingConfig::default(), config.sampling);
+        assert!(!config.locks.enabled);
+        assert!(!config.timing.enabled);
+        assert_eq!(AllocationsConfig::default(), config.allocations);
+        assert!(warnings.is_empty());
+
+        let (config, warnings) = Config::parse("version = 2\n[sampling]\ninterval_ms = 5\n[locks]\nenabled = true\n[timing]\nenabled = true\n[allocations]\nenabled = true\nsampling_interval = 4096\n").unwrap();
+
+        assert_eq!(SCHEMA_VERSION, config.version);
+        assert_eq!(5, config.sampling.interval_ms);
+        assert!(config.locks.enabled);
+        assert!(config.timing.enabled);
+        assert_eq!(AllocationsConfig { enabled: true, sampling_interval: 4096 }, config.allocations);
+        assert_eq!(64, config.sampling.max_depth);
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn version_1_instrumentation_rules_are_moved_to_their_section() {
+        let (config, warnings) = Config::parse("agent_name = \"Test\"\nentry_points = [\n    \".Hello.main\"\n]\n").unwrap();
+
+        assert_eq!(vec![".Hello.main".to_string()], config.instrumentation.entry_points);
+        assert!(config.is_entry_point("", "Hello", "main"));
+        assert_eq!(vec![ConfigError::new(Some(2), "`entry_points` should be set in the [instrumentation] section since version 2")], warnings);
+    }
+
+    #[test]
+    fn unknown_keys_are_reported_with_suggestions() {
+        let text = "version = 2\n\n[sampling]\nintervall_ms = 5\nthreads = [\"main\"]\n\n[exporters]\nformats = []\n";
+        let (config, warnings) = Config::parse(text).unwrap();
+
+        assert_eq!(vec!["main".to_string()], config.sampling.threads);
+        assert_eq!(vec![
+            "line 7: unknown key `exporters`".to_string(),
+            "line 4: unknown key `sampling.intervall_ms`, did you mean `interval_ms`?".to_string()
+        ], warnings.iter().map(|warning| warning.to_string()).collect::<Vec<String>>());
+    }
+
+    #[test]
+    fn errors_point_at_the_line() {
+        assert_eq!(ConfigError::new(Some(3), "unexpected eof encountered"), Config::parse("[sampling]\nthreads = [\n").unwrap_err());
+        assert_eq!("line 3: invalid type: string \"fast\", expected u64 for key `sampling.interval_ms`",
+            Config::parse("version = 2\n[sampling]\ninterval_ms = \"fast\"\n").unwrap_err().to_string());
+        assert_eq!("line 2: invalid value: integer `70000`, expected u16 for key `prometheus.port`",
+            Config::parse("[prometheus]\nport = 70000\n").unwrap_err().to_string());
+        assert_eq!("line 1: unsupported configuration version 3, expected 1 to 2", Config::parse("version = 3\n").unwrap_err().to_string());
+        assert_eq!("line 3: `export.interval_ms` must be greater than 0", Config::parse("[export]\nformats = [\"csv\"]\ninterval_ms = 0\n").unwrap_err().to_string());
+        assert_eq!("line 3: `gc.budget_window_ms` must be greater than 0 if `gc.budget_ms` is set",
+            Config::parse("[gc]\nbudget_ms = 50\nbudget_window_ms = 0\n").unwrap_err().to_string());
+        assert!(Config::parse("[gc]\nbudget_ms = 0\nbudget_window_ms = 0\n").is_ok());
+        assert_eq!("line 2: `allocations.sampling_interval` must not be negative", Config::parse("[allocations]\nsampling_interval = -1\n").unwrap_err().to_string());
+        assert_eq!("line 3: `gc.count_freed_sampled` needs `allocations.enabled` to be set",
+            Config::parse("[gc]\nenabled = true\ncount_freed_sampled = true\n").unwrap_err().to_string());
+        assert!(Config::parse("[gc]\nenabled = true\ncount_freed_sampled = true\n[allocations]\nenabled = true\n").is_ok());
+    }
+
+    #[test]
+    fn configuration_is_read_from_the_given_file() {
+        let path = env::temp_dir().join("jvmti-config-test.conf");
+
+        File::create(&path).unwrap().write_all(b"version = 2\n[instrumentation]\nactive_classes = [\"Hello\"]\n").unwrap();
+
+        let (config, _) = Config::read_from_file(&path).unwrap();
+
+        assert_eq!(vec!["Hello".to_string()], config.instrumentation.active_classes);
+        assert!(Config::read_from_file(env::temp_dir().join("jvmti-missing.conf")).is_err());
+    }
+}
@@ -3,8 +3,35 @@ extern crate jvmti;
 #[cfg(test)]
 mod tests {
 
+    use jvmti::context::AgentContext;
+    use jvmti::runtime::ThreadEvent;
+    use jvmti::thread::{Thread, ThreadId};
+    use std::ptr;
+
+    fn thread_event(cpu_time: Option<u64>) -> ThreadEvent {
+        ThreadEvent {
+            thread: Thread { id: ThreadId { native_id: ptr::null_mut() }, java_id: 7, name: "worker".to_string(), priority: 5, is_daemon: true },
+            cpu_time: cpu_time
+        }
+    }
+
     #[test]
     fn test() {
         
     }
+
+    #[test]
+    fn thread_lifetimes_are_never_negative() {
+        let context = AgentContext::new();
+
+        context.thread_start(&thread_event(None));
+        assert!(context.thread_record(7).unwrap().is_daemon);
+
+        let lifetime = context.thread_end(&thread_event(Some(1500))).unwrap();
+
+        assert!(lifetime.num_nanoseconds().unwrap() >= 0);
+        assert!(context.thread_record(7).is_none());
+        assert_eq!(1500, context.top_threads(1)[0].cpu_time);
+        assert_eq!((1, 1), context.thread_churn());
+    }
 }
@@ -0,0 +1,85 @@
+extern crate jvmti;
+
+#[cfg(test)]
+mod tests {
+
+    use jvmti::config::ControlConfig;
+    use jvmti::context::static_context;
+    use jvmti::control::{self, Command, ControlClient, Controller, Profiler};
+    use std::env;
+    use std::fs;
+    use std::io::{BufRead, BufReader, Write};
+    use std::os::unix::fs::PermissionsExt;
+    use std::os::unix::net::UnixStream;
+    use std::process;
+
+    #[test]
+    fn commands_are_parsed_from_words_and_json() {
+        assert_eq!(Ok(Command::Enable(Profiler::Sampling)), Command::parse("enable sampling\n"));
+        assert_eq!(Ok(Command::Set("sampling.interval_ms".to_string(), 5)), Command::parse(r#"{"command": "set", "args": ["sampling.interval_ms", 5]}"#));
+        assert_eq!(Ok(Command::Report("methods".to_string(), 3)), Command::parse("report  methods 3"));
+        assert_eq!(Ok(Command::Threads(32)), Command::parse("threads"));
+        assert_eq!(Ok(Command::Watch("com.example.Dao.query".to_string())), Command::parse("watch com.example.Dao.query"));
+
+        assert_eq!(Err("unknown profiler: heap, expected sampling, exceptions or timeline".to_string()), Command::parse("disable heap"));
+        assert_eq!(Err("not a number: fast".to_string()), Command::parse("set sampling.interval_ms fast"));
+        assert_eq!(Err("wrong number of arguments for reset, see help".to_string()), Command::parse("reset"));
+        assert_eq!(Err("unknown command: explode, see help".to_string()), Command::parse("explode"));
+        assert!(Command::parse("{\"args\": []}").unwrap_err().starts_with("invalid JSON command"));
+    }
+
+    #[test]
+    fn commands_change_the_agent_context() {
+        let mut controller = Controller::new();
+
+        assert_eq!(Ok(vec!["sampling.max_depth set to 16".to_string()]), controller.execute(&Command::Set("sampling.max_depth".to_string(), 16)));
+        assert_eq!(16, static_context().config.read().unwrap().sampling.max_depth);
+        assert!(controller.execute(&Command::Set("sampling.interval_ms".to_string(), 0)).is_err());
+
+        assert_eq!(Ok(vec!["timeline enabled".to_string()]), controller.execute(&Command::Enable(Profiler::Timeline)));
+        assert_eq!(Some(0), static_context().timeline_len());
+        assert!(controller.execute(&Command::Status).unwrap().contains(&"timeline: enabled (0 events)".to_string()));
+
+        // Exception events are switched through the environment of the agent, which needs a JVM
+        assert!(controller.execute(&Command::Enable(Profiler::Exceptions)).is_err());
+        assert!(!static_context().config.read().unwrap().exceptions.enabled);
+
+        // Watching methods depends on the slow invocation detector, which needs a JVM
+        assert!(controller.execute(&Command::Watch("Hello.main".to_string())).is_err());
+        assert_eq!(Err("Hello.main isn't watched".to_string()), controller.execute(&Command::Unwatch("Hello.main".to_string())));
+        assert!(controller.execute(&Command::Threads(8)).is_err());
+
+        assert_eq!(r#"{"ok":true,"lines":["reset locks"]}"#, controller.handle("reset locks"));
+        assert_eq!(r#"{"ok":false,"error":"no timings recorded for Hello.main"}"#, controller.handle("histogram Hello.main"));
+    }
+
+    #[test]
+    fn commands_are_answered_on_the_control_socket() {
+        let path = env::temp_dir().join(format!("jvmti-control-test-{}.sock", process::id()));
+        let config = ControlConfig { enabled: true, path: Some(path.to_string_lossy().into_owned()) };
+
+        assert_eq!(path, control::start(&config).unwrap());
+        assert!(control::start(&config).is_err());
+        assert_eq!(0o600, fs::metadata(&path).unwrap().permissions().mode() & 0o777);
+
+        {
+            let mut stream = UnixStream::connect(&path).unwrap();
+            stream.write_all(b"\nreport gc\n{\"command\": \"enable\", \"args\": [\"heap\"]}\n").unwrap();
+
+            let mut responses = BufReader::new(stream).lines();
+            assert_eq!(r#"{"ok":true,"lines":["GC pause tracking isn't enabled"]}"#, responses.next().unwrap().unwrap());
+            assert_eq!(r#"{"ok":false,"error":"unknown profiler: heap, expected sampling, exceptions or timeline"}"#, responses.next().unwrap().unwrap());
+        }
+
+        // Clients are served one at a time, the next one is accepted once the previous one is gone
+        let mut client = ControlClient::connect(&path).unwrap();
+        assert_eq!(Ok(vec!["reset samples".to_string()]), client.send("reset samples").unwrap());
+        assert_eq!(Ok(vec![]), client.send("stacks samples").unwrap());
+        assert_eq!(Err("unknown profile: heap, expected one of samples, calltree, allocations, locks".to_string()), client.send("stacks heap").unwrap());
+        drop(client);
+
+        control::stop();
+        assert!(!control::is_running());
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,56 @@
+extern crate jvmti;
+
+#[cfg(test)]
+mod tests {
+
+    use jvmti::class::ClassId;
+    use jvmti::environment::jni::{return_type, JObject, JValue};
+    use jvmti::error::NativeError;
+    use jvmti::class::JavaType;
+    use std::ptr;
+
+    #[test]
+    fn jvalue_accessors_return_the_wrapped_value() {
+        assert_eq!(Ok(true), JValue::Boolean(true).z());
+        assert_eq!(Ok(-3), JValue::Byte(-3).b());
+        assert_eq!(Ok(65), JValue::Char(65).c());
+        assert_eq!(Ok(42), JValue::Int(42).i());
+        assert_eq!(Ok(1 << 40), JValue::Long(1 << 40).j());
+        assert_eq!(Ok(2.5), JValue::Double(2.5).d());
+        assert!(JValue::Object(JObject::null()).l().unwrap().is_null());
+    }
+
+    #[test]
+    fn jvalue_accessors_reject_other_types() {
+        assert_eq!(Err(NativeError::TypeMismatch), JValue::Int(42).j());
+        assert_eq!(Err(NativeError::TypeMismatch), JValue::Void.l());
+        assert_eq!(Err(NativeError::TypeMismatch), JValue::Long(42).i());
+    }
+
+    #[test]
+    fn jvalue_converts_to_native_union() {
+        unsafe {
+            assert_eq!(1, JValue::Boolean(true).to_native().z);
+            assert_eq!(-7, JValue::Int(-7).to_native().i);
+            assert_eq!(1 << 40, JValue::Long(1 << 40).to_native().j);
+            assert_eq!(0.5, JValue::Float(0.5).to_native().f);
+            assert!(JValue::Object(JObject::null()).to_native().l.is_null());
+        }
+    }
+
+    #[test]
+    fn jobject_wraps_class_ids() {
+        let class_id = ClassId { native_id: ptr::null_mut() };
+
+        assert!(JObject::from(&class_id).is_null());
+        assert_eq!(JObject::null(), JObject::from(&class_id));
+    }
+
+    #[test]
+    fn return_type_is_parsed_from_method_signatures() {
+        assert_eq!(Ok(JavaType::Int), return_type("(Ljava/lang/String;J)I"));
+        assert_eq!(Ok(JavaType::Void), return_type("()V"));
+        assert_eq!(Ok(JavaType::Class("Ljava/lang/String;")), return_type("()Ljava/lang/String;"));
+        assert_eq!(Err(NativeError::IllegalArgument), return_type("I"));
+    }
+}
@@ -1,2 +1,3 @@
+mod jni;
 mod jvm;
 mod jvmti;
@@ -0,0 +1,45 @@
+extern crate jvmti;
+
+#[cfg(test)]
+mod tests {
+
+    use jvmti::error::{wrap_error, translate_error, NativeError};
+
+    #[test]
+    fn known_error_codes_are_wrapped_into_their_variants() {
+        assert_eq!(NativeError::NoError, wrap_error(0));
+        assert_eq!(NativeError::InvalidThread, wrap_error(10));
+        assert_eq!(NativeError::InvalidClass, wrap_error(21));
+        assert_eq!(NativeError::ClassNotPrepared, wrap_error(22));
+        assert_eq!(NativeError::UnmodifiableClass, wrap_error(79));
+        assert_eq!(NativeError::WrongPhase, wrap_error(112));
+        assert_eq!(NativeError::Disconnected, wrap_error(116));
+    }
+
+    #[test]
+    fn wrapped_errors_return_their_original_code() {
+        for code in 0..200 {
+            assert_eq!(code, wrap_error(code).code());
+        }
+    }
+
+    #[test]
+    fn unknown_error_codes_are_retained() {
+        assert_eq!(NativeError::UnknownError(12345), wrap_error(12345));
+        assert_eq!(12345, wrap_error(12345).code());
+    }
+
+    #[test]
+    fn errors_are_displayed_with_their_description_and_code() {
+        let error = NativeError::WrongPhase;
+
+        assert_eq!(format!("{} (error code 112)", translate_error(&error)), format!("{}", error));
+    }
+
+    #[test]
+    fn native_errors_can_be_used_as_std_errors() {
+        let error: Box<::std::error::Error> = Box::new(NativeError::InvalidThread);
+
+        assert!(format!("{}", error).contains("error code 10"));
+    }
+}
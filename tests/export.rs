@@ -0,0 +1,191 @@
+extern crate jvmti;
+
+#[cfg(test)]
+mod tests {
+
+    use jvmti::config::{ExportConfig, PrometheusConfig, StatsdConfig};
+    use jvmti::export::{exporters, Exporter, Snapshot};
+    use jvmti::export::prometheus::{self, HistogramSample};
+    use jvmti::export::rotate::RotatingFile;
+    use jvmti::export::sinks::{CsvExporter, JsonLinesExporter, TableExporter};
+    use jvmti::export::statsd::{self, StatsdExporter};
+    use jvmti::profile::histogram::Histogram;
+    use std::env;
+    use std::collections::BTreeMap;
+    use std::fs;
+    use std::io::{Read, Write};
+    use std::net::{TcpStream, UdpSocket};
+    use std::time::Duration;
+    use std::path::PathBuf;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("jvmti-export-test-{}-{}", name, ::std::process::id()));
+
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn snapshot() -> Snapshot {
+        let mut snapshot = Snapshot::new(1500000000000);
+
+        snapshot.add("method_calls", &[("method", "Hello.main")], 3.0);
+        snapshot.add("monitor_contentions", &[("site", "Lock, at Hello.run")], 1.0);
+        snapshot.add("gc_pause_time_ns", &[], 1250.5);
+        snapshot
+    }
+
+    #[test]
+    fn rotating_files_are_rotated_once_they_are_full() {
+        let dir = temp_dir("rotate");
+        let mut file = RotatingFile::new(dir.join("metrics.csv"), 20, 2);
+
+        for _ in 0..4 {
+            file.write(b"0123456789\n", Some(b"header\n")).unwrap();
+        }
+
+        assert_eq!("header\n0123456789\n", fs::read_to_string(file.path()).unwrap());
+        assert_eq!("header\n0123456789\n", fs::read_to_string(file.rotated_path(1)).unwrap());
+        assert_eq!("header\n0123456789\n", fs::read_to_string(file.rotated_path(2)).unwrap());
+        assert!(!file.rotated_path(3).exists());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn sinks_write_metrics_in_their_formats() {
+        let dir = temp_dir("sinks");
+        let directory = dir.to_str().unwrap();
+
+        JsonLinesExporter::new(directory, 1024, 1).export(&snapshot()).unwrap();
+        CsvExporter::new(directory, 1024, 1).export(&snapshot()).unwrap();
+        TableExporter::new(directory, 1024, 1).export(&snapshot()).unwrap();
+
+        let json = fs::read_to_string(dir.join("metrics.jsonl")).unwrap();
+        let csv = fs::read_to_string(dir.join("metrics.csv")).unwrap();
+        let table = fs::read_to_string(dir.join("metrics.txt")).unwrap();
+
+        assert_eq!(Some(r#"{"timestamp":1500000000000,"name":"method_calls","labels":{"method":"Hello.main"},"value":3.0}"#), json.lines().next());
+        assert_eq!(3, json.lines().count());
+        assert_eq!(vec![
+            "timestamp,name,labels,value",
+            "1500000000000,method_calls,method=Hello.main,3",
+            "1500000000000,monitor_contentions,\"site=Lock, at Hello.run\",1",
+            "1500000000000,gc_pause_time_ns,,1250.500"
+        ], csv.lines().collect::<Vec<&str>>());
+        assert_eq!(vec![
+            "Snapshot at 1500000000000",
+            "METRIC               LABELS                      VALUE",
+            "method_calls         method=Hello.main               3",
+            "monitor_contentions  site=Lock, at Hello.run         1",
+            "gc_pause_time_ns                              1250.500",
+            ""
+        ], table.lines().collect::<Vec<&str>>());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn unknown_export_formats_are_skipped() {
+        let config = ExportConfig { formats: vec!["csv".to_string(), "xml".to_string(), "table".to_string()], .. ExportConfig::default() };
+
+        assert_eq!(2, exporters(&config, "/tmp").len());
+        assert!(!ExportConfig::default().is_enabled());
+    }
+
+    #[test]
+    fn prometheus_exposition_groups_samples_by_metric() {
+        let mut snapshot = snapshot();
+        let mut histogram = Histogram::new();
+        let mut labels = BTreeMap::new();
+
+        snapshot.add("method_calls", &[("method", "Quote\"d")], 1.0);
+        snapshot.add("threads_live", &[], 4.0);
+        histogram.record(500_000);
+        histogram.record(2_000_000);
+        labels.insert("method".to_string(), "Hello.main".to_string());
+
+        let text = prometheus::render(&snapshot, &[HistogramSample { name: "method_duration_ns".to_string(), labels: labels, histogram: histogram, sum: 2_500_000 }]);
+        let lines: Vec<&str> = text.lines().collect();
+
+        assert_eq!(vec![
+            "# TYPE jvmti_method_calls counter",
+            "jvmti_method_calls{method=\"Hello.main\"} 3",
+            "jvmti_method_calls{method=\"Quote\\\"d\"} 1",
+            "# TYPE jvmti_monitor_contentions counter"
+        ], lines[..4].to_vec());
+        assert!(lines.contains(&"# TYPE jvmti_threads_live gauge"));
+        assert!(lines.contains(&"# TYPE jvmti_method_duration_ns histogram"));
+        assert!(lines.contains(&"jvmti_method_duration_ns_bucket{method=\"Hello.main\",le=\"100000\"} 0"));
+        assert!(lines.contains(&"jvmti_method_duration_ns_bucket{method=\"Hello.main\",le=\"1000000\"} 1"));
+        assert!(lines.contains(&"jvmti_method_duration_ns_bucket{method=\"Hello.main\",le=\"5000000\"} 2"));
+        assert!(lines.contains(&"jvmti_method_duration_ns_bucket{method=\"Hello.main\",le=\"+Inf\"} 2"));
+        assert!(lines.contains(&"jvmti_method_duration_ns_sum{method=\"Hello.main\"} 2500000"));
+        assert!(lines.contains(&"jvmti_method_duration_ns_count{method=\"Hello.main\"} 2"));
+    }
+
+    #[test]
+    fn prometheus_server_serves_metrics_on_the_configured_path() {
+        let address = prometheus::start(PrometheusConfig { port: Some(0), .. PrometheusConfig::default() }).unwrap();
+        let get = |path: &str| {
+            let mut stream = TcpStream::connect(address).unwrap();
+            let mut response = String::new();
+
+            stream.write_all(format!("GET {} HTTP/1.1\r\nHost: localhost\r\n\r\n", path).as_bytes()).unwrap();
+            stream.read_to_string(&mut response).unwrap();
+            response
+        };
+
+        let metrics = get("/metrics");
+        let missing = get("/");
+
+        prometheus::stop();
+
+        assert!(metrics.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(metrics.contains("Content-Type: text/plain; version=0.0.4"));
+        assert!(metrics.contains("\r\n# TYPE jvmti_threads_live gauge\njvmti_threads_live 0\n"));
+        assert!(missing.starts_with("HTTP/1.1 404 Not Found\r\n"));
+        assert!(!prometheus::is_running());
+    }
+
+    #[test]
+    fn statsd_counters_are_sent_as_increments() {
+        let mut exporter = StatsdExporter::new(StatsdConfig { tags: vec!["env:test".to_string()], .. StatsdConfig::default() }, "agent 1");
+        let mut later = snapshot();
+
+        later.metrics[0].value = 5.0;
+        later.add("gc_pause_max_ns", &[], 1000.0);
+
+        assert_eq!(vec![
+            "jvmti.method_calls:3|c|#method:Hello.main,agent_id:agent_1,env:test",
+            "jvmti.monitor_contentions:1|c|#site:Lock__at_Hello.run,agent_id:agent_1,env:test",
+            "jvmti.gc_pause_time_ns:1250.500|c|#agent_id:agent_1,env:test"
+        ], exporter.lines(&snapshot()));
+        assert_eq!(vec![
+            "jvmti.method_calls:2|c|#method:Hello.main,agent_id:agent_1,env:test",
+            "jvmti.gc_pause_max_ns:1000|g|#agent_id:agent_1,env:test"
+        ], exporter.lines(&later));
+
+        let mut plain = StatsdExporter::new(StatsdConfig { dogstatsd: false, prefix: String::new(), .. StatsdConfig::default() }, "agent");
+
+        assert_eq!("method_calls.Hello_main:3|c", plain.lines(&snapshot())[0]);
+        assert_eq!(vec!["a\nbb", "ccc"], statsd::packets(&["a".to_string(), "bb".to_string(), "ccc".to_string()], 5));
+    }
+
+    #[test]
+    fn statsd_metrics_are_sent_over_udp() {
+        let listener = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let config = StatsdConfig { port: listener.local_addr().unwrap().port(), .. StatsdConfig::default() };
+        let mut buffer = [0; 2048];
+
+        listener.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+        StatsdExporter::new(config.clone(), "agent").export(&snapshot()).unwrap();
+
+        let len = listener.recv(&mut buffer).unwrap();
+
+        assert!(String::from_utf8_lossy(&buffer[..len]).starts_with("jvmti.method_calls:3|c|#method:Hello.main,agent_id:agent\n"));
+
+        // Sends to a port nobody listens on are dropped silently
+        drop(listener);
+        assert!(StatsdExporter::new(config, "agent").export(&snapshot()).is_ok());
+    }
+}
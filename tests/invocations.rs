@@ -0,0 +1,52 @@
+extern crate jvmti;
+
+#[cfg(test)]
+mod tests {
+
+    use jvmti::config::SlowInvocationsConfig;
+    use jvmti::control::{Command, Controller};
+    use jvmti::emulator::JVMEmulator;
+    use jvmti::event::VMEvent;
+    use jvmti::invocations;
+    use std::thread;
+
+    #[test]
+    fn watching_methods_toggles_method_events() {
+        let emulator = JVMEmulator::new();
+        let events = emulator.events.clone();
+        let is_enabled = |event: VMEvent| events.lock().unwrap().get(&event).cloned();
+
+        assert!(invocations::init(Box::new(emulator), &SlowInvocationsConfig::default(), false).is_ok());
+        assert_eq!(Some(false), is_enabled(VMEvent::MethodExit));
+
+        let mut controller = Controller::new();
+
+        assert_eq!(Ok(vec!["watching Hello.main".to_string()]), controller.execute(&Command::Watch("Hello.main".to_string())));
+        assert_eq!(Some(true), is_enabled(VMEvent::MethodEntry));
+        assert_eq!(Some(false), is_enabled(VMEvent::MethodExit));
+
+        assert_eq!(Ok(vec!["watching Hello.run".to_string()]), controller.execute(&Command::Watch("Hello.run".to_string())));
+        assert_eq!(Ok(vec!["no longer watching Hello.main".to_string()]), controller.execute(&Command::Unwatch("Hello.main".to_string())));
+        assert_eq!(Some(true), is_enabled(VMEvent::MethodEntry));
+
+        // Unwatching the last method switches method entry events off again
+        assert_eq!(Ok(vec!["no longer watching Hello.run".to_string()]), controller.execute(&Command::Unwatch("Hello.run".to_string())));
+        assert_eq!(Some(false), is_enabled(VMEvent::MethodEntry));
+    }
+
+    #[test]
+    fn capturing_is_flagged_for_the_current_thread_only() {
+        assert!(!invocations::is_capturing());
+
+        let nested = invocations::capturing(|| {
+            assert!(invocations::is_capturing());
+            assert!(!thread::spawn(invocations::is_capturing).join().unwrap());
+
+            invocations::capturing(invocations::is_capturing)
+        });
+
+        // The flag is restored once the outermost capture has finished
+        assert!(nested);
+        assert!(!invocations::is_capturing());
+    }
+}
@@ -0,0 +1,83 @@
+extern crate jvmti;
+#[macro_use]
+extern crate slog;
+
+#[cfg(test)]
+mod tests {
+
+    use jvmti::logging::{self, Level, LogConfig, LogOutput, ModuleFilter};
+    use jvmti::options::Options;
+    use slog::{Discard, Drain, Logger, Never, OwnedKVList, Record};
+    use std::env;
+    use std::fs::{self, File};
+    use std::io::Read;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Drain counting the records it receives
+    struct Counter(Arc<AtomicUsize>);
+
+    impl Drain for Counter {
+        type Ok = ();
+        type Err = Never;
+
+        fn log(&self, _: &Record, _: &OwnedKVList) -> Result<(), Never> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn log_outputs_are_parsed() {
+        assert_eq!(LogOutput::Stderr, LogOutput::parse("stderr"));
+        assert_eq!(LogOutput::Syslog("/dev/log".to_string()), LogOutput::parse("syslog"));
+        assert_eq!(LogOutput::Syslog("/run/log.sock".to_string()), LogOutput::parse("syslog:/run/log.sock"));
+        assert_eq!(LogOutput::File("agent.log".to_string()), LogOutput::parse("agent.log"));
+    }
+
+    #[test]
+    fn log_config_defaults_to_info_on_stderr() {
+        assert_eq!(LogConfig::default(), LogConfig::from_options(&Options::parse("agentid=test".to_string())));
+    }
+
+    #[test]
+    fn modules_use_the_level_of_their_closest_configured_parent() {
+        let modules = vec![("profile".to_string(), Level::Debug), ("jvmti::profile::gc".to_string(), Level::Error)];
+        let filter = ModuleFilter::new(Discard, Level::Warning, &modules);
+
+        assert_eq!(Level::Warning, filter.level_for("jvmti::event_handler"));
+        assert_eq!(Level::Debug, filter.level_for("jvmti::profile"));
+        assert_eq!(Level::Debug, filter.level_for("jvmti::profile::locks"));
+        assert_eq!(Level::Error, filter.level_for("jvmti::profile::gc"));
+        assert_eq!(Level::Warning, filter.level_for("jvmti::profiler"));
+    }
+
+    #[test]
+    fn trace_records_are_not_compiled_out() {
+        let count = Arc::new(AtomicUsize::new(0));
+        let logger = Logger::root(ModuleFilter::new(Counter(count.clone()), Level::Trace, &[]).ignore_res(), o!());
+
+        trace!(logger, "Kept");
+        debug!(logger, "Kept");
+
+        assert_eq!(2, count.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn records_are_written_to_the_log_file() {
+        let path = env::temp_dir().join(format!("jvmti-log-test-{}", ::std::process::id())).join("agent.log");
+        let config = LogConfig { output: LogOutput::File(path.to_string_lossy().into_owned()), level: Level::Info, modules: vec![] };
+
+        logging::init(&config).unwrap();
+        info!(logging::logger(), "Written {}", 1);
+        debug!(logging::logger(), "Filtered");
+        logging::shutdown();
+
+        let mut contents = String::new();
+        File::open(&path).unwrap().read_to_string(&mut contents).unwrap();
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+
+        assert!(contents.contains("Written 1"));
+        assert!(!contents.contains("Filtered"));
+    }
+}
@@ -0,0 +1,26 @@
+extern crate jvmti;
+
+#[cfg(test)]
+mod tests {
+
+    use jvmti::emulator::JVMEmulator;
+    use jvmti::error::NativeError;
+    use jvmti::monitor::RawMonitor;
+
+    #[test]
+    fn raw_monitors_guard_their_data() {
+        let monitor = RawMonitor::new(Box::new(JVMEmulator::new()), "test-monitor", vec![1, 2]).ok().unwrap();
+
+        monitor.lock().ok().unwrap().push(3);
+
+        assert_eq!(vec![1, 2, 3], *monitor.lock().ok().unwrap());
+    }
+
+    #[test]
+    fn raw_monitors_report_creation_errors() {
+        match RawMonitor::new(Box::new(JVMEmulator::new()), "", 0) {
+            Err(NativeError::IllegalArgument) => (),
+            _ => assert!(false, "an unnamed monitor shouldn't be created")
+        }
+    }
+}
@@ -0,0 +1,42 @@
+extern crate jvmti;
+
+#[cfg(test)]
+mod tests {
+
+    use jvmti::mutf8::{decode, encode};
+
+    #[test]
+    fn ascii_strings_are_encoded_as_is() {
+        assert_eq!(b"java/lang/Object".to_vec(), encode("java/lang/Object"));
+        assert_eq!(Some("java/lang/Object".to_string()), decode(b"java/lang/Object"));
+    }
+
+    #[test]
+    fn nul_characters_are_encoded_in_two_bytes() {
+        assert_eq!(vec![ 0x61, 0xC0, 0x80, 0x62 ], encode("a\u{0}b"));
+        assert_eq!(Some("a\u{0}b".to_string()), decode(&[ 0x61, 0xC0, 0x80, 0x62 ]));
+    }
+
+    #[test]
+    fn supplementary_characters_are_encoded_as_surrogate_pairs() {
+        let encoded = vec![ 0xED, 0xA0, 0xBD, 0xED, 0xB8, 0x80 ];
+
+        assert_eq!(encoded, encode("\u{1F600}"));
+        assert_eq!(Some("\u{1F600}".to_string()), decode(&encoded));
+    }
+
+    #[test]
+    fn strings_survive_a_roundtrip() {
+        let value = "Gr\u{fc}\u{df}e \u{4e16}\u{754c} \u{1F600}\u{0}";
+
+        assert_eq!(Some(value.to_string()), decode(&encode(value)));
+    }
+
+    #[test]
+    fn invalid_sequences_are_rejected() {
+        assert_eq!(None, decode(&[ 0x61, 0x00 ]));
+        assert_eq!(None, decode(&[ 0xF0, 0x9F, 0x98, 0x80 ]));
+        assert_eq!(None, decode(&[ 0xC3 ]));
+        assert_eq!(None, decode(&[ 0xED, 0xA0, 0xBD ]));
+    }
+}
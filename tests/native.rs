@@ -0,0 +1,103 @@
+extern crate jvmti;
+
+#[cfg(test)]
+mod tests {
+
+    use jvmti::native::jvmti_native::*;
+    use std::mem::{size_of, zeroed};
+
+    macro_rules! offset_of {
+        ($ty:ty, $field:ident) => {
+            unsafe {
+                let instance: $ty = zeroed();
+                (&instance.$field as *const _ as usize) - (&instance as *const $ty as usize)
+            }
+        }
+    }
+
+    #[test]
+    fn primitive_types_match_jni_md() {
+        assert_eq!(4, size_of::<jint>());
+        assert_eq!(8, size_of::<jlong>());
+        assert_eq!(1, size_of::<jbyte>());
+        assert_eq!(1, size_of::<jboolean>());
+        assert_eq!(2, size_of::<jchar>());
+        assert_eq!(2, size_of::<jshort>());
+        assert_eq!(4, size_of::<jfloat>());
+        assert_eq!(8, size_of::<jdouble>());
+        assert_eq!(8, size_of::<jvalue>());
+        assert!((-1 as jbyte) < 0);
+    }
+
+    #[test]
+    fn jvmti_structures_match_the_native_layout() {
+        assert_eq!(32, size_of::<jvmtiThreadInfo>());
+        assert_eq!(16, size_of::<jvmtiMonitorStackDepthInfo>());
+        assert_eq!(24, size_of::<jvmtiThreadGroupInfo>());
+        assert_eq!(16, size_of::<jvmtiFrameInfo>());
+        assert_eq!(32, size_of::<jvmtiStackInfo>());
+        assert_eq!(48, size_of::<jvmtiHeapReferenceInfoStackLocal>());
+        assert_eq!(32, size_of::<jvmtiHeapReferenceInfoJniLocal>());
+        assert_eq!(64, size_of::<jvmtiHeapReferenceInfo>());
+        assert_eq!(128, size_of::<jvmtiHeapCallbacks>());
+        assert_eq!(24, size_of::<jvmtiClassDefinition>());
+        assert_eq!(40, size_of::<jvmtiMonitorUsage>());
+        assert_eq!(16, size_of::<jvmtiLineNumberEntry>());
+        assert_eq!(48, size_of::<jvmtiLocalVariableEntry>());
+        assert_eq!(24, size_of::<jvmtiParamInfo>());
+        assert_eq!(56, size_of::<jvmtiExtensionFunctionInfo>());
+        assert_eq!(40, size_of::<jvmtiExtensionEventInfo>());
+        assert_eq!(16, size_of::<jvmtiAddrLocationMap>());
+        assert_eq!(16, size_of::<jvmtiCapabilities>());
+    }
+
+    #[test]
+    fn jvmti_structure_fields_are_placed_at_native_offsets() {
+        assert_eq!(8, offset_of!(jvmtiThreadInfo, priority));
+        assert_eq!(16, offset_of!(jvmtiThreadInfo, thread_group));
+        assert_eq!(8, offset_of!(jvmtiStackInfo, state));
+        assert_eq!(16, offset_of!(jvmtiStackInfo, frame_buffer));
+        assert_eq!(24, offset_of!(jvmtiStackInfo, frame_count));
+        assert_eq!(32, size_of::<jvmtiTimerInfo>());
+        assert_eq!(8, offset_of!(jvmtiTimerInfo, may_skip_forward));
+        assert_eq!(9, offset_of!(jvmtiTimerInfo, may_skip_backward));
+        assert_eq!(12, offset_of!(jvmtiTimerInfo, kind));
+        assert_eq!(16, offset_of!(jvmtiTimerInfo, reserved1));
+        assert_eq!(32, offset_of!(jvmtiHeapReferenceInfoStackLocal, location));
+    }
+
+    #[test]
+    fn event_callback_table_includes_java_11_events() {
+        assert_eq!(37 * 8, size_of::<jvmtiEventCallbacks>());
+        assert_eq!(272, offset_of!(jvmtiEventCallbacks, VMObjectAlloc));
+        assert_eq!(288, offset_of!(jvmtiEventCallbacks, SampledObjectAlloc));
+        assert_eq!(288 / 8, (JVMTI_EVENT_SAMPLED_OBJECT_ALLOC - JVMTI_MIN_EVENT_TYPE_VAL) as usize);
+    }
+
+    #[test]
+    fn function_tables_match_the_native_layout() {
+        assert_eq!(156 * 8, size_of::<jvmtiInterface_1>());
+        assert_eq!(8 * 2, offset_of!(jvmtiInterface_1, GetAllModules));
+        assert_eq!(8 * 39, offset_of!(jvmtiInterface_1, GetNamedModule));
+        assert_eq!(8 * 99, offset_of!(jvmtiInterface_1, GetAllStackTraces));
+        assert_eq!(8 * 98, offset_of!(jvmtiInterface_1, IsModifiableModule));
+        assert_eq!(8 * 155, offset_of!(jvmtiInterface_1, SetHeapSamplingInterval));
+
+        assert_eq!(8 * 8, size_of::<JNIInvokeInterface>());
+        assert_eq!(8 * 234, size_of::<JNINativeInterface>());
+        assert_eq!(8 * 233, offset_of!(JNINativeInterface, GetModule));
+    }
+
+    #[test]
+    #[cfg(target_arch = "x86_64")]
+    fn va_list_is_passed_as_a_pointer_on_x86_64() {
+        assert_eq!(24, size_of::<__va_list_tag>());
+        assert_eq!(8, size_of::<va_list>());
+    }
+
+    #[test]
+    #[cfg(target_arch = "aarch64")]
+    fn va_list_is_passed_by_value_on_aarch64() {
+        assert_eq!(32, size_of::<va_list>());
+    }
+}
@@ -3,6 +3,7 @@ extern crate jvmti;
 #[cfg(test)]
 mod tests {
 
+    use jvmti::logging::Level;
     use jvmti::options::Options;
 
     #[test]
@@ -34,4 +35,14 @@ mod tests {
         assert_eq!(true, opts.custom_args.contains_key("setting2"));
         assert_eq!(true, opts.custom_args.contains_key("setting3"));
     }
+
+    #[test]
+    fn log_settings_are_parsed() {
+        let opts = Options::parse("log=/var/log/agent.log,loglevel=debug,loglevel.event_handler=trace,loglevel.sampler=loud".to_string());
+
+        assert_eq!(Some("/var/log/agent.log".to_string()), opts.log_output);
+        assert_eq!(Some(Level::Debug), opts.log_level);
+        assert_eq!(vec![("event_handler".to_string(), Level::Trace)], opts.log_modules);
+        assert!(opts.custom_args.is_empty());
+    }
 }
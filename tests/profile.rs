@@ -0,0 +1,632 @@
+extern crate jvmti;
+
+#[cfg(test)]
+mod tests {
+
+    use jvmti::class::{ClassId, ClassSignature, JavaType};
+    use jvmti::method::{MethodId, MethodSignature};
+    use jvmti::profile::alloc::AllocationProfile;
+    use jvmti::profile::calltree::CallTreeProfile;
+    use jvmti::profile::exceptions::ExceptionProfile;
+    use jvmti::profile::flamegraph::{render_svg, FoldedStacks, ToFolded};
+    use jvmti::profile::gc::{GcPause, GcPauses};
+    use jvmti::profile::histogram::Histogram;
+    use jvmti::profile::locks::LockProfile;
+    use jvmti::profile::sampling::SampleProfile;
+    use jvmti::profile::slow::{SlowInvocation, SlowInvocationDetector};
+    use jvmti::profile::threads::ThreadTable;
+    use jvmti::profile::timeline::{Timeline, GC_TRACK};
+    use jvmti::profile::timing::MethodTimings;
+    use jvmti::profile::trace::TraceRecorder;
+    use jvmti::runtime::{ExceptionCatchEvent, ExceptionEvent, MethodInvocationEvent, MonitorEvent, MonitorWaitEvent, MonitorWaitedEvent, ObjectAllocationEvent};
+    use jvmti::stack::StackTraceElement;
+    use jvmti::thread::{Thread, ThreadId};
+    use std::ptr;
+
+    fn frame(class: &str, method: &str, location: i64) -> StackTraceElement {
+        StackTraceElement::new(ClassSignature::new(&JavaType::parse(class).unwrap()), MethodSignature::new(method.to_string()), location)
+    }
+
+    fn allocation(class: &str, size: i64, stack_trace: Vec<StackTraceElement>) -> ObjectAllocationEvent {
+        ObjectAllocationEvent {
+            class_id: ClassId { native_id: ptr::null_mut() },
+            class_sig: ClassSignature::new(&JavaType::parse(class).unwrap()),
+            thread: Thread { id: ThreadId { native_id: ptr::null_mut() }, java_id: 1, name: "main".to_string(), priority: 5, is_daemon: false },
+            size: size,
+            stack_trace: stack_trace
+        }
+    }
+
+    #[test]
+    fn stack_trace_elements_are_rendered_with_their_location() {
+        let element = frame("Lcom/example/Service;", "handle", 12);
+
+        assert_eq!("com.example.Service.handle", element.method_name());
+        assert_eq!("com.example.Service.handle@12", element.to_string());
+    }
+
+    #[test]
+    fn allocations_are_aggregated_by_site() {
+        let mut profile = AllocationProfile::new(2);
+
+        profile.record(&allocation("Ljava/lang/String;", 24, vec![ frame("Lcom/example/A;", "a", 1), frame("Lcom/example/B;", "b", 2) ]));
+        profile.record(&allocation("Ljava/lang/String;", 40, vec![ frame("Lcom/example/A;", "a", 1), frame("Lcom/example/B;", "b", 2) ]));
+        profile.record(&allocation("Ljava/lang/String;", 16, vec![ frame("Lcom/example/A;", "a", 5), frame("Lcom/example/B;", "b", 2) ]));
+
+        assert_eq!(2, profile.len());
+
+        let sites = profile.top_sites(10);
+        assert_eq!(vec![ "com.example.A.a@1".to_string(), "com.example.B.b@2".to_string() ], sites[0].0.frames);
+        assert_eq!("java.lang.String", sites[0].0.class_name);
+        assert_eq!(2, sites[0].1.count);
+        assert_eq!(64, sites[0].1.total_bytes);
+        assert_eq!(40, sites[0].1.max_bytes);
+        assert_eq!(16, sites[1].1.total_bytes);
+    }
+
+    #[test]
+    fn allocation_sites_only_consider_the_topmost_frames() {
+        let mut profile = AllocationProfile::new(1);
+
+        profile.record(&allocation("[B", 100, vec![ frame("Lcom/example/A;", "a", 1), frame("Lcom/example/B;", "b", 2) ]));
+        profile.record(&allocation("[B", 100, vec![ frame("Lcom/example/A;", "a", 1), frame("Lcom/example/C;", "c", 3) ]));
+        profile.record(&allocation("[I", 100, vec![ frame("Lcom/example/A;", "a", 1) ]));
+
+        assert_eq!(2, profile.len());
+        assert_eq!("byte[] at com.example.A.a@1", profile.top_sites(1)[0].0.to_string());
+    }
+
+    #[test]
+    fn top_sites_are_limited_and_reset_clears_the_profile() {
+        let mut profile = AllocationProfile::new(1);
+
+        for i in 0..5 {
+            profile.record(&allocation("Ljava/lang/Object;", 16 * (i + 1), vec![ frame("Lcom/example/A;", "a", i) ]));
+        }
+
+        let sites = profile.top_sites(3);
+        assert_eq!(3, sites.len());
+        assert_eq!(80, sites[0].1.total_bytes);
+
+        profile.reset();
+        assert!(profile.is_empty());
+    }
+
+    #[test]
+    fn histogram_percentiles_are_within_bucket_precision() {
+        let mut histogram = Histogram::new();
+
+        for value in 1..1001 {
+            histogram.record(value * 1000);
+        }
+
+        let p50 = histogram.percentile(0.5);
+        let p99 = histogram.percentile(0.99);
+
+        assert_eq!(1000, histogram.count());
+        assert!(p50 >= 500_000 && p50 <= 500_000 * 9 / 8, "p50 was {}", p50);
+        assert!(p99 >= 990_000 && p99 <= 1_000_000, "p99 was {}", p99);
+        assert_eq!(1_000_000, histogram.percentile(1.0));
+    }
+
+    #[test]
+    fn method_timings_subtract_child_time() {
+        let thread_id = 1;
+        let mut timings = MethodTimings::new();
+
+        timings.enter(thread_id, "A.outer", 0);
+        timings.enter(thread_id, "B.inner", 10);
+        assert_eq!(Some(30), timings.exit(thread_id, "B.inner", 40, false));
+        timings.enter(thread_id, "B.inner", 50);
+        assert_eq!(Some(10), timings.exit(thread_id, "B.inner", 60, false));
+        assert_eq!(Some(100), timings.exit(thread_id, "A.outer", 100, false));
+
+        let outer = timings.get("A.outer").unwrap();
+        let inner = timings.get("B.inner").unwrap();
+
+        assert_eq!((1, 100, 60), (outer.count, outer.total_time, outer.self_time));
+        assert_eq!((2, 40, 40), (inner.count, inner.total_time, inner.self_time));
+        assert_eq!((10, 30), (inner.min_time, inner.max_time));
+    }
+
+    #[test]
+    fn method_timings_close_frames_popped_by_exceptions() {
+        let thread_id = 1;
+        let mut timings = MethodTimings::new();
+
+        assert_eq!(None, timings.exit(thread_id, "A.never_entered", 5, false));
+
+        timings.enter(thread_id, "A.outer", 0);
+        timings.enter(thread_id, "B.inner", 10);
+        assert_eq!(Some(50), timings.exit(thread_id, "A.outer", 50, true));
+
+        assert_eq!(1, timings.get("A.outer").unwrap().exceptions);
+        assert_eq!(40, timings.get("B.inner").unwrap().total_time);
+        assert_eq!(10, timings.get("A.outer").unwrap().self_time);
+        assert_eq!("A.outer", timings.top_methods(1)[0].0);
+
+        timings.reset();
+        assert!(timings.is_empty());
+    }
+
+    #[test]
+    fn overloaded_methods_are_timed_separately() {
+        let invocation = |descriptor: &str| MethodInvocationEvent {
+            method_id: MethodId { native_id: ptr::null_mut() },
+            method_sig: MethodSignature::with_descriptor("valueOf".to_string(), descriptor.to_string()),
+            class_sig: ClassSignature::new(&JavaType::parse("Ljava/lang/String;").unwrap()),
+            thread: Thread { id: ThreadId { native_id: ptr::null_mut() }, java_id: 1, name: "main".to_string(), priority: 5, is_daemon: false },
+            popped_by_exception: false
+        };
+        let (int_value, char_value) = (invocation("(I)Ljava/lang/String;"), invocation("(C)Ljava/lang/String;"));
+        let thread_id = 1;
+        let mut timings = MethodTimings::new();
+
+        assert_eq!("java.lang.String.valueOf(I)Ljava/lang/String;", int_value.method_key());
+
+        timings.enter(thread_id, &int_value.method_key(), 0);
+        timings.enter(thread_id, &char_value.method_key(), 10);
+        timings.exit(thread_id, &char_value.method_key(), 15, false);
+        timings.exit(thread_id, &int_value.method_key(), 20, false);
+
+        assert_eq!(2, timings.len());
+        assert_eq!(15, timings.get("java.lang.String.valueOf(I)Ljava/lang/String;").unwrap().self_time);
+        assert_eq!(5, timings.get("java.lang.String.valueOf(C)Ljava/lang/String;").unwrap().total_time);
+    }
+
+    #[test]
+    fn call_trees_distinguish_calling_contexts() {
+        let thread_id = 1;
+        let mut profile = CallTreeProfile::new();
+
+        profile.enter(thread_id, "A.main", 0);
+        profile.enter(thread_id, "B.work", 10);
+        profile.enter(thread_id, "C.log", 20);
+        profile.exit(thread_id, "C.log", 30);
+        profile.exit(thread_id, "B.work", 50);
+        profile.enter(thread_id, "C.log", 60);
+        profile.exit(thread_id, "C.log", 70);
+        profile.exit(thread_id, "A.main", 100);
+
+        let root = profile.thread_tree(thread_id).unwrap();
+        let main = root.child("A.main").unwrap();
+
+        assert_eq!(100, root.total_time);
+        assert_eq!((1, 100, 50), (main.count, main.total_time, main.self_time));
+        assert_eq!((40, 30), (main.child("B.work").unwrap().total_time, main.child("B.work").unwrap().self_time));
+        assert_eq!(10, main.child("B.work").unwrap().child("C.log").unwrap().total_time);
+        assert_eq!(10, main.child("C.log").unwrap().total_time);
+        assert_eq!(5, root.render_lines().len());
+    }
+
+    #[test]
+    fn call_trees_are_merged_across_threads() {
+        let (first, second) = (1, 2);
+        let mut profile = CallTreeProfile::new();
+
+        profile.enter(first, "A.run", 0);
+        profile.enter(second, "A.run", 5);
+        profile.exit(first, "A.run", 10);
+        profile.exit(second, "A.run", 25);
+
+        let merged = profile.merged();
+
+        assert_eq!((2, 30), (merged.child("A.run").unwrap().count, merged.child("A.run").unwrap().total_time));
+        assert_eq!(30, merged.total_time);
+
+        // The trees of ended threads are dropped, but still part of the merged tree
+        profile.thread_end(first);
+
+        assert_eq!(None, profile.thread_tree(first));
+        assert_eq!(merged, profile.merged());
+    }
+
+    #[test]
+    fn samples_are_folded_from_the_outermost_frame() {
+        let mut profile = SampleProfile::new();
+
+        profile.record(&[ "B.work".to_string(), "A.main".to_string() ]);
+        profile.record(&[ "B.work".to_string(), "A.main".to_string() ]);
+        profile.record(&[ "C.idle".to_string(), "A.main".to_string() ]);
+        profile.record(&[]);
+
+        assert_eq!(3, profile.samples());
+        assert_eq!(2, profile.get("A.main;B.work"));
+        assert_eq!(vec![ "A.main;B.work 2".to_string(), "A.main;C.idle 1".to_string() ], profile.folded_lines());
+
+        profile.reset();
+        assert!(profile.is_empty());
+    }
+
+    #[test]
+    fn folded_stacks_survive_a_roundtrip() {
+        let folded = FoldedStacks::parse("A.main;B.work 3\nA.main;C.idle 1\nA.main;B.work 2\nnot a stack\n");
+
+        assert_eq!(5, folded.get("A.main;B.work"));
+        assert_eq!(6, folded.total());
+        assert_eq!(folded, FoldedStacks::parse(&folded.to_string()));
+    }
+
+    #[test]
+    fn call_trees_are_folded_by_self_time() {
+        let thread_id = 1;
+        let mut profile = CallTreeProfile::new();
+
+        profile.enter(thread_id, "A.main", 0);
+        profile.enter(thread_id, "B.work", 10);
+        profile.exit(thread_id, "B.work", 40);
+        profile.exit(thread_id, "A.main", 50);
+
+        let folded = profile.merged().to_folded();
+
+        assert_eq!(vec![ "A.main 20".to_string(), "A.main;B.work 30".to_string() ], folded.lines());
+    }
+
+    #[test]
+    fn flame_graphs_are_rendered_as_standalone_svg() {
+        let svg = render_svg(&FoldedStacks::parse("A.main;B.<init> 3\nA.main;C.idle 1\n"), "Test & profile", "samples");
+
+        assert!(svg.starts_with("<?xml"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert!(svg.contains("<title>all (4 samples, 100.00%)</title>"));
+        assert!(svg.contains("<title>B.&lt;init&gt; (3 samples, 75.00%)</title>"));
+        assert!(svg.contains("Test &amp; profile"));
+        assert_eq!(4, svg.matches("<g class=\"frame\">").count());
+        // The script is kept in a single CDATA section
+        assert_eq!(1, svg.matches("]]>").count());
+    }
+
+    fn thread(name: &str) -> Thread {
+        Thread { id: ThreadId { native_id: ptr::null_mut() }, java_id: 1, name: name.to_string(), priority: 5, is_daemon: false }
+    }
+
+    #[test]
+    fn monitor_contention_is_aggregated_by_site() {
+        let mut profile = LockProfile::new(1);
+        let contended = || MonitorEvent { thread: thread("worker"), class_sig: ClassSignature::new(&JavaType::parse("Lcom/example/Cache;").unwrap()), stack_trace: vec![ frame("Lcom/example/Cache;", "get", 3), frame("Lcom/example/A;", "a", 1) ] };
+
+        profile.contended_enter(&contended(), 100);
+        assert_eq!(Some(50), profile.contended_entered(thread("worker").java_id, 150));
+        profile.contended_enter(&contended(), 200);
+        assert_eq!(Some(20), profile.contended_entered(thread("worker").java_id, 220));
+        assert_eq!(None, profile.contended_entered(thread("worker").java_id, 300));
+
+        let sites = profile.top_contended(10);
+        assert_eq!(1, sites.len());
+        assert_eq!("com.example.Cache at com.example.Cache.get@3", sites[0].0.to_string());
+        assert_eq!(2, sites[0].1.count);
+        assert_eq!(70, sites[0].1.total_time);
+        assert_eq!(50, sites[0].1.max_time);
+        assert_eq!(vec![ "com.example.Cache.get@3;com.example.Cache 70".to_string() ], profile.to_folded().lines());
+    }
+
+    #[test]
+    fn object_waits_are_tracked_separately() {
+        let mut profile = LockProfile::new(4);
+        let wait = |timeout| MonitorWaitEvent { thread: thread("worker"), class_sig: ClassSignature::new(&JavaType::parse("Ljava/lang/Object;").unwrap()), stack_trace: vec![ frame("Lcom/example/Queue;", "take", 7) ], timeout: timeout };
+        let waited = |timed_out| MonitorWaitedEvent { thread: thread("worker"), class_sig: ClassSignature::new(&JavaType::parse("Ljava/lang/Object;").unwrap()), timed_out: timed_out };
+
+        profile.wait_start(&wait(0), 0);
+        assert_eq!(Some(500), profile.wait_end(&waited(false), 500));
+        profile.wait_start(&wait(10), 1000);
+        assert_eq!(Some(10), profile.wait_end(&waited(true), 1010));
+
+        assert!(profile.top_contended(10).is_empty());
+        assert!(profile.to_folded().is_empty());
+
+        let waits = profile.top_waits(10);
+        assert_eq!(1, waits.len());
+        assert_eq!(2, waits[0].1.count);
+        assert_eq!(510, waits[0].1.total_time);
+        assert_eq!(1, waits[0].1.timed_waits);
+        assert_eq!(1, waits[0].1.timed_out);
+
+        profile.reset();
+        assert!(profile.is_empty());
+    }
+
+    #[test]
+    fn gc_pauses_are_checked_against_threshold_and_budget() {
+        let mut pauses = GcPauses::new(50, 100, 1000, 2);
+
+        assert_eq!(None, pauses.finish(10));
+
+        pauses.start(0);
+        let first = pauses.finish(40).unwrap();
+        assert_eq!(40, first.duration());
+        assert!(!first.over_threshold && !first.over_budget);
+
+        pauses.start(100);
+        let second = pauses.finish(170).unwrap();
+        assert!(second.over_threshold && second.over_budget);
+
+        // The first two pauses have left the budget window by now
+        pauses.start(2000);
+        let third = pauses.finish(2030).unwrap();
+        assert!(!third.over_threshold && !third.over_budget);
+
+        assert_eq!(3, pauses.count());
+        assert_eq!(140, pauses.total_time());
+        assert_eq!(70, pauses.max_time());
+        assert_eq!(1, pauses.over_threshold());
+        assert_eq!(1, pauses.over_budget());
+        assert_eq!(vec![ 70, 30 ], pauses.timeline().iter().map(|pause| pause.duration()).collect::<Vec<u64>>());
+    }
+
+    #[test]
+    fn freed_objects_are_attributed_to_the_last_pause() {
+        let mut pauses = GcPauses::new(0, 0, 0, 10);
+
+        pauses.object_freed();
+        pauses.start(0);
+        pauses.object_freed();
+        pauses.object_freed();
+        assert_eq!(2, pauses.finish(10).unwrap().freed);
+
+        // Reported after the collection has finished
+        pauses.object_freed();
+
+        assert_eq!(4, pauses.freed());
+        assert_eq!(3, pauses.timeline()[0].freed);
+        assert!(!pauses.timeline()[0].over_threshold && !pauses.timeline()[0].over_budget);
+
+        pauses.reset();
+        assert!(pauses.is_empty());
+        assert!(pauses.timeline().is_empty());
+
+        // Finished pauses are queued until they're taken, regardless of resets
+        assert_eq!(vec![ 3 ], pauses.take_unreported().iter().map(|pause| pause.freed).collect::<Vec<u64>>());
+        assert!(pauses.take_unreported().is_empty());
+    }
+
+    #[test]
+    fn thread_records_track_lifetime_and_cpu_time() {
+        let mut threads = ThreadTable::new(1);
+
+        threads.start(1, &thread("pool-1-thread-1"), 1000);
+        threads.cpu_sample(1, &thread("pool-1-thread-1"), 2000, 300);
+        threads.cpu_sample(1, &thread("pool-1-thread-1"), 3000, 500);
+        threads.cpu_sample(2, &thread("main"), 3000, 100);
+
+        assert_eq!(200, threads.get(1).unwrap().recent_cpu_time);
+        assert_eq!(3000, threads.get(2).unwrap().start_time);
+        assert_eq!(vec![ 1, 2 ], threads.top_by_recent_cpu(10).iter().map(|record| record.java_id).collect::<Vec<i64>>());
+
+        let record = threads.end(1, &thread("pool-1-thread-1"), 5000, Some(800));
+        assert!(!record.is_alive());
+        assert_eq!(4000, record.lifetime(9000));
+        assert_eq!(800, record.cpu_time);
+
+        assert_eq!(vec![ "pool-1-thread-1".to_string(), "main".to_string() ], threads.top_by_cpu(10).iter().map(|record| record.name.clone()).collect::<Vec<String>>());
+        assert_eq!(1, threads.top_by_recent_cpu(10).len());
+    }
+
+    #[test]
+    fn ended_threads_are_counted_for_churn() {
+        let mut threads = ThreadTable::new(2);
+
+        for java_id in 0..5 {
+            threads.start(java_id, &thread("worker"), 0);
+            threads.end(java_id, &thread("worker"), 10, None);
+        }
+
+        // Threads ending without having been seen are still recorded
+        threads.end(9, &thread("unknown"), 20, None);
+
+        assert_eq!(5, threads.started());
+        assert_eq!(6, threads.ended());
+        assert_eq!(vec![ 4, 9 ], threads.finished().iter().map(|record| record.java_id).collect::<Vec<i64>>());
+        assert!(threads.live().is_empty());
+
+        threads.reset();
+        assert_eq!(0, threads.started());
+        assert!(threads.finished().is_empty());
+    }
+
+    fn exception(class: &str, throw_site: StackTraceElement, catch_site: Option<StackTraceElement>, stack_depth: usize) -> ExceptionEvent {
+        ExceptionEvent { thread: thread("main"), exception_class: ClassSignature::new(&JavaType::parse(class).unwrap()), throw_site: throw_site, catch_site: catch_site, stack_depth: stack_depth }
+    }
+
+    fn exception_catch(class: &str, catch_site: StackTraceElement, stack_depth: usize) -> ExceptionCatchEvent {
+        ExceptionCatchEvent { thread: thread("main"), exception_class: ClassSignature::new(&JavaType::parse(class).unwrap()), catch_site: catch_site, stack_depth: stack_depth }
+    }
+
+    #[test]
+    fn exceptions_are_counted_by_type_and_site() {
+        let mut profile = ExceptionProfile::new();
+
+        profile.thrown(&exception("Ljava/io/IOException;", frame("Lcom/example/Io;", "read", 4), Some(frame("Lcom/example/App;", "run", 9)), 12), 0);
+        assert_eq!(Some(3), profile.caught(&exception_catch("Ljava/io/IOException;", frame("Lcom/example/App;", "run", 9), 9)));
+
+        profile.thrown(&exception("Ljava/io/IOException;", frame("Lcom/example/Io;", "read", 4), None, 12), 10);
+        profile.thrown(&exception("Ljava/lang/IllegalStateException;", frame("Lcom/example/App;", "run", 2), None, 9), 20);
+
+        // Thrown by native code, so the throw hasn't been reported
+        assert_eq!(None, profile.caught(&exception_catch("Ljava/lang/IllegalStateException;", frame("Lcom/example/App;", "main", 1), 1)));
+
+        let io = profile.get_type("java.io.IOException").unwrap();
+        assert_eq!((2, 1, 1), (io.thrown, io.caught, io.uncaught));
+        assert_eq!(3, io.max_distance);
+        assert_eq!(3.0, io.mean_distance());
+
+        let sites = profile.top_sites(10);
+        assert_eq!(2, sites.len());
+        assert_eq!("java.io.IOException at com.example.Io.read@4", sites[0].0.to_string());
+        assert_eq!(Some(&1), sites[0].1.catch_methods.get("com.example.App.run"));
+        assert_eq!("java.io.IOException", profile.top_types(1)[0].0);
+    }
+
+    #[test]
+    fn exceptions_used_as_control_flow_are_detected() {
+        let mut profile = ExceptionProfile::new();
+
+        for idx in 0..5 {
+            profile.thrown(&exception("Ljava/lang/NumberFormatException;", frame("Lcom/example/Parser;", "parse", 3), Some(frame("Lcom/example/Parser;", "tryParse", 7)), 5), idx * 100_000_000);
+            profile.caught(&exception_catch("Ljava/lang/NumberFormatException;", frame("Lcom/example/Parser;", "tryParse", 7), 4));
+        }
+
+        // A second later the rate starts over
+        profile.thrown(&exception("Ljava/lang/NumberFormatException;", frame("Lcom/example/Parser;", "parse", 3), Some(frame("Lcom/example/Parser;", "tryParse", 7)), 5), 2_000_000_000);
+
+        let hot_spots = profile.hot_spots(5);
+        assert_eq!(1, hot_spots.len());
+        assert_eq!(5, hot_spots[0].1.peak_rate);
+        assert!(profile.hot_spots(6).is_empty());
+
+        let report = profile.report_lines(10, 5);
+        assert_eq!("Exceptions used as control flow:", report[4]);
+        assert!(report[5].ends_with("caught by com.example.Parser.tryParse (peak rate: 5/s)"));
+
+        profile.reset();
+        assert!(profile.is_empty());
+    }
+
+    #[test]
+    fn traces_record_nested_spans_within_budget() {
+        let thread_id = 1;
+        let mut traces = TraceRecorder::new(2, 1_000);
+
+        // Calls outside of an entry point aren't traced
+        traces.enter(thread_id, "main", "A.before", 0, false);
+        assert!(!traces.is_tracing(thread_id));
+        assert_eq!(None, traces.exit(thread_id, "A.before", 5, false));
+
+        traces.enter(thread_id, "main", "A.main", 10, true);
+        traces.enter(thread_id, "main", "B.first", 20, false);
+        // Too deep
+        traces.enter(thread_id, "main", "C.nested", 30, false);
+        assert_eq!(None, traces.exit(thread_id, "C.nested", 40, false));
+        assert_eq!(None, traces.exit(thread_id, "B.first", 50, false));
+        // Too late
+        traces.enter(thread_id, "main", "B.second", 2_000, false);
+        assert_eq!(None, traces.exit(thread_id, "B.second", 2_100, false));
+
+        let trace = traces.exit(thread_id, "A.main", 3_000, false).unwrap();
+        assert!(!traces.is_tracing(thread_id));
+        assert_eq!(2, trace.dropped);
+        assert_eq!(2, trace.root.span_count());
+        assert_eq!(2_990, trace.root.duration());
+        assert_eq!("B.first", trace.root.children[0].method);
+        assert_eq!(30, trace.root.children[0].duration());
+
+        let lines = trace.render_lines();
+        assert_eq!("Trace of A.main on main (2 spans, 2 dropped)", lines[0]);
+        assert_eq!("    B.first (0.000 ms)", lines[2]);
+    }
+
+    #[test]
+    fn traces_close_spans_popped_by_exceptions() {
+        let thread_id = 1;
+        let mut traces = TraceRecorder::new(10, 1_000_000);
+
+        traces.enter(thread_id, "worker", "A.main", 0, true);
+        traces.enter(thread_id, "worker", "B.call", 10, false);
+        traces.enter(thread_id, "worker", "C.fail", 20, false);
+        // The exit of C.fail hasn't been reported
+        assert_eq!(None, traces.exit(thread_id, "B.call", 30, true));
+
+        let trace = traces.exit(thread_id, "A.main", 40, false).unwrap();
+        let call = &trace.root.children[0];
+        assert!(call.exception);
+        assert!(call.children[0].exception);
+        assert_eq!(30, call.children[0].end);
+        assert!(!trace.root.exception);
+
+        traces.enter(thread_id, "worker", "A.main", 50, true);
+        traces.thread_end(thread_id);
+        assert!(!traces.is_tracing(thread_id));
+    }
+
+    #[test]
+    fn slow_invocations_are_reported_with_their_arguments() {
+        let thread_id = 1;
+        let mut detector = SlowInvocationDetector::new(100, 2);
+
+        assert!(detector.enter(thread_id, "com.example.Dao.query", vec!["42".to_string(), "SELECT 1".to_string()], 0));
+        // Recursive invocations are timed separately
+        assert!(!detector.enter(thread_id, "com.example.Dao.query", vec!["1".to_string()], 10));
+        // Exits of methods that aren't watched are ignored
+        assert_eq!(None, detector.exit(thread_id, "main", "com.example.Dao.helper", 20, false));
+        assert_eq!(None, detector.exit(thread_id, "main", "com.example.Dao.query", 50, false));
+        assert!(detector.is_watching(thread_id));
+
+        let mut invocation = detector.exit(thread_id, "main", "com.example.Dao.query", 250, true).unwrap();
+        assert!(!detector.is_watching(thread_id));
+        assert_eq!(250, invocation.duration);
+        assert_eq!(vec!["42".to_string(), "SELECT 1".to_string()], invocation.arguments);
+
+        invocation.stack_trace = vec!["com.example.Dao.query@3".to_string()];
+        let lines = invocation.render_lines();
+        assert_eq!("Slow invocation of com.example.Dao.query(42, SELECT 1) on main took 0.000 ms, exception thrown", lines[0]);
+        assert_eq!("  at com.example.Dao.query@3", lines[1]);
+
+        detector.record(invocation.clone());
+        detector.record(SlowInvocation { duration: 500, ..invocation.clone() });
+        detector.record(SlowInvocation { duration: 150, ..invocation });
+        assert_eq!(3, detector.count());
+        assert_eq!(vec![500, 250], detector.slowest(10).iter().map(|invocation| invocation.duration).collect::<Vec<u64>>());
+
+        detector.enter(thread_id, "com.example.Dao.query", vec![], 300);
+        detector.thread_end(thread_id);
+        assert!(!detector.is_watching(thread_id));
+
+        detector.reset();
+        assert_eq!(0, detector.count());
+    }
+
+    #[test]
+    fn timeline_puts_each_thread_on_its_own_track() {
+        let (main_id, thread_id) = (1, 2);
+        let mut traces = TraceRecorder::new(10, 1_000_000);
+        let mut timeline = Timeline::new(1_000, 100, 42);
+
+        timeline.thread_start(main_id, "main", 1_000);
+        timeline.thread_start(thread_id, "worker", 2_000);
+        traces.enter(thread_id, "worker", "A.run", 3_000, true);
+        traces.enter(thread_id, "worker", "B.call", 4_000, false);
+        traces.exit(thread_id, "B.call", 5_000, true);
+        timeline.trace(&traces.exit(thread_id, "A.run", 6_000, false).unwrap());
+        timeline.wait_start(thread_id, "java.lang.Object", 7_000);
+        timeline.wait_end(thread_id, "worker", true, 9_500);
+        timeline.gc_pause(&GcPause { start: 8_000, end: 10_000, freed: 3, over_threshold: false, over_budget: false });
+        timeline.thread_end(thread_id, "worker", 11_000);
+
+        let worker = timeline.track(thread_id, "worker");
+        assert_eq!(1, timeline.track(main_id, "main"));
+
+        let phases: Vec<(&str, &str, u64, f64)> = timeline.events().iter().map(|event| (event.ph, event.name.as_str(), event.tid, event.ts)).collect();
+
+        assert_eq!(vec![
+            ("B", "A.run", worker, 2.0),
+            ("B", "B.call", worker, 3.0),
+            ("E", "B.call", worker, 4.0),
+            ("E", "A.run", worker, 5.0),
+            ("X", "Wait on java.lang.Object", worker, 6.0),
+            ("X", "GC pause", GC_TRACK, 7.0),
+            ("X", "Thread worker", worker, 1.0)
+        ], phases);
+        assert_eq!(Some(9.0), timeline.events()[6].dur);
+        assert_eq!(Some(&"true".to_string()), timeline.events()[2].args.get("exception"));
+
+        let json = timeline.to_json(12_000).unwrap();
+        assert!(json.starts_with(r#"{"traceEvents":[{"name":"process_name","ph":"M","ts":0.0,"pid":42,"tid":0,"args":{"name":"JVM"}}"#));
+        assert!(json.contains(r#"{"name":"thread_name","ph":"M","ts":0.0,"pid":42,"tid":2,"args":{"name":"worker"}}"#));
+        // Threads still alive end at the time of the export
+        assert!(json.contains(r#"{"name":"Thread main","cat":"thread","ph":"X","ts":0.0,"dur":11.0,"pid":42,"tid":1,"args":{"alive":"true"}}"#));
+        assert!(json.ends_with(r#"],"displayTimeUnit":"ms","otherData":{"dropped_events":"0"}}"#));
+
+        // Threads sharing a name get tracks of their own, renamed threads keep theirs
+        assert_eq!(3, timeline.track(3, "worker"));
+        assert_eq!(worker, timeline.track(thread_id, "worker-1"));
+        assert!(timeline.to_json(12_000).unwrap().contains(r#"{"name":"thread_name","ph":"M","ts":0.0,"pid":42,"tid":2,"args":{"name":"worker-1"}}"#));
+
+        // Traces that don't fit are dropped as a whole
+        let mut full = Timeline::new(0, 3, 42);
+        traces.enter(thread_id, "worker", "A.run", 0, true);
+        traces.enter(thread_id, "worker", "B.call", 1, false);
+        traces.exit(thread_id, "B.call", 2, false);
+        full.trace(&traces.exit(thread_id, "A.run", 3, false).unwrap());
+        assert!(full.is_empty());
+        assert_eq!(4, full.dropped());
+    }
+}
@@ -0,0 +1,162 @@
+extern crate jvmti;
+
+#[cfg(test)]
+mod tests {
+
+    use jvmti::recording::{self, convert, crc32, read_varint, tag, unzigzag, write_varint, zigzag, Event, MAGIC};
+    use jvmti::recording::reader::RecordingReader;
+    use jvmti::recording::writer::RecordingWriter;
+    use std::env;
+    use std::fs::{self, File};
+    use std::io::ErrorKind;
+    use std::process;
+    use std::thread;
+
+    fn events() -> Vec<Event> {
+        vec![
+            Event::ThreadStart { time: 1_000, thread: "main".to_string() },
+            Event::MethodEntry { time: 1_500, thread: "main".to_string(), class: "Hello".to_string(), method: "main".to_string() },
+            Event::MonitorWait { time: 2_000, thread: "main".to_string(), class: "java.lang.Object".to_string(), timeout: 100 },
+            Event::MonitorWaited { time: 3_000, thread: "main".to_string(), timed_out: true },
+            // Pauses are reported once they're over
+            Event::GcPause { time: 2_500, duration: 1_000, freed: 7 },
+            Event::MonitorContended { time: 3_500, thread: "main".to_string(), class: "Lock".to_string() },
+            Event::MonitorEntered { time: 3_600, thread: "main".to_string() },
+            Event::ExceptionThrown { time: 3_700, thread: "main".to_string(), class: "java.io.IOException".to_string(), caught: true },
+            Event::ExceptionCaught { time: 3_800, thread: "main".to_string(), class: "java.io.IOException".to_string() },
+            Event::MethodExit { time: 4_000, thread: "main".to_string(), class: "Hello".to_string(), method: "main".to_string(), exception: false },
+            Event::ThreadEnd { time: 5_000, thread: "main".to_string(), cpu_time: 2_000 }
+        ]
+    }
+
+    fn record(events: &[Event], chunk_size: usize) -> Vec<u8> {
+        let mut writer = RecordingWriter::new(vec![], chunk_size).unwrap();
+
+        for event in events {
+            writer.write(event).unwrap();
+        }
+
+        assert_eq!(events.len() as u64, writer.events());
+        writer.into_inner().unwrap()
+    }
+
+    #[test]
+    fn varints_and_checksums_are_encoded_as_expected() {
+        let mut buffer = vec![];
+
+        write_varint(&mut buffer, 300);
+        assert_eq!(vec![0xac, 0x02], buffer);
+        assert_eq!(300, read_varint(&buffer, &mut 0).unwrap());
+        assert_eq!(ErrorKind::UnexpectedEof, read_varint(&[0x80], &mut 0).unwrap_err().kind());
+        assert_eq!(3, zigzag(-2));
+        assert_eq!(-2, unzigzag(zigzag(-2)));
+        assert_eq!(::std::i64::MIN, unzigzag(zigzag(::std::i64::MIN)));
+        assert_eq!(0xcbf43926, crc32(b"123456789"));
+    }
+
+    #[test]
+    fn recordings_are_read_back_across_chunks() {
+        let events = events();
+        let single = record(&events, 1024 * 1024);
+        let chunked = record(&events, 16);
+
+        assert!(single.len() < chunked.len());
+        assert_eq!(events, RecordingReader::new(&single[..]).unwrap().map(|event| event.unwrap()).collect::<Vec<Event>>());
+        assert_eq!(events, RecordingReader::new(&chunked[..]).unwrap().map(|event| event.unwrap()).collect::<Vec<Event>>());
+    }
+
+    #[test]
+    fn corrupt_chunks_are_skipped_and_truncation_is_reported() {
+        let events = events();
+        let mut data = record(&events[..2], 1);
+        let first_recording_end = data.len();
+
+        data.extend_from_slice(&record(&events[2..4], 1)[10..]);
+        // Corrupt the payload of the second chunk
+        data[first_recording_end - 1] ^= 0xff;
+
+        let results: Vec<_> = RecordingReader::new(&data[..]).unwrap().collect();
+        assert_eq!(ErrorKind::InvalidData, results[1].as_ref().unwrap_err().kind());
+        assert_eq!(vec![events[0].clone(), events[2].clone(), events[3].clone()], results.into_iter().filter_map(|event| event.ok()).collect::<Vec<Event>>());
+
+        let truncated = &data[..data.len() - 2];
+        let results: Vec<_> = RecordingReader::new(truncated).unwrap().collect();
+        assert_eq!(ErrorKind::UnexpectedEof, results.last().unwrap().as_ref().unwrap_err().kind());
+
+        assert!(RecordingReader::new(&b"JVMTIREC\x02\x00"[..]).is_err());
+    }
+
+    #[test]
+    fn oversized_strings_are_reported_as_invalid() {
+        let mut payload = vec![];
+
+        write_varint(&mut payload, 0);
+        payload.push(tag::STRING);
+        write_varint(&mut payload, 0);
+        write_varint(&mut payload, ::std::u64::MAX);
+
+        let mut data = MAGIC.to_vec();
+        data.extend_from_slice(&[1, 0]);
+
+        for value in [payload.len() as u32, crc32(&payload)].iter() {
+            data.extend_from_slice(&[*value as u8, (*value >> 8) as u8, (*value >> 16) as u8, (*value >> 24) as u8]);
+        }
+
+        data.extend_from_slice(&payload);
+
+        let results: Vec<_> = RecordingReader::new(&data[..]).unwrap().collect();
+        assert_eq!(ErrorKind::InvalidData, results[0].as_ref().unwrap_err().kind());
+    }
+
+    #[test]
+    fn events_are_written_by_the_recording_thread() {
+        let path = env::temp_dir().join(format!("jvmti-recording-test-{}.rec", process::id()));
+
+        recording::start(&path, 64).unwrap();
+        assert!(recording::start(&path, 64).is_err());
+
+        let workers: Vec<_> = (0..4).map(|_| thread::spawn(|| for event in events() {
+            recording::record(|| event);
+        })).collect();
+
+        for worker in workers {
+            worker.join().unwrap();
+        }
+
+        assert_eq!(4 * events().len() as u64, recording::stop().unwrap());
+        assert!(!recording::is_recording());
+
+        recording::record(|| panic!("events aren't created once the recording has stopped"));
+
+        let recorded = RecordingReader::new(File::open(&path).unwrap()).unwrap().map(|event| event.unwrap()).count();
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(4 * events().len(), recorded);
+    }
+
+    #[test]
+    fn recordings_are_converted_to_other_formats() {
+        let mut text = vec![];
+        let mut csv = vec![];
+        let mut json = vec![];
+        let mut chrome = vec![];
+
+        convert::write_text(events(), &mut text).unwrap();
+        convert::write_csv(events(), &mut csv).unwrap();
+        convert::write_json_lines(events(), &mut json).unwrap();
+        convert::write_chrome(events(), &mut chrome, 42).unwrap();
+
+        let text = String::from_utf8(text).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        let json = String::from_utf8(json).unwrap();
+        let chrome = String::from_utf8(chrome).unwrap();
+
+        assert_eq!(Some("1500 method_entry main Hello.main"), text.lines().nth(1));
+        assert_eq!(Some("2500 gc_pause 1000"), text.lines().nth(4));
+        assert_eq!(vec!["time,type,thread,subject,value", "1000,thread_start,main,,"], csv.lines().take(2).collect::<Vec<&str>>());
+        assert_eq!(Some(r#"{"type":"monitor_waited","time":3000,"thread":"main","timed_out":true}"#), json.lines().nth(3));
+        assert!(chrome.contains(r#"{"name":"Hello.main","cat":"trace","ph":"B","ts":0.5,"pid":42,"tid":1}"#));
+        assert!(chrome.contains(r#"{"name":"GC pause","cat":"gc","ph":"X","ts":1.5,"dur":1.0,"pid":42,"tid":0,"args":{"sampled_objects_freed":"7"}}"#));
+        assert!(chrome.contains(r#"{"name":"Thread main","cat":"thread","ph":"X","ts":0.0,"dur":4.0,"pid":42,"tid":1}"#));
+    }
+}
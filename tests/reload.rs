@@ -0,0 +1,166 @@
+extern crate jvmti;
+
+#[cfg(test)]
+mod tests {
+
+    use jvmti::config::Config;
+    use jvmti::context::static_context;
+    use jvmti::reload::{self, Change};
+    use std::env;
+    use std::fs::{self, File};
+    use std::io::Write;
+    use std::path::{Path, PathBuf};
+    use std::process;
+    use std::sync::{Mutex, MutexGuard};
+
+    // The tests share the configuration of the agent
+    static CONFIG: Mutex<()> = Mutex::new(());
+
+    fn lock_config() -> MutexGuard<'static, ()> {
+        CONFIG.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    fn agent_name() -> String {
+        static_context().config.read().unwrap().agent_name.clone()
+    }
+
+    /// The default configuration, writing its output to the temporary directory
+    fn baseline() -> Config {
+        let mut config = Config::default();
+        config.output_dir = env::temp_dir().to_string_lossy().into_owned();
+        config
+    }
+
+    fn config_file(name: &str) -> PathBuf {
+        env::temp_dir().join(format!("jvmti-reload-test-{}-{}.conf", name, process::id()))
+    }
+
+    fn write_config(path: &Path, settings: &str) {
+        File::create(path).unwrap().write_all(format!("version = 2\noutput_dir = {:?}\n{}", env::temp_dir().to_string_lossy(), settings).as_bytes()).unwrap();
+    }
+
+    #[test]
+    fn changed_sections_are_found() {
+        let mut config = Config::default();
+        config.sampling.max_depth = 8;
+        config.reload.enabled = true;
+
+        assert_eq!(vec!["reload".to_string(), "sampling".to_string()], reload::changed_sections(&Config::default(), &config));
+        assert!(reload::changed_sections(&config, &config.clone()).is_empty());
+    }
+
+    #[test]
+    fn changed_active_classes_require_a_restart() {
+        let _lock = lock_config();
+        reload::apply(baseline());
+
+        // Loaded classes aren't transformed again, entry points are matched by the method events
+        let mut config = baseline();
+        config.instrumentation.active_classes = vec!["com.example.Service".to_string()];
+        assert_eq!(vec![Change::RestartRequired("instrumentation".to_string())], reload::apply(config.clone()));
+
+        config.instrumentation.entry_points = vec!["com.example.Dao.query".to_string()];
+        match reload::apply(config).as_slice() {
+            [Change::Failed(ref section, _)] => assert_eq!("instrumentation", section),
+            other => panic!("unexpected changes: {:?}", other)
+        }
+
+        reload::apply(baseline());
+    }
+
+    #[test]
+    fn configuration_changes_are_applied_to_the_running_agent() {
+        let _lock = lock_config();
+        reload::apply(baseline());
+
+        let mut config = baseline();
+        config.timeline.enabled = true;
+        config.exceptions.enabled = true;
+        config.gc.enabled = true;
+
+        // Exception events are switched through the environment of the agent, which needs a JVM
+        assert_eq!(vec![
+            Change::Failed("exceptions".to_string(), "couldn't set exception events: The functionality is not available in this virtual machine.".to_string()),
+            Change::RestartRequired("gc".to_string()),
+            Change::Applied("timeline".to_string(), "timeline enabled".to_string())
+        ], reload::apply(config.clone()));
+        assert_eq!(Some(0), static_context().timeline_len());
+        assert!(static_context().config.read().unwrap().exceptions.enabled);
+        assert!(reload::apply(config.clone()).is_empty());
+
+        // Method events are toggled through the environment of the agent, which needs a JVM
+        config.instrumentation.entry_points = vec![".Hello.main".to_string()];
+        match reload::apply(config).as_slice() {
+            [Change::Failed(ref section, _)] => assert_eq!("instrumentation", section),
+            other => panic!("unexpected changes: {:?}", other)
+        }
+
+        // Disabling the timeline writes it to the output directory
+        assert!(reload::apply(baseline()).contains(&Change::Applied("timeline".to_string(), format!("timeline disabled, wrote 0 events to {}", env::temp_dir().join("timeline.json").display()))));
+        assert_eq!(None, static_context().timeline_len());
+    }
+
+    #[test]
+    fn configuration_files_are_reloaded() {
+        let _lock = lock_config();
+        reload::apply(baseline());
+
+        let path = config_file("reloaded");
+
+        write_config(&path, "agent_name = \"reloaded\"\n");
+        assert_eq!(vec![Change::Applied("agent_name".to_string(), "updated".to_string())], reload::reload(&path).unwrap());
+        assert_eq!("reloaded", agent_name());
+
+        // A rejected file leaves the previous configuration in effect
+        write_config(&path, "agent_name = \"rejected\"\n[reload]\ninterval_ms = 0\n");
+        assert!(reload::reload(&path).is_err());
+        assert_eq!("reloaded", agent_name());
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn modified_files_are_reloaded_when_polled() {
+        let _lock = lock_config();
+        reload::apply(baseline());
+
+        let path = config_file("polled");
+
+        write_config(&path, "agent_name = \"first\"\n");
+        let mut watcher = reload::Watcher::new(&path);
+        assert!(watcher.poll().is_none());
+        assert_eq!(Config::default().agent_name, agent_name());
+
+        // Files rewritten within the resolution of their modification time differ in size
+        write_config(&path, "agent_name = \"second\"\n");
+        assert_eq!(vec![Change::Applied("agent_name".to_string(), "updated".to_string())], watcher.poll().unwrap().unwrap());
+        assert_eq!("second", agent_name());
+        assert!(watcher.poll().is_none());
+
+        write_config(&path, "agent_name = \"rejected\"\n[reload]\ninterval_ms = 0\n");
+        assert!(watcher.poll().unwrap().is_err());
+        assert_eq!("second", agent_name());
+
+        // A file that's gone keeps the current configuration and is reloaded once it's back
+        fs::remove_file(&path).unwrap();
+        assert!(watcher.poll().is_none());
+
+        write_config(&path, "agent_name = \"third\"\n");
+        assert!(watcher.poll().unwrap().is_ok());
+        assert_eq!("third", agent_name());
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn the_reload_thread_is_started_once() {
+        let path = config_file("thread");
+
+        reload::start(&path).unwrap();
+        assert!(reload::is_running());
+        assert!(reload::start(&path).is_err());
+
+        reload::stop();
+        assert!(!reload::is_running());
+    }
+}
@@ -28,4 +28,12 @@ mod tests {
         let s: MutString = CString::new(expected).unwrap().as_ptr() as *mut i8;
         assert_eq!(expected, stringify(s));
     }
+
+    #[test]
+    fn stringify_decodes_modified_utf8() {
+        let expected = "a\u{0}\u{1F600}";
+        let s: MutString = CString::new(vec![ 0x61, 0xC0, 0x80, 0xED, 0xA0, 0xBD, 0xED, 0xB8, 0x80 ]).unwrap().into_raw();
+        assert_eq!(expected, stringify(s));
+        unsafe { drop(CString::from_raw(s)); }
+    }
 }